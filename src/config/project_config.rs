@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Default)]
+pub(crate) struct ProjectConfig {
+    pub naming_overrides: HashMap<String, String>,
+    pub mutable_state_fallback: MutableStateFallback,
+    pub exception_mappings: HashMap<String, String>,
+    pub anonymous_function_shorthand: bool,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+pub enum MutableStateFallback {
+    #[default]
+    Volatile,
+    Atom,
+}
+
+impl ProjectConfig {
+    /// Reads an ini style project file made of `[section]` headers and `key = value` entries.
+    /// A missing file is not an error, every setting simply keeps its default.
+    pub(crate) fn load(config_file_path: &Path) -> Result<Self, String> {
+        let mut project_config: ProjectConfig = ProjectConfig::default();
+
+        if !config_file_path.exists() {
+            return Ok(project_config);
+        }
+
+        let contents: String = match fs::read_to_string(config_file_path) {
+            Ok(contents) => contents,
+            Err(error) => return Err(format!("Unable to read project config: {}", error)),
+        };
+
+        let mut current_section: String = String::new();
+
+        for (line_index, line) in contents.lines().enumerate() {
+            let line: &str = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if line.starts_with('[') && line.ends_with(']') {
+                current_section = line[1..line.len() - 1].trim().to_string();
+                continue;
+            }
+
+            let (key, value): (&str, &str) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => {
+                    return Err(format!(
+                        "Malformed project config entry on line {}: {}",
+                        line_index + 1,
+                        line
+                    ))
+                }
+            };
+
+            match current_section.as_str() {
+                "naming" => {
+                    project_config
+                        .naming_overrides
+                        .insert(key.to_string(), value.to_string());
+                }
+                "exceptions" => {
+                    project_config
+                        .exception_mappings
+                        .insert(key.to_string(), value.to_string());
+                }
+                "mutation" => match (key, value) {
+                    ("fallback", "volatile") => {
+                        project_config.mutable_state_fallback = MutableStateFallback::Volatile;
+                    }
+                    ("fallback", "atom") => {
+                        project_config.mutable_state_fallback = MutableStateFallback::Atom;
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Unsupported mutation setting `{} = {}` on line {}, expected `fallback = volatile` or `fallback = atom`",
+                            key,
+                            value,
+                            line_index + 1
+                        ))
+                    }
+                },
+                "style" => match (key, value) {
+                    ("anonymous_function_shorthand", "true") => {
+                        project_config.anonymous_function_shorthand = true;
+                    }
+                    ("anonymous_function_shorthand", "false") => {
+                        project_config.anonymous_function_shorthand = false;
+                    }
+                    _ => {
+                        return Err(format!(
+                            "Unsupported style setting `{} = {}` on line {}, expected `anonymous_function_shorthand = true` or `anonymous_function_shorthand = false`",
+                            key,
+                            value,
+                            line_index + 1
+                        ))
+                    }
+                },
+                _ => {
+                    return Err(format!(
+                        "Unknown project config section [{}] on line {}",
+                        current_section,
+                        line_index + 1
+                    ))
+                }
+            }
+        }
+
+        Ok(project_config)
+    }
+}
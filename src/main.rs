@@ -1,31 +1,36 @@
 extern crate core;
 
+use crate::config::project_config::ProjectConfig;
 use crate::syntax_tree::syntax_tree_node::SyntaxTreeNode;
 use crate::tokenizer::token::Token;
 use crate::transpiler::prettifier::Prettifier;
 use std::fs;
 use std::path::Path;
 
+pub mod config;
 pub mod syntax_tree;
 pub mod tokenizer;
 pub mod transpiler;
 
+#[cfg(test)]
+mod tests;
+
 fn main() {
     let input_file_path: &Path = Path::new("./src/input/source.cs");
     let contents: String =
         fs::read_to_string(input_file_path)
             .expect("Should have been able to read the file");
 
-    let source_code_tokens: Vec<Token> = tokenizer::tokenizer::tokenize(contents)
-        .expect("Failed to tokenize source code");
-
-    let abstract_syntax_tree: SyntaxTreeNode = syntax_tree::syntax_tree_builder::parse(source_code_tokens)
-        .expect("Failed to parse abstract syntax tree");
+    let project_config: ProjectConfig =
+        ProjectConfig::load(Path::new("./src/input/clojuresharp.cfg"))
+            .expect("Failed to load project configuration");
 
-    let transpiled_code: String = transpiler::transpiler::transpile(abstract_syntax_tree);
+    let (pretty_transpiled_code, warnings): (String, Vec<String>) =
+        transpile_source(contents, &project_config).expect("Failed to transpile source code");
 
-    let prettifier: Prettifier = Prettifier::new(' ', 4);
-    let pretty_transpiled_code: String = prettifier.prettify(transpiled_code);
+    for warning in warnings {
+        eprintln!("warning: {}", warning);
+    }
 
     let output_file_path: &Path = Path::new("./src/output/result.clj");
 
@@ -37,3 +42,92 @@ fn main() {
     fs::write(output_file_path, pretty_transpiled_code)
         .expect("Should have been able to write output file");
 }
+
+pub(crate) fn transpile_source(
+    contents: String,
+    project_config: &ProjectConfig,
+) -> Result<(String, Vec<String>), String> {
+    let mut warnings: Vec<String> = vec![];
+
+    let source_code_tokens: Vec<Token> = match tokenizer::tokenizer::tokenize(contents) {
+        Ok(valid_tokens) => valid_tokens,
+        Err(error) => return Err(format!("Failed to tokenize source code: {}", error)),
+    };
+
+    let abstract_syntax_tree: SyntaxTreeNode =
+        match syntax_tree::syntax_tree_builder::parse(source_code_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(format!("Failed to parse abstract syntax tree: {}", error)),
+        };
+
+    warnings.extend(transpiler::clojure_core::flag_undeclared_core_calls(
+        &abstract_syntax_tree,
+        &project_config.naming_overrides,
+    ));
+
+    let abstract_syntax_tree: SyntaxTreeNode =
+        transpiler::linq::translate_query_operators(abstract_syntax_tree);
+
+    let (abstract_syntax_tree, class_warnings): (SyntaxTreeNode, Vec<String>) =
+        match transpiler::classes::lower_classes(abstract_syntax_tree) {
+            Ok(valid_result) => valid_result,
+            Err(error) => return Err(format!("Failed to lower classes: {}", error)),
+        };
+    warnings.extend(class_warnings);
+
+    let (abstract_syntax_tree, member_warnings): (SyntaxTreeNode, Vec<String>) =
+        transpiler::members::resolve_member_access(
+            abstract_syntax_tree,
+            &project_config.naming_overrides,
+        );
+    warnings.extend(member_warnings);
+
+    let abstract_syntax_tree: SyntaxTreeNode = transpiler::naming::apply_naming_conventions(
+        abstract_syntax_tree,
+        &project_config.naming_overrides,
+    );
+
+    let (abstract_syntax_tree, collision_warnings): (SyntaxTreeNode, Vec<String>) =
+        transpiler::clojure_core::resolve_core_collisions(abstract_syntax_tree);
+    warnings.extend(collision_warnings);
+
+    let abstract_syntax_tree: SyntaxTreeNode =
+        transpiler::loops::thread_collection_additions(abstract_syntax_tree);
+
+    let (abstract_syntax_tree, early_return_warnings): (SyntaxTreeNode, Vec<String>) =
+        match transpiler::returns::eliminate_early_returns(abstract_syntax_tree) {
+            Ok(valid_result) => valid_result,
+            Err(error) => return Err(format!("Failed to eliminate early returns: {}", error)),
+        };
+    warnings.extend(early_return_warnings);
+
+    let (abstract_syntax_tree, mutable_state_warnings): (SyntaxTreeNode, Vec<String>) =
+        transpiler::mutation::apply_mutable_state_fallback(
+            abstract_syntax_tree,
+            project_config.mutable_state_fallback,
+        );
+    warnings.extend(mutable_state_warnings);
+
+    let abstract_syntax_tree: SyntaxTreeNode =
+        transpiler::idioms::apply_binding_idioms(abstract_syntax_tree);
+
+    let abstract_syntax_tree: SyntaxTreeNode =
+        transpiler::rebinding::rebind_branch_reassignments(abstract_syntax_tree);
+
+    let abstract_syntax_tree: SyntaxTreeNode =
+        transpiler::lambdas::apply_anonymous_function_style(
+            abstract_syntax_tree,
+            project_config.anonymous_function_shorthand,
+        );
+
+    let abstract_syntax_tree: SyntaxTreeNode = transpiler::exceptions::resolve_exception_types(
+        abstract_syntax_tree,
+        &project_config.exception_mappings,
+    );
+
+    let transpiled_code: String = transpiler::transpiler::transpile(abstract_syntax_tree);
+
+    let prettifier: Prettifier = Prettifier::new(' ', 4);
+
+    Ok((prettifier.prettify(transpiled_code), warnings))
+}
@@ -1,15 +1,163 @@
+// Parse errors are propagated with explicit matches throughout this module.
+#![allow(clippy::question_mark)]
+
 use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
 use crate::tokenizer::token::{Token, TokenType};
 
+const CLASS_MEMBER_MODIFIERS: [&str; 13] = [
+    "public",
+    "private",
+    "protected",
+    "internal",
+    "static",
+    "const",
+    "readonly",
+    "virtual",
+    "override",
+    "abstract",
+    "sealed",
+    "required",
+    "async",
+];
+
+const QUERY_CLAUSE_KEYWORDS: [&str; 8] = [
+    "from", "let", "where", "join", "orderby", "select", "group", "into",
+];
+
+/// Wraps a postfix `++`/`--` whose old value is used by the surrounding expression. Statements
+/// discard the value, so they keep the bare step assignment.
+pub(crate) const POSTFIX_STEP_NAME: &str = "postfix step";
+
 pub(crate) fn parse(source_tokens: Vec<Token>) -> Result<SyntaxTreeNode, String> {
-    if !matches!(source_tokens[0].type_, TokenType::NamespaceToken)
-        || !matches!(source_tokens[1].type_, TokenType::NameIdentifierToken)
-    {
+    let mut namespace_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut current_index: usize = 0;
+    while current_index < source_tokens.len() {
+        match source_tokens[current_index].type_ {
+            TokenType::NamespaceToken => {
+                let (parsed_namespaces, end_index): (Vec<SyntaxTreeNode>, usize) =
+                    match parse_namespace(&source_tokens, current_index, None) {
+                        Ok(valid_namespaces) => valid_namespaces,
+                        Err(error) => return Err(error),
+                    };
+
+                namespace_nodes.extend(parsed_namespaces);
+                current_index = end_index + 1;
+            }
+            TokenType::UsingToken => {
+                current_index = match find_index_of_next_semicolon(&source_tokens, current_index) {
+                    Some(index) => index + 1,
+                    None => return Err(String::from("cannot find end of using directive")),
+                };
+            }
+            TokenType::CommentToken => current_index += 1,
+            _ => {
+                return Err(format!(
+                    "Unexpected token outside of namespace: {}",
+                    source_tokens[current_index]
+                ))
+            }
+        }
+    }
+
+    if namespace_nodes.is_empty() {
         return Err(String::from("no token namespace found"));
     }
 
+    let compilation_unit_node: SyntaxTreeNode = SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::CompilationUnit,
+        children: namespace_nodes,
+    };
+
+    if let Some(method_name) = find_method_with_unbound_rethrow(&compilation_unit_node, "") {
+        return Err(format!(
+            "`throw;` outside of a catch clause in method {} cannot be translated",
+            method_name
+        ));
+    }
+
+    Ok(compilation_unit_node)
+}
+
+fn find_method_with_unbound_rethrow<'a>(
+    syntax_tree_node: &'a SyntaxTreeNode,
+    method_name: &'a str,
+) -> Option<&'a str> {
+    let method_name: &str = match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Method, Some(name)) => name,
+        (SyntaxTreeNodeType::Throw, _) if syntax_tree_node.children.is_empty() => {
+            return Some(method_name)
+        }
+        _ => method_name,
+    };
+
+    syntax_tree_node
+        .children
+        .iter()
+        .find_map(|child| find_method_with_unbound_rethrow(child, method_name))
+}
+
+fn parse_namespace(
+    source_tokens: &[Token],
+    namespace_index: usize,
+    parent_namespace_name: Option<&str>,
+) -> Result<(Vec<SyntaxTreeNode>, usize), String> {
+    let name_end_index: usize = namespace_index
+        + 1
+        + source_tokens
+            .iter()
+            .skip(namespace_index + 1)
+            .position(|token| {
+                !matches!(
+                    token.type_,
+                    TokenType::NameIdentifierToken | TokenType::DotMethodToken
+                )
+            })
+            .unwrap_or(source_tokens.len() - namespace_index - 1);
+
+    let namespace_name: String = source_tokens[namespace_index + 1..name_end_index]
+        .iter()
+        .filter_map(|token| token.value.clone())
+        .collect::<Vec<String>>()
+        .join(".");
+
+    if namespace_name.is_empty() {
+        return Err(String::from("namespace declaration is missing a name"));
+    }
+
+    let namespace_name: String = match parent_namespace_name {
+        Some(parent_name) => format!("{}.{}", parent_name, namespace_name),
+        None => namespace_name,
+    };
+
+    let (body_tokens, end_index): (&[Token], usize) = match source_tokens.get(name_end_index) {
+        Some(token) if matches!(token.type_, TokenType::SemicolonToken) => (
+            &source_tokens[name_end_index + 1..],
+            source_tokens.len() - 1,
+        ),
+        Some(token) if matches!(token.type_, TokenType::OpenScopeToken) => {
+            match find_index_of_last_closing_scope(source_tokens, name_end_index) {
+                Some(index) => (&source_tokens[name_end_index + 1..index], index),
+                None => return Err(format!("cannot find end of namespace {}", namespace_name)),
+            }
+        }
+        _ => return Err(format!("malformed namespace declaration {}", namespace_name)),
+    };
+
+    match parse_namespace_body(&namespace_name, body_tokens) {
+        Ok(valid_namespaces) => Ok((valid_namespaces, end_index)),
+        Err(error) => Err(error),
+    }
+}
+
+fn parse_namespace_body(
+    namespace_name: &str,
+    source_tokens: &[Token],
+) -> Result<Vec<SyntaxTreeNode>, String> {
     let mut current_index: usize = 0;
     let mut internal_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut nested_namespace_nodes: Vec<SyntaxTreeNode> = vec![];
 
     while current_index < source_tokens.len() {
         match (
@@ -17,13 +165,29 @@ pub(crate) fn parse(source_tokens: Vec<Token>) -> Result<SyntaxTreeNode, String>
             source_tokens.get(current_index + 1),
             source_tokens.get(current_index + 2),
         ) {
+            (Some(first), _, _) if matches!(first.type_, TokenType::NamespaceToken) => {
+                let (parsed_namespaces, end_index): (Vec<SyntaxTreeNode>, usize) =
+                    match parse_namespace(source_tokens, current_index, Some(namespace_name)) {
+                        Ok(valid_namespaces) => valid_namespaces,
+                        Err(error) => return Err(error),
+                    };
+
+                nested_namespace_nodes.extend(parsed_namespaces);
+                current_index = end_index + 1;
+            }
+            (Some(first), _, _) if matches!(first.type_, TokenType::UsingToken) => {
+                current_index = match find_index_of_next_semicolon(source_tokens, current_index) {
+                    Some(index) => index + 1,
+                    None => return Err(String::from("cannot find end of using directive")),
+                };
+            }
             (Some(first), Some(second), Some(third))
                 if matches!(first.type_, TokenType::TypeDeclarationToken)
                     && matches!(second.type_, TokenType::NameIdentifierToken)
                     && matches!(third.type_, TokenType::OpenParenthesisToken) =>
             {
                 let end_index: usize =
-                    match find_index_of_last_closing_scope(&source_tokens, current_index) {
+                    match find_index_of_last_closing_scope(source_tokens, current_index) {
                         Some(index) => index,
                         None => return Err("cannot find end of scope".to_string()),
                     };
@@ -36,23 +200,66 @@ pub(crate) fn parse(source_tokens: Vec<Token>) -> Result<SyntaxTreeNode, String>
                     Err(error) => return Err(error),
                 }
             }
+            (Some(first), Some(second), _)
+                if is_contextual_keyword(first, "interface")
+                    && matches!(second.type_, TokenType::NameIdentifierToken) =>
+            {
+                let end_index: usize =
+                    match find_index_of_last_closing_scope(source_tokens, current_index) {
+                        Some(index) => index,
+                        None => return Err(String::from("cannot find end of interface")),
+                    };
+
+                match parse_interface(&source_tokens[current_index..=end_index]) {
+                    Ok(valid_node) => {
+                        internal_nodes.push(valid_node);
+                        current_index = end_index + 1;
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+            (Some(first), Some(second), _)
+                if is_contextual_keyword(first, "record")
+                    && matches!(second.type_, TokenType::NameIdentifierToken) =>
+            {
+                let end_index: usize = match find_end_of_class_member(source_tokens, current_index) {
+                    Some(index) => index,
+                    None => return Err(String::from("cannot find end of record")),
+                };
+
+                match parse_record(&source_tokens[current_index..=end_index]) {
+                    Ok(valid_node) => {
+                        internal_nodes.push(valid_node);
+                        current_index = end_index + 1;
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
             (Some(first), Some(second), _)
                 if matches!(first.type_, TokenType::ClassToken)
                     && matches!(second.type_, TokenType::NameIdentifierToken) =>
             {
-                todo!()
+                let end_index: usize =
+                    match find_index_of_last_closing_scope(source_tokens, current_index) {
+                        Some(index) => index,
+                        None => return Err(String::from("cannot find end of class")),
+                    };
+
+                match parse_class(&source_tokens[current_index..=end_index]) {
+                    Ok(valid_node) => {
+                        internal_nodes.push(valid_node);
+                        current_index = end_index + 1;
+                    }
+                    Err(error) => return Err(error),
+                }
             }
             (Some(first), Some(second), _)
                 if matches!(first.type_, TokenType::NameIdentifierToken)
                     && matches!(second.type_, TokenType::OpenParenthesisToken) =>
             {
-                let semicolon_index: usize = match source_tokens
-                    .iter()
-                    .position(|token| matches!(token.type_, TokenType::SemicolonToken))
-                {
-                    Some(index) => current_index + index,
-                    None => source_tokens.len(),
-                };
+                let semicolon_index: usize =
+                    find_index_of_next_semicolon(source_tokens, current_index)
+                        .unwrap_or(source_tokens.len());
 
                 match parse_expression(&source_tokens[current_index..semicolon_index]) {
                     Ok(valid_node) => {
@@ -66,11 +273,164 @@ pub(crate) fn parse(source_tokens: Vec<Token>) -> Result<SyntaxTreeNode, String>
         }
     }
 
-    Ok(SyntaxTreeNode {
-        value: source_tokens[1].value.clone(),
-        type_: SyntaxTreeNodeType::Namespace,
-        children: internal_nodes,
+    let mut namespace_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    if !internal_nodes.is_empty() || nested_namespace_nodes.is_empty() {
+        namespace_nodes.push(SyntaxTreeNode {
+            value: Some(namespace_name.to_string()),
+            type_: SyntaxTreeNodeType::Namespace,
+            children: internal_nodes,
+        });
+    }
+    namespace_nodes.extend(nested_namespace_nodes);
+
+    Ok(namespace_nodes)
+}
+
+fn find_index_of_next_semicolon(source_tokens: &[Token], index_to_start: usize) -> Option<usize> {
+    find_top_level_indices(&source_tokens[index_to_start..], |token| {
+        matches!(token.type_, TokenType::SemicolonToken)
     })
+    .first()
+    .map(|index| index + index_to_start)
+}
+
+fn find_top_level_indices(source_tokens: &[Token], predicate: impl Fn(&Token) -> bool) -> Vec<usize> {
+    let mut nesting_depth: i32 = 0;
+    let mut top_level_indices: Vec<usize> = vec![];
+
+    for (index, token) in source_tokens.iter().enumerate() {
+        match token.type_ {
+            TokenType::OpenParenthesisToken
+            | TokenType::OpenCollectionToken
+            | TokenType::OpenScopeToken => nesting_depth += 1,
+            TokenType::CloseParenthesisToken
+            | TokenType::CloseCollectionToken
+            | TokenType::CloseScopeToken => nesting_depth -= 1,
+            _ if nesting_depth == 0 && predicate(token) => top_level_indices.push(index),
+            _ => {}
+        }
+    }
+
+    top_level_indices
+}
+
+fn find_index_of_matching_parenthesis(source_tokens: &[Token], open_index: usize) -> Option<usize> {
+    let mut open_parenthesis_count: u32 = 0;
+
+    for (index, token) in source_tokens.iter().enumerate().skip(open_index) {
+        match token.type_ {
+            TokenType::OpenParenthesisToken => open_parenthesis_count += 1,
+            TokenType::CloseParenthesisToken => {
+                open_parenthesis_count -= 1;
+                if open_parenthesis_count == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn find_end_of_statement(source_tokens: &[Token], start_index: usize) -> Option<usize> {
+    let token: &Token = source_tokens.get(start_index)?;
+
+    match (&token.type_, token.value.as_deref()) {
+        (TokenType::OpenScopeToken, _) => {
+            find_index_of_last_closing_scope(source_tokens, start_index)
+        }
+        (TokenType::BranchingOperatorToken, Some("else")) => {
+            find_end_of_statement(source_tokens, start_index + 1)
+        }
+        (TokenType::BranchingOperatorToken, _) => {
+            let close_parenthesis_index: usize =
+                find_index_of_matching_parenthesis(source_tokens, start_index + 1)?;
+            let end_of_body_index: usize =
+                find_end_of_statement(source_tokens, close_parenthesis_index + 1)?;
+
+            match source_tokens.get(end_of_body_index + 1) {
+                Some(Token {
+                    type_: TokenType::BranchingOperatorToken,
+                    value: Some(else_keyword),
+                    ..
+                }) if else_keyword == "else" => {
+                    find_end_of_statement(source_tokens, end_of_body_index + 1)
+                }
+                _ => Some(end_of_body_index),
+            }
+        }
+        (TokenType::LoopToken, Some("while" | "for" | "foreach"))
+        | (TokenType::SwitchToken, _)
+        | (TokenType::UsingToken, _)
+            if matches!(
+                source_tokens.get(start_index + 1)?.type_,
+                TokenType::OpenParenthesisToken
+            ) =>
+        {
+            let close_parenthesis_index: usize =
+                find_index_of_matching_parenthesis(source_tokens, start_index + 1)?;
+            find_end_of_statement(source_tokens, close_parenthesis_index + 1)
+        }
+        (TokenType::ExceptionHandlingToken, Some("try")) => {
+            let mut end_of_clause_index: usize =
+                find_end_of_statement(source_tokens, start_index + 1)?;
+
+            loop {
+                match source_tokens.get(end_of_clause_index + 1) {
+                    Some(Token {
+                        type_: TokenType::ExceptionHandlingToken,
+                        value: Some(clause_keyword),
+                        ..
+                    }) if clause_keyword == "catch" => {
+                        let body_start_index: usize =
+                            find_catch_body_start(source_tokens, end_of_clause_index + 2)?;
+                        end_of_clause_index =
+                            find_end_of_statement(source_tokens, body_start_index)?;
+                    }
+                    Some(Token {
+                        type_: TokenType::ExceptionHandlingToken,
+                        value: Some(clause_keyword),
+                        ..
+                    }) if clause_keyword == "finally" => {
+                        end_of_clause_index =
+                            find_end_of_statement(source_tokens, end_of_clause_index + 2)?;
+                    }
+                    _ => return Some(end_of_clause_index),
+                }
+            }
+        }
+        (TokenType::LoopToken, Some("do")) => {
+            let end_of_body_index: usize = find_end_of_statement(source_tokens, start_index + 1)?;
+            Some(
+                find_index_of_next_semicolon(source_tokens, end_of_body_index + 1)
+                    .unwrap_or(source_tokens.len() - 1),
+            )
+        }
+        _ => Some(
+            find_index_of_next_semicolon(source_tokens, start_index)
+                .unwrap_or(source_tokens.len() - 1),
+        ),
+    }
+}
+
+fn find_catch_body_start(source_tokens: &[Token], start_index: usize) -> Option<usize> {
+    let mut body_start_index: usize = start_index;
+
+    if matches!(
+        source_tokens.get(body_start_index)?.type_,
+        TokenType::OpenParenthesisToken
+    ) {
+        body_start_index = find_index_of_matching_parenthesis(source_tokens, body_start_index)? + 1;
+    }
+
+    if is_contextual_keyword(source_tokens.get(body_start_index)?, "when") {
+        body_start_index =
+            find_index_of_matching_parenthesis(source_tokens, body_start_index + 1)? + 1;
+    }
+
+    Some(body_start_index)
 }
 
 fn find_index_of_last_closing_scope(
@@ -111,7 +471,11 @@ fn parse_method(method_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
 
     let method_body_tokens: &[Token] = &method_tokens[argument_close_parenthesis_index + 2..];
 
-    let mut method_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut method_nodes: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+        value: method_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Type,
+        children: vec![],
+    }]);
     method_nodes.extend(match parse_method_arguments(method_argument_tokens) {
         Ok(valid_node) => valid_node,
         Err(error) => return Err(error),
@@ -128,370 +492,3561 @@ fn parse_method(method_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
     })
 }
 
-fn parse_method_arguments(argument_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
-    Ok(argument_tokens
+fn parse_class(class_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let class_name: String = class_tokens[1].value.clone().unwrap();
+    let body_start_index: usize = match class_tokens
         .iter()
-        .filter(|token| matches!(token.type_, TokenType::NameIdentifierToken))
-        .map(|token| SyntaxTreeNode {
-            value: token.value.clone(),
-            type_: SyntaxTreeNodeType::MethodArgument,
-            children: vec![],
+        .position(|token| matches!(token.type_, TokenType::OpenScopeToken))
+    {
+        Some(index) => index,
+        None => return Err(format!("class {} has no body", class_name)),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = class_tokens[2..body_start_index]
+        .iter()
+        .filter(|token| {
+            matches!(
+                token.type_,
+                TokenType::NameIdentifierToken | TokenType::TypeDeclarationToken
+            )
         })
-        .collect::<Vec<SyntaxTreeNode>>())
-}
+        .map(create_type_node)
+        .collect::<Vec<SyntaxTreeNode>>();
 
-fn parse_internal_scope(internal_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
-    let mut scope_nodes: Vec<SyntaxTreeNode> = vec![];
+    children.extend(
+        match parse_class_members(
+            &class_name,
+            &class_tokens[body_start_index + 1..class_tokens.len() - 1],
+        ) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
 
-    let mut token_index: usize = 0;
-    while token_index < internal_tokens.len() {
-        let token: &Token = &internal_tokens[token_index];
+    Ok(SyntaxTreeNode {
+        value: Some(class_name),
+        type_: SyntaxTreeNodeType::Class,
+        children,
+    })
+}
 
-        match token.type_ {
-            TokenType::ReturnToken | TokenType::CloseScopeToken => {
-                token_index += 1;
-            }
-            TokenType::CommentToken => {
-                scope_nodes.push(SyntaxTreeNode {
-                    value: token.value.clone(),
-                    type_: SyntaxTreeNodeType::Comment,
-                    children: vec![],
-                });
-                token_index += 1;
-            }
-            _ => {
-                let mut end_of_scope_index: usize = token_index
-                    + internal_tokens
-                        .iter()
-                        .skip(token_index)
-                        .position(|token| matches!(token.type_, TokenType::SemicolonToken))
-                        .unwrap_or(0);
+fn parse_interface(interface_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let interface_name: String = interface_tokens[1].value.clone().unwrap();
+    let body_start_index: usize = match interface_tokens
+        .iter()
+        .position(|token| matches!(token.type_, TokenType::OpenScopeToken))
+    {
+        Some(index) => index,
+        None => return Err(format!("interface {} has no body", interface_name)),
+    };
 
-                if internal_tokens
-                    .iter()
-                    .skip(token_index)
-                    .position(|token| matches!(token.type_, TokenType::OpenScopeToken))
-                    .is_some_and(|open_scope_index| {
-                        (token_index + open_scope_index) < end_of_scope_index
-                    })
-                {
-                    end_of_scope_index =
-                        find_index_of_last_closing_scope(&internal_tokens, token_index)
-                            .expect("Unable to find end of current scope");
-                }
+    let mut children: Vec<SyntaxTreeNode> = interface_tokens[2..body_start_index]
+        .iter()
+        .filter(|token| matches!(token.type_, TokenType::NameIdentifierToken))
+        .map(create_type_node)
+        .collect::<Vec<SyntaxTreeNode>>();
 
-                scope_nodes.push(
-                    match parse_expression(&internal_tokens[token_index..=end_of_scope_index]) {
-                        Ok(valid_node) => valid_node,
-                        Err(error) => return Err(error),
-                    },
-                );
+    let member_nodes: Vec<SyntaxTreeNode> = match parse_class_members(
+        &interface_name,
+        &interface_tokens[body_start_index + 1..interface_tokens.len() - 1],
+    ) {
+        Ok(valid_nodes) => valid_nodes,
+        Err(error) => return Err(error),
+    };
 
-                token_index = end_of_scope_index + 1;
-            }
-        }
-    }
+    children.extend(member_nodes.into_iter().map(|member_node| match member_node.type_ {
+        SyntaxTreeNodeType::Field => SyntaxTreeNode {
+            value: member_node.value,
+            type_: SyntaxTreeNodeType::Method,
+            children: Vec::from([
+                member_node.children[0].clone(),
+                create_instance_argument_node(),
+            ]),
+        },
+        _ => member_node,
+    }));
 
-    Ok(group_consecutive_assignments(scope_nodes))
+    Ok(SyntaxTreeNode {
+        value: Some(interface_name),
+        type_: SyntaxTreeNodeType::Interface,
+        children,
+    })
 }
 
-fn group_consecutive_assignments(body_nodes: Vec<SyntaxTreeNode>) -> Vec<SyntaxTreeNode> {
-    let mut body_nodes_with_compound_assignments: Vec<SyntaxTreeNode> = vec![];
+fn parse_record(record_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let name_index: usize = match record_tokens[1].value.as_deref() {
+        Some("struct" | "class") => 2,
+        _ => 1,
+    };
+    let record_name: String = match record_tokens.get(name_index) {
+        Some(Token {
+            type_: TokenType::NameIdentifierToken,
+            value: Some(record_name),
+            ..
+        }) => record_name.clone(),
+        _ => return Err(format!("Failed to parse record name on line {}", record_tokens[0].line)),
+    };
 
-    let mut i: usize = 0;
-    while i < body_nodes.len() {
-        if !matches!(body_nodes[i].type_, SyntaxTreeNodeType::Assignment) {
-            body_nodes_with_compound_assignments.push(body_nodes[i].clone());
-            i += 1;
-            continue;
-        }
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    let mut header_start_index: usize = name_index + 1;
 
-        let mut j: usize = i + 1;
-        while j < body_nodes.len() && matches!(body_nodes[j].type_, SyntaxTreeNodeType::Assignment)
-        {
-            j += 1;
-        }
+    if matches!(
+        record_tokens.get(name_index + 1),
+        Some(Token {
+            type_: TokenType::OpenParenthesisToken,
+            ..
+        })
+    ) {
+        let close_paren_index: usize =
+            match find_index_of_matching_parenthesis(record_tokens, name_index + 1) {
+                Some(index) => index,
+                None => return Err(format!("cannot find end of record {} parameters", record_name)),
+            };
 
-        body_nodes_with_compound_assignments.push(match i + 1 == j {
-            true => body_nodes[i].clone(),
-            false => SyntaxTreeNode {
-                value: None,
-                type_: SyntaxTreeNodeType::Assignment,
-                children: Vec::from(&body_nodes[i..j]),
-            },
-        });
+        let parameter_nodes: Vec<SyntaxTreeNode> =
+            match parse_method_arguments(&record_tokens[name_index + 2..close_paren_index]) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            };
 
-        i = j;
+        children.extend(parameter_nodes.into_iter().map(|parameter_node| SyntaxTreeNode {
+            value: parameter_node.value,
+            type_: SyntaxTreeNodeType::Field,
+            children: parameter_node.children,
+        }));
+        header_start_index = close_paren_index + 1;
     }
 
-    body_nodes_with_compound_assignments
-}
+    let body_start_index: Option<usize> = record_tokens
+        .iter()
+        .skip(header_start_index)
+        .position(|token| matches!(token.type_, TokenType::OpenScopeToken))
+        .map(|index| index + header_start_index);
+    let header_end_index: usize = body_start_index.unwrap_or(record_tokens.len() - 1);
 
-fn parse_expression(expression_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
-    let expression_tokens: &[Token] = match expression_tokens.last().unwrap().type_ {
-        TokenType::SemicolonToken => &expression_tokens[..(expression_tokens.len() - 1)],
-        _ => expression_tokens,
-    };
+    let base_type_nodes: Vec<SyntaxTreeNode> = find_top_level_indices(
+        &record_tokens[header_start_index..header_end_index],
+        |token| matches!(token.type_, TokenType::NameIdentifierToken),
+    )
+    .into_iter()
+    .map(|index| create_type_node(&record_tokens[header_start_index + index]))
+    .collect::<Vec<SyntaxTreeNode>>();
+    children.splice(0..0, base_type_nodes);
 
-    if expression_tokens.len() == 1 {
-        return Ok(SyntaxTreeNode {
-            value: expression_tokens[0].value.clone(),
-            type_: SyntaxTreeNodeType::Literal,
-            children: vec![],
-        });
+    if let Some(body_start_index) = body_start_index {
+        children.extend(
+            match parse_class_members(
+                &record_name,
+                &record_tokens[body_start_index + 1..record_tokens.len() - 1],
+            ) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
     }
 
-    if matches!(expression_tokens[0].type_, TokenType::ReturnToken) {
-        return parse_expression(&expression_tokens[1..]);
-    }
+    Ok(SyntaxTreeNode {
+        value: Some(record_name),
+        type_: SyntaxTreeNodeType::Class,
+        children,
+    })
+}
 
-    if matches!(expression_tokens[0].type_, TokenType::OpenParenthesisToken) {
-        if let Some(close_paren_index) = expression_tokens
-            .iter()
-            .rposition(|token| matches!(token.type_, TokenType::CloseParenthesisToken))
+fn parse_class_members(class_name: &str, member_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut member_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut index: usize = 0;
+
+    while index < member_tokens.len() {
+        let mut modifiers: Vec<String> = vec![];
+        while let Some(Token {
+            type_: TokenType::NameIdentifierToken,
+            value: Some(modifier),
+            ..
+        }) = member_tokens.get(index)
         {
-            return parse_expression(&expression_tokens[1..close_paren_index]);
+            if !CLASS_MEMBER_MODIFIERS.contains(&modifier.as_str()) {
+                break;
+            }
+            modifiers.push(modifier.clone());
+            index += 1;
         }
-    }
 
-    if matches!(&expression_tokens[0], Token { type_: TokenType::BranchingOperatorToken, value: Some(i)} if i == "if")
-    {
-        let close_paren_index: usize = expression_tokens
+        let is_static: bool = modifiers
             .iter()
-            .position(|token| matches!(token.type_, TokenType::CloseParenthesisToken))
-            .unwrap();
+            .any(|modifier| modifier == "static" || modifier == "const");
+
+        let member_start_index: usize = index;
+        let (type_name, name_index): (Option<String>, usize) = match (
+            member_tokens.get(index),
+            member_tokens.get(index + 1),
+            member_tokens.get(index + 2),
+        ) {
+            (Some(type_token), Some(open_token), Some(close_token))
+                if matches!(open_token.type_, TokenType::OpenCollectionToken)
+                    && matches!(close_token.type_, TokenType::CloseCollectionToken) =>
+            {
+                (
+                    type_token.value.as_ref().map(|type_name| format!("{}[]", type_name)),
+                    index + 3,
+                )
+            }
+            (Some(type_token), _, _) => (type_token.value.clone(), index + 1),
+            (None, _, _) => break,
+        };
+
+        let (interface_name, name_index): (Option<String>, usize) = match (
+            member_tokens.get(name_index),
+            member_tokens.get(name_index + 1),
+            member_tokens.get(name_index + 2),
+        ) {
+            (
+                Some(Token {
+                    type_: TokenType::NameIdentifierToken,
+                    value: Some(interface_name),
+                    ..
+                }),
+                Some(Token {
+                    type_: TokenType::DotMethodToken,
+                    ..
+                }),
+                Some(Token {
+                    type_: TokenType::NameIdentifierToken,
+                    ..
+                }),
+            ) => (Some(interface_name.clone()), name_index + 2),
+            _ => (None, name_index),
+        };
+
+        match (
+            &member_tokens[member_start_index],
+            member_tokens.get(name_index),
+            member_tokens.get(name_index + 1),
+        ) {
+            (Token { type_: TokenType::CommentToken, .. }, _, _) => {
+                index += 1;
+            }
+            (Token { type_: TokenType::NameIdentifierToken, value: Some(name), .. }, Some(open_token), _)
+                if name == class_name
+                    && matches!(open_token.type_, TokenType::OpenParenthesisToken) =>
+            {
+                let end_index: usize = match find_end_of_class_member(member_tokens, member_start_index) {
+                    Some(end_index) => end_index,
+                    None => return Err(format!("cannot find end of {} constructor", class_name)),
+                };
+
+                if !is_static {
+                    member_nodes.push(match parse_constructor(&member_tokens[member_start_index..=end_index]) {
+                        Ok(valid_node) => valid_node,
+                        Err(error) => return Err(error),
+                    });
+                }
+                index = end_index + 1;
+            }
+            (_, Some(name_token), Some(next_token))
+                if matches!(name_token.type_, TokenType::NameIdentifierToken) =>
+            {
+                let end_index: usize = match find_end_of_class_member(member_tokens, name_index) {
+                    Some(end_index) => end_index,
+                    None => {
+                        return Err(format!(
+                            "cannot find end of class member {}",
+                            name_token.value.clone().unwrap_or_default()
+                        ))
+                    }
+                };
+
+                let parsed_members: Result<Vec<SyntaxTreeNode>, String> = match next_token.type_ {
+                    TokenType::OpenParenthesisToken => parse_class_method(
+                        type_name,
+                        &member_tokens[name_index..=end_index],
+                        is_static,
+                    )
+                    .map(|method_node| Vec::from([method_node])),
+                    TokenType::OpenScopeToken | TokenType::ArrowToken => parse_property(
+                        type_name,
+                        &member_tokens[name_index..=end_index],
+                        &modifiers,
+                    ),
+                    _ => parse_fields(type_name, &member_tokens[name_index..=end_index], &modifiers),
+                };
+
+                match (parsed_members, &interface_name) {
+                    (Ok(valid_nodes), Some(interface_name)) => {
+                        member_nodes.extend(valid_nodes.into_iter().map(|member_node| SyntaxTreeNode {
+                            value: member_node
+                                .value
+                                .map(|member_name| format!("{}.{}", interface_name, member_name)),
+                            type_: member_node.type_,
+                            children: member_node.children,
+                        }))
+                    }
+                    (Ok(valid_nodes), None) => member_nodes.extend(valid_nodes),
+                    (Err(error), _) => return Err(error),
+                }
+                index = end_index + 1;
+            }
+            _ => {
+                return Err(format!(
+                    "Failed to parse member of class {} on line {}",
+                    class_name, member_tokens[member_start_index].line
+                ))
+            }
+        }
+    }
+
+    Ok(member_nodes)
+}
+
+fn find_end_of_class_member(member_tokens: &[Token], start_index: usize) -> Option<usize> {
+    let mut nesting_depth: i32 = 0;
+    let mut has_expression_body: bool = false;
+
+    for (index, token) in member_tokens.iter().enumerate().skip(start_index) {
+        match token.type_ {
+            TokenType::AssignmentOperatorToken | TokenType::ArrowToken if nesting_depth == 0 => {
+                has_expression_body = true
+            }
+            TokenType::OpenParenthesisToken
+            | TokenType::OpenCollectionToken
+            | TokenType::OpenScopeToken => nesting_depth += 1,
+            TokenType::CloseParenthesisToken | TokenType::CloseCollectionToken => {
+                nesting_depth -= 1
+            }
+            TokenType::CloseScopeToken => {
+                nesting_depth -= 1;
+                if nesting_depth == 0
+                    && !has_expression_body
+                    && !matches!(
+                        member_tokens.get(index + 1),
+                        Some(Token {
+                            type_: TokenType::AssignmentOperatorToken,
+                            ..
+                        })
+                    )
+                {
+                    return Some(index);
+                }
+            }
+            TokenType::SemicolonToken if nesting_depth == 0 => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn parse_class_method(
+    type_name: Option<String>,
+    method_tokens: &[Token],
+    is_static: bool,
+) -> Result<SyntaxTreeNode, String> {
+    let close_paren_index: usize = match find_index_of_matching_parenthesis(method_tokens, 1) {
+        Some(index) => index,
+        None => return Err(String::from("cannot find end of method arguments")),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+        value: type_name,
+        type_: SyntaxTreeNodeType::Type,
+        children: vec![],
+    }]);
+    if !is_static {
+        children.push(create_instance_argument_node());
+    }
+    children.extend(
+        match parse_method_arguments(&method_tokens[2..close_paren_index]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+    children.extend(
+        match parse_member_body(&method_tokens[close_paren_index + 1..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: method_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Method,
+        children,
+    })
+}
+
+fn parse_constructor(constructor_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let close_paren_index: usize = match find_index_of_matching_parenthesis(constructor_tokens, 1) {
+        Some(index) => index,
+        None => return Err(String::from("cannot find end of constructor arguments")),
+    };
+    let body_start_index: usize = match constructor_tokens
+        .iter()
+        .skip(close_paren_index)
+        .position(|token| {
+            matches!(
+                token.type_,
+                TokenType::OpenScopeToken | TokenType::ArrowToken
+            )
+        }) {
+        Some(index) => index + close_paren_index,
+        None => return Err(String::from("constructor has no body")),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> =
+        match parse_method_arguments(&constructor_tokens[2..close_paren_index]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        };
+    children.extend(match constructor_tokens[body_start_index].type_ {
+        TokenType::ArrowToken => match parse_internal_scope(&constructor_tokens[body_start_index + 1..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+        _ => match parse_member_body(&constructor_tokens[body_start_index..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    });
+
+    Ok(SyntaxTreeNode {
+        value: constructor_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Constructor,
+        children,
+    })
+}
+
+fn parse_member_body(body_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    match body_tokens.first() {
+        Some(Token {
+            type_: TokenType::ArrowToken,
+            ..
+        }) => {
+            let expression_tokens: &[Token] = match find_index_of_next_semicolon(body_tokens, 0) {
+                Some(semicolon_index) => &body_tokens[1..semicolon_index],
+                None => &body_tokens[1..],
+            };
+
+            parse_expression(expression_tokens).map(|body_node| Vec::from([body_node]))
+        }
+        Some(Token {
+            type_: TokenType::OpenScopeToken,
+            ..
+        }) => match find_index_of_last_closing_scope(body_tokens, 0) {
+            Some(close_index) => parse_internal_scope(&body_tokens[1..close_index]),
+            None => Err(String::from("cannot find end of member body")),
+        },
+        Some(Token {
+            type_: TokenType::SemicolonToken,
+            ..
+        }) => Ok(vec![]),
+        _ => Err(String::from("class member has no body")),
+    }
+}
+
+fn parse_property(
+    type_name: Option<String>,
+    property_tokens: &[Token],
+    modifiers: &[String],
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let is_static: bool = modifiers.iter().any(|modifier| modifier == "static");
+
+    let getter_body_tokens: Option<&[Token]> = match property_tokens[1].type_ {
+        TokenType::ArrowToken => Some(&property_tokens[1..]),
+        _ => property_tokens
+            .iter()
+            .position(|token| is_contextual_keyword(token, "get"))
+            .map(|get_index| &property_tokens[get_index + 1..])
+            .filter(|accessor_tokens| {
+                matches!(
+                    accessor_tokens.first(),
+                    Some(Token {
+                        type_: TokenType::ArrowToken | TokenType::OpenScopeToken,
+                        ..
+                    })
+                )
+            }),
+    };
+
+    if let Some(getter_body_tokens) = getter_body_tokens {
+        let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+            value: type_name,
+            type_: SyntaxTreeNodeType::Type,
+            children: vec![],
+        }]);
+        if !is_static {
+            children.push(create_instance_argument_node());
+        }
+        children.extend(match parse_member_body(getter_body_tokens) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        });
+
+        return Ok(Vec::from([SyntaxTreeNode {
+            value: property_tokens[0].value.clone(),
+            type_: SyntaxTreeNodeType::Method,
+            children,
+        }]));
+    }
+
+    let initializer_tokens: &[Token] = match find_index_of_last_closing_scope(property_tokens, 1) {
+        Some(close_index) => &property_tokens[close_index + 1..],
+        None => return Err(String::from("cannot find end of property accessors")),
+    };
+
+    let mut field_tokens: Vec<Token> = Vec::from([property_tokens[0].clone()]);
+    field_tokens.extend_from_slice(initializer_tokens);
+
+    parse_fields(type_name, &field_tokens, modifiers)
+}
+
+fn parse_fields(
+    type_name: Option<String>,
+    field_tokens: &[Token],
+    modifiers: &[String],
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let field_tokens: &[Token] = match field_tokens.last() {
+        Some(Token {
+            type_: TokenType::SemicolonToken,
+            ..
+        }) => &field_tokens[..field_tokens.len() - 1],
+        _ => field_tokens,
+    };
+
+    let mut field_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut comma_indices: Vec<usize> =
+        find_top_level_indices(field_tokens, |token| matches!(token.type_, TokenType::CommaToken));
+    comma_indices.push(field_tokens.len());
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        let declarator_tokens: &[Token] = &field_tokens[i..j];
+        i = j + 1;
+
+        let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+            value: type_name.clone(),
+            type_: SyntaxTreeNodeType::Type,
+            children: vec![],
+        }]);
+        children.extend(modifiers.iter().map(|modifier| SyntaxTreeNode {
+            value: Some(modifier.clone()),
+            type_: SyntaxTreeNodeType::Modifier,
+            children: vec![],
+        }));
+
+        match declarator_tokens {
+            [name_token] => field_nodes.push(SyntaxTreeNode {
+                value: name_token.value.clone(),
+                type_: SyntaxTreeNodeType::Field,
+                children,
+            }),
+            [name_token, assignment_token, value_tokens @ ..]
+                if matches!(assignment_token.type_, TokenType::AssignmentOperatorToken)
+                    && !value_tokens.is_empty() =>
+            {
+                children.push(apply_target_type(
+                    match parse_expression(value_tokens) {
+                        Ok(valid_node) => valid_node,
+                        Err(error) => return Err(error),
+                    },
+                    type_name.as_deref(),
+                ));
+
+                field_nodes.push(SyntaxTreeNode {
+                    value: name_token.value.clone(),
+                    type_: SyntaxTreeNodeType::Field,
+                    children,
+                });
+            }
+            _ => {
+                return Err(format!(
+                    "Failed to parse field declaration: {}",
+                    declarator_tokens
+                        .iter()
+                        .map(|token| token.to_string())
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ))
+            }
+        }
+    }
+
+    Ok(field_nodes)
+}
+
+fn create_instance_argument_node() -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(String::from("this")),
+        type_: SyntaxTreeNodeType::MethodArgument,
+        children: vec![],
+    }
+}
+
+fn parse_method_arguments(argument_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    Ok(argument_tokens
+        .split(|token| matches!(token.type_, TokenType::CommaToken))
+        .filter(|argument| {
+            argument
+                .last()
+                .is_some_and(|token| matches!(token.type_, TokenType::NameIdentifierToken))
+        })
+        .map(|argument| SyntaxTreeNode {
+            value: argument[argument.len() - 1].value.clone(),
+            type_: SyntaxTreeNodeType::MethodArgument,
+            children: match &argument[..argument.len() - 1] {
+                [.., element_type_token, open_token, close_token]
+                    if matches!(open_token.type_, TokenType::OpenCollectionToken)
+                        && matches!(close_token.type_, TokenType::CloseCollectionToken) =>
+                {
+                    Vec::from([SyntaxTreeNode {
+                        value: element_type_token
+                            .value
+                            .as_ref()
+                            .map(|type_name| format!("{}[]", type_name)),
+                        type_: SyntaxTreeNodeType::Type,
+                        children: vec![],
+                    }])
+                }
+                [.., type_token] => Vec::from([create_type_node(type_token)]),
+                [] => vec![],
+            },
+        })
+        .collect::<Vec<SyntaxTreeNode>>())
+}
+
+fn parse_internal_scope(internal_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut scope_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut token_index: usize = 0;
+    while token_index < internal_tokens.len() {
+        let token: &Token = &internal_tokens[token_index];
+
+        match token.type_ {
+            TokenType::CloseScopeToken | TokenType::SemicolonToken => {
+                token_index += 1;
+            }
+            TokenType::ReturnToken => {
+                let end_of_statement_index: usize =
+                    match find_end_of_statement(internal_tokens, token_index) {
+                        Some(index) => index,
+                        None => return Err(format!("cannot find end of statement at {}", token)),
+                    };
+                let value_tokens: &[Token] =
+                    &internal_tokens[token_index + 1..=end_of_statement_index];
+
+                scope_nodes.push(SyntaxTreeNode {
+                    value: None,
+                    type_: SyntaxTreeNodeType::Return,
+                    children: match value_tokens {
+                        [] | [Token {
+                            type_: TokenType::SemicolonToken,
+                            ..
+                        }] => vec![],
+                        _ => Vec::from([match parse_expression(value_tokens) {
+                            Ok(valid_node) => valid_node,
+                            Err(error) => return Err(error),
+                        }]),
+                    },
+                });
+                token_index = end_of_statement_index + 1;
+            }
+            TokenType::UsingToken
+                if !matches!(
+                    internal_tokens.get(token_index + 1),
+                    Some(Token {
+                        type_: TokenType::OpenParenthesisToken,
+                        ..
+                    })
+                ) =>
+            {
+                let end_of_declaration_index: usize =
+                    match find_index_of_next_semicolon(internal_tokens, token_index) {
+                        Some(index) => index,
+                        None => {
+                            return Err(format!(
+                                "cannot find end of using declaration on line {}",
+                                token.line
+                            ))
+                        }
+                    };
+
+                let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+                    value: None,
+                    type_: SyntaxTreeNodeType::Block,
+                    children: match parse_variable_declarators(
+                        &internal_tokens[token_index + 1..end_of_declaration_index],
+                    ) {
+                        Ok(valid_nodes) => valid_nodes,
+                        Err(error) => return Err(error),
+                    },
+                }]);
+                children.extend(
+                    match parse_internal_scope(&internal_tokens[end_of_declaration_index + 1..]) {
+                        Ok(valid_nodes) => valid_nodes,
+                        Err(error) => return Err(error),
+                    },
+                );
+
+                scope_nodes.push(SyntaxTreeNode {
+                    value: None,
+                    type_: SyntaxTreeNodeType::Using,
+                    children,
+                });
+                break;
+            }
+            TokenType::CommentToken => {
+                scope_nodes.push(SyntaxTreeNode {
+                    value: token.value.clone(),
+                    type_: SyntaxTreeNodeType::Comment,
+                    children: vec![],
+                });
+                token_index += 1;
+            }
+            _ => {
+                let end_of_statement_index: usize =
+                    match find_end_of_statement(internal_tokens, token_index) {
+                        Some(index) => index,
+                        None => return Err(format!("cannot find end of statement at {}", token)),
+                    };
+
+                match token.type_ {
+                    TokenType::OpenScopeToken => scope_nodes.extend(
+                        match parse_internal_scope(
+                            &internal_tokens[token_index + 1..end_of_statement_index],
+                        ) {
+                            Ok(valid_nodes) => valid_nodes,
+                            Err(error) => return Err(error),
+                        },
+                    ),
+                    _ => scope_nodes.push(
+                        match parse_expression(
+                            &internal_tokens[token_index..=end_of_statement_index],
+                        ) {
+                            Ok(valid_node) => discard_postfix_value(valid_node),
+                            Err(error) => return Err(error),
+                        },
+                    ),
+                }
+
+                token_index = end_of_statement_index + 1;
+            }
+        }
+    }
+
+    Ok(group_consecutive_assignments(scope_nodes))
+}
+
+fn discard_postfix_value(statement_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    match (
+        statement_node.value.as_deref(),
+        statement_node.children.as_slice(),
+    ) {
+        (Some(POSTFIX_STEP_NAME), [_]) => statement_node.children.into_iter().next().unwrap(),
+        _ => statement_node,
+    }
+}
+
+fn parse_embedded_statement(statement_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    match statement_tokens.first() {
+        Some(token) if matches!(token.type_, TokenType::OpenScopeToken) => {
+            match find_index_of_last_closing_scope(statement_tokens, 0) {
+                Some(close_scope_index) => {
+                    parse_internal_scope(&statement_tokens[1..close_scope_index])
+                }
+                None => Err(String::from("cannot find end of scope")),
+            }
+        }
+        _ => parse_internal_scope(statement_tokens),
+    }
+}
+
+pub(crate) fn group_consecutive_assignments(body_nodes: Vec<SyntaxTreeNode>) -> Vec<SyntaxTreeNode> {
+    let mut body_nodes_with_compound_assignments: Vec<SyntaxTreeNode> = vec![];
+
+    let mut i: usize = 0;
+    while i < body_nodes.len() {
+        if !matches!(body_nodes[i].type_, SyntaxTreeNodeType::Assignment) {
+            body_nodes_with_compound_assignments.push(body_nodes[i].clone());
+            i += 1;
+            continue;
+        }
+
+        let mut j: usize = i + 1;
+        while j < body_nodes.len() && matches!(body_nodes[j].type_, SyntaxTreeNodeType::Assignment)
+        {
+            j += 1;
+        }
+
+        body_nodes_with_compound_assignments.push(match i + 1 == j {
+            true => body_nodes[i].clone(),
+            false => SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::Assignment,
+                children: Vec::from(&body_nodes[i..j]),
+            },
+        });
+
+        i = j;
+    }
+
+    body_nodes_with_compound_assignments
+}
+
+fn parse_expression(expression_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let expression_tokens: &[Token] = match expression_tokens.last() {
+        Some(token) if matches!(token.type_, TokenType::SemicolonToken) => {
+            &expression_tokens[..(expression_tokens.len() - 1)]
+        }
+        Some(_) => expression_tokens,
+        None => return Err(String::from("Failed to parse empty expression")),
+    };
+
+    if matches!(expression_tokens[0].type_, TokenType::LoopControlToken) {
+        return Ok(SyntaxTreeNode {
+            value: expression_tokens[0].value.clone(),
+            type_: SyntaxTreeNodeType::LoopControl,
+            children: vec![],
+        });
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::ThrowToken) {
+        return Ok(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Throw,
+            children: match expression_tokens.len() {
+                1 => vec![],
+                _ => Vec::from([match parse_expression(&expression_tokens[1..]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                }]),
+            },
+        });
+    }
+
+    if expression_tokens.len() == 1 {
+        return Ok(SyntaxTreeNode {
+            value: expression_tokens[0].value.clone(),
+            type_: SyntaxTreeNodeType::Literal,
+            children: vec![],
+        });
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::ReturnToken) {
+        return parse_expression(&expression_tokens[1..]);
+    }
+
+    if matches!(&expression_tokens[0], Token { type_: TokenType::BranchingOperatorToken, value: Some(i), .. } if i == "if")
+    {
+        let close_paren_index: usize = match find_index_of_matching_parenthesis(expression_tokens, 1)
+        {
+            Some(index) => index,
+            None => return Err(String::from("cannot find end of if condition")),
+        };
 
         let mut children: Vec<SyntaxTreeNode> = Vec::from([
             match parse_expression(&expression_tokens[2..close_paren_index]) {
                 Ok(valid_node) => valid_node,
                 Err(error) => return Err(error),
             },
-        ]);
-        children.extend(
-            match parse_internal_scope(&expression_tokens[close_paren_index + 2..]) {
+        ]);
+
+        let end_of_body_index: usize =
+            match find_end_of_statement(expression_tokens, close_paren_index + 1) {
+                Some(index) => index,
+                None => return Err(String::from("cannot find end of if body")),
+            };
+
+        children.extend(
+            match parse_embedded_statement(
+                &expression_tokens[close_paren_index + 1..=end_of_body_index],
+            ) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
+
+        if end_of_body_index + 1 < expression_tokens.len() {
+            children.push(SyntaxTreeNode {
+                value: expression_tokens[end_of_body_index + 1].value.clone(),
+                type_: SyntaxTreeNodeType::Branch,
+                children: match parse_embedded_statement(
+                    &expression_tokens[end_of_body_index + 2..],
+                ) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                },
+            });
+        }
+
+        return Ok(SyntaxTreeNode {
+            value: expression_tokens[0].value.clone(),
+            type_: SyntaxTreeNodeType::Branch,
+            children,
+        });
+    }
+
+    if matches!(&expression_tokens[0], Token { type_: TokenType::BranchingOperatorToken, value: Some(i), .. } if i == "else")
+    {
+        return Err(format!(
+            "`else` on line {} has no matching `if`",
+            expression_tokens[0].line
+        ));
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::LoopToken) {
+        return parse_loop(expression_tokens);
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::SwitchToken) {
+        return parse_switch(expression_tokens);
+    }
+
+    if matches!(&expression_tokens[0], Token { type_: TokenType::ExceptionHandlingToken, value: Some(keyword), .. } if keyword == "try")
+    {
+        return parse_try(expression_tokens);
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::UsingToken) {
+        return parse_using(expression_tokens);
+    }
+
+    if is_contextual_keyword(&expression_tokens[0], "delegate") {
+        return parse_anonymous_method(expression_tokens);
+    }
+
+    if let Some(arrow_index) = find_lambda_arrow(expression_tokens) {
+        return parse_lambda(expression_tokens, arrow_index);
+    }
+
+    if is_query_start(expression_tokens) {
+        return parse_query(expression_tokens);
+    }
+
+    if let Token {
+        type_: TokenType::ExceptionHandlingToken,
+        value: Some(keyword),
+        line,
+    } = &expression_tokens[0]
+    {
+        return Err(format!("`{}` on line {} has no matching `try`", keyword, line));
+    }
+
+    if expression_tokens.len() == 2
+        && matches!(
+            expression_tokens[0].type_,
+            TokenType::TypeDeclarationToken | TokenType::NameIdentifierToken
+        )
+        && matches!(expression_tokens[1].type_, TokenType::NameIdentifierToken)
+    {
+        return Ok(SyntaxTreeNode {
+            value: expression_tokens[1].value.clone(),
+            type_: SyntaxTreeNodeType::Assignment,
+            children: Vec::from([
+                SyntaxTreeNode {
+                    value: expression_tokens[0].value.clone(),
+                    type_: SyntaxTreeNodeType::Type,
+                    children: vec![],
+                },
+                SyntaxTreeNode {
+                    value: Some(String::from("null")),
+                    type_: SyntaxTreeNodeType::Literal,
+                    children: vec![],
+                },
+            ]),
+        });
+    }
+
+    if expression_tokens.len() > 2
+        && matches!(
+            expression_tokens[0].type_,
+            TokenType::TypeDeclarationToken | TokenType::NameIdentifierToken
+        )
+        && matches!(expression_tokens[1].type_, TokenType::NameIdentifierToken)
+        && matches!(expression_tokens[2].type_, TokenType::AssignmentOperatorToken)
+        && find_top_level_indices(expression_tokens, |token| {
+            matches!(token.type_, TokenType::CommaToken)
+        })
+        .first()
+        .is_some_and(|comma_index| {
+            find_query_start(expression_tokens).is_none_or(|query_index| *comma_index < query_index)
+        })
+    {
+        return Ok(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Assignment,
+            children: match parse_variable_declarators(expression_tokens) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        });
+    }
+
+    let is_operand_expression: bool = !find_top_level_indices(expression_tokens, |token| {
+        matches!(
+            token.type_,
+            TokenType::NumericOperationToken
+                | TokenType::BooleanOperationToken
+                | TokenType::EqualityOperatorToken
+                | TokenType::RelationalOperatorToken
+        )
+    })
+    .is_empty();
+
+    if let Some(assignment_index) = find_top_level_indices(expression_tokens, |token| {
+        matches!(token.type_, TokenType::AssignmentOperatorToken)
+            && !(is_operand_expression && matches!(token.value.as_deref(), Some("++" | "--")))
+    })
+    .first()
+    .copied()
+    {
+        return parse_assignment(expression_tokens, assignment_index);
+    }
+
+    if let Some(switch_index) = find_top_level_indices(expression_tokens, |token| {
+        matches!(token.type_, TokenType::SwitchToken)
+    })
+    .first()
+    .copied()
+    {
+        if find_index_of_last_closing_scope(expression_tokens, switch_index + 1)
+            == Some(expression_tokens.len() - 1)
+        {
+            return parse_switch_expression(expression_tokens, switch_index);
+        }
+    }
+
+    if let Some(with_index) = find_top_level_indices(expression_tokens, |token| {
+        is_contextual_keyword(token, "with")
+    })
+    .first()
+    .copied()
+    {
+        if with_index > 0
+            && matches!(
+                expression_tokens.get(with_index + 1),
+                Some(Token {
+                    type_: TokenType::OpenScopeToken,
+                    ..
+                })
+            )
+            && find_index_of_last_closing_scope(expression_tokens, with_index + 1)
+                == Some(expression_tokens.len() - 1)
+        {
+            return parse_with_expression(expression_tokens, with_index);
+        }
+    }
+
+    for (operator_token_type, operators) in [
+        (TokenType::BooleanOperationToken, ["||", "|"].as_slice()),
+        (TokenType::BooleanOperationToken, ["&&", "&"].as_slice()),
+        (TokenType::NameIdentifierToken, ["is"].as_slice()),
+        (TokenType::EqualityOperatorToken, ["==", "!="].as_slice()),
+        (TokenType::RelationalOperatorToken, ["<", ">", "<=", ">="].as_slice()),
+    ] {
+        if let Some(operator_index) = find_top_level_indices(expression_tokens, |token| {
+            token.type_ == operator_token_type
+                && token
+                    .value
+                    .as_deref()
+                    .is_some_and(|value| operators.contains(&value))
+        })
+        .last()
+        .copied()
+        {
+            return parse_binary_expression(expression_tokens, operator_index);
+        }
+    }
+
+    if find_top_level_indices(expression_tokens, |token| {
+        matches!(token.type_, TokenType::NumericOperationToken)
+    })
+    .iter()
+    .any(|index| *index > 0)
+    {
+        return parse_numerical_expression(expression_tokens);
+    }
+
+    if let [Token {
+        type_: TokenType::NumericOperationToken,
+        value: Some(sign),
+        ..
+    }, Token {
+        type_: TokenType::NumericLiteralToken,
+        value: Some(number),
+        ..
+    }] = expression_tokens
+    {
+        if sign == "-" {
+            return Ok(SyntaxTreeNode {
+                value: Some(format!("-{}", number)),
+                type_: SyntaxTreeNodeType::Literal,
+                children: vec![],
+            });
+        }
+    }
+
+    if let Token {
+        type_: TokenType::BooleanOperationToken | TokenType::NumericOperationToken,
+        value: Some(operator),
+        ..
+    } = &expression_tokens[0]
+    {
+        return Ok(SyntaxTreeNode {
+            value: Some(match operator.as_str() {
+                "!" => String::from("not"),
+                _ => operator.clone(),
+            }),
+            type_: SyntaxTreeNodeType::Expression,
+            children: Vec::from([match parse_expression(&expression_tokens[1..]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            }]),
+        });
+    }
+
+    let null_conditional_indices: Vec<usize> =
+        find_top_level_indices(expression_tokens, |token| {
+            matches!(token.type_, TokenType::NullConditionalToken)
+        });
+    if !null_conditional_indices.is_empty() {
+        return parse_null_conditional_chain(expression_tokens, &null_conditional_indices);
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::OpenParenthesisToken)
+        && find_index_of_matching_parenthesis(expression_tokens, 0)
+            == Some(expression_tokens.len() - 1)
+    {
+        return parse_expression(&expression_tokens[1..expression_tokens.len() - 1]);
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::NameIdentifierToken)
+        && matches!(expression_tokens[1].type_, TokenType::OpenParenthesisToken)
+        && find_index_of_matching_parenthesis(expression_tokens, 1)
+            == Some(expression_tokens.len() - 1)
+    {
+        return Ok(SyntaxTreeNode {
+            value: expression_tokens[0].value.clone(),
+            type_: SyntaxTreeNodeType::Expression,
+            children: match parse_collection(&expression_tokens[2..expression_tokens.len() - 1]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+        });
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::NewToken)
+        && find_top_level_indices(expression_tokens, |token| {
+            matches!(token.type_, TokenType::DotMethodToken)
+        })
+        .is_empty()
+    {
+        return parse_object_creation(expression_tokens);
+    }
+
+    if matches!(expression_tokens[0].type_, TokenType::OpenCollectionToken)
+        && matches!(
+            expression_tokens[expression_tokens.len() - 1].type_,
+            TokenType::CloseCollectionToken
+        )
+    {
+        return Ok(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Collection,
+            children: match parse_collection(&expression_tokens[1..expression_tokens.len() - 1]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+        });
+    }
+
+    if let Some(postfix_index) = find_last_postfix_operation(expression_tokens) {
+        return parse_postfix_operation(expression_tokens, postfix_index);
+    }
+
+    Err(format!(
+        "Failed to parse expressions: {}",
+        expression_tokens
+            .iter()
+            .map(|token| token.to_string())
+            .collect::<Vec<String>>()
+            .join(", ")
+    ))
+}
+
+fn find_last_postfix_operation(expression_tokens: &[Token]) -> Option<usize> {
+    if matches!(
+        expression_tokens.last()?.type_,
+        TokenType::CloseCollectionToken
+    ) {
+        let mut nesting_depth: u32 = 0;
+
+        for (index, token) in expression_tokens.iter().enumerate().rev() {
+            match token.type_ {
+                TokenType::CloseCollectionToken => nesting_depth += 1,
+                TokenType::OpenCollectionToken => {
+                    nesting_depth -= 1;
+                    if nesting_depth == 0 {
+                        return Some(index).filter(|open_index| *open_index > 0);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        return None;
+    }
+
+    find_top_level_indices(expression_tokens, |token| {
+        matches!(token.type_, TokenType::DotMethodToken)
+    })
+    .last()
+    .copied()
+    .filter(|dot_index| {
+        *dot_index > 0
+            && matches!(
+                expression_tokens.get(dot_index + 1),
+                Some(Token {
+                    type_: TokenType::NameIdentifierToken,
+                    ..
+                })
+            )
+            && match expression_tokens.get(dot_index + 2) {
+                None => true,
+                Some(Token {
+                    type_: TokenType::OpenParenthesisToken,
+                    ..
+                }) => {
+                    find_index_of_matching_parenthesis(expression_tokens, dot_index + 2)
+                        == Some(expression_tokens.len() - 1)
+                }
+                Some(_) => false,
+            }
+    })
+}
+
+fn parse_postfix_operation(
+    expression_tokens: &[Token],
+    postfix_index: usize,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> =
+        Vec::from([match parse_expression(&expression_tokens[..postfix_index]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        }]);
+
+    if matches!(
+        expression_tokens[postfix_index].type_,
+        TokenType::OpenCollectionToken
+    ) {
+        children.extend(
+            match parse_collection(&expression_tokens[postfix_index + 1..expression_tokens.len() - 1])
+            {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
+
+        return Ok(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::ElementAccess,
+            children,
+        });
+    }
+
+    let member_name: Option<String> = expression_tokens[postfix_index + 1].value.clone();
+
+    if postfix_index + 2 == expression_tokens.len() {
+        return Ok(SyntaxTreeNode {
+            value: member_name,
+            type_: SyntaxTreeNodeType::MemberAccess,
+            children,
+        });
+    }
+
+    children.extend(
+        match parse_collection(&expression_tokens[postfix_index + 3..expression_tokens.len() - 1]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: member_name,
+        type_: SyntaxTreeNodeType::Invocation,
+        children,
+    })
+}
+
+fn parse_null_conditional_chain(
+    expression_tokens: &[Token],
+    null_conditional_indices: &[usize],
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([match parse_expression(
+        &expression_tokens[..null_conditional_indices[0]],
+    ) {
+        Ok(valid_node) => valid_node,
+        Err(error) => return Err(error),
+    }]);
+
+    let segment_boundaries: Vec<usize> = null_conditional_indices
+        .iter()
+        .copied()
+        .chain([expression_tokens.len()])
+        .collect::<Vec<usize>>();
+
+    for segment_bounds in segment_boundaries.windows(2) {
+        let segment_tokens: &[Token] = &expression_tokens[segment_bounds[0] + 1..segment_bounds[1]];
+
+        let mut step_start_index: usize = 0;
+        for step_end_index in find_top_level_indices(segment_tokens, |token| {
+            matches!(token.type_, TokenType::DotMethodToken)
+        })
+        .into_iter()
+        .chain([segment_tokens.len()])
+        {
+            children.push(
+                match parse_null_conditional_step(&segment_tokens[step_start_index..step_end_index])
+                {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                },
+            );
+            step_start_index = step_end_index + 1;
+        }
+    }
+
+    Ok(SyntaxTreeNode {
+        value: Some(String::from("some->")),
+        type_: SyntaxTreeNodeType::Expression,
+        children,
+    })
+}
+
+fn parse_null_conditional_step(step_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    match step_tokens {
+        [member_token] if matches!(member_token.type_, TokenType::NameIdentifierToken) => {
+            Ok(SyntaxTreeNode {
+                value: member_token.value.clone(),
+                type_: SyntaxTreeNodeType::MemberAccess,
+                children: vec![],
+            })
+        }
+        [method_token, open_paren_token, .., close_paren_token]
+            if matches!(method_token.type_, TokenType::NameIdentifierToken)
+                && matches!(open_paren_token.type_, TokenType::OpenParenthesisToken)
+                && matches!(close_paren_token.type_, TokenType::CloseParenthesisToken) =>
+        {
+            Ok(SyntaxTreeNode {
+                value: method_token.value.clone(),
+                type_: SyntaxTreeNodeType::Expression,
+                children: match parse_collection(&step_tokens[2..step_tokens.len() - 1]) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                },
+            })
+        }
+        _ => Err(format!(
+            "Unsupported member access after `?.`: {}",
+            step_tokens
+                .iter()
+                .map(|token| token.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )),
+    }
+}
+
+fn parse_assignment(
+    expression_tokens: &[Token],
+    assignment_index: usize,
+) -> Result<SyntaxTreeNode, String> {
+    let assignment_operator: Option<&str> = expression_tokens[assignment_index].value.as_deref();
+
+    if let Some(step_operator @ ("++" | "--")) = assignment_operator {
+        let variable_token: &Token = match assignment_index {
+            0 => &expression_tokens[1],
+            _ => &expression_tokens[assignment_index - 1],
+        };
+
+        let step_node: SyntaxTreeNode = SyntaxTreeNode {
+            value: variable_token.value.clone(),
+            type_: SyntaxTreeNodeType::Assignment,
+            children: Vec::from([SyntaxTreeNode {
+                value: Some(String::from(match step_operator {
+                    "++" => "inc",
+                    _ => "dec",
+                })),
+                type_: SyntaxTreeNodeType::Expression,
+                children: Vec::from([SyntaxTreeNode {
+                    value: variable_token.value.clone(),
+                    type_: SyntaxTreeNodeType::Literal,
+                    children: vec![],
+                }]),
+            }]),
+        };
+
+        return Ok(match assignment_index {
+            0 => step_node,
+            _ => SyntaxTreeNode {
+                value: Some(String::from(POSTFIX_STEP_NAME)),
+                type_: SyntaxTreeNodeType::Expression,
+                children: Vec::from([step_node]),
+            },
+        });
+    }
+
+    if assignment_operator.is_none()
+        && assignment_index > 0
+        && matches!(
+            expression_tokens[assignment_index - 1].type_,
+            TokenType::CloseParenthesisToken
+        )
+    {
+        return parse_deconstruction(expression_tokens, assignment_index);
+    }
+
+    if assignment_index == 0
+        || !matches!(
+            expression_tokens[assignment_index - 1].type_,
+            TokenType::NameIdentifierToken
+        )
+    {
+        return Err(format!(
+            "Failed to parse assignment target: {}",
+            expression_tokens[..assignment_index]
+                .iter()
+                .map(|token| token.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        ));
+    }
+
+    let assigned_value_node: SyntaxTreeNode =
+        match parse_expression(&expression_tokens[assignment_index + 1..]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        };
+
+    let assigned_value_node: SyntaxTreeNode = match assignment_operator {
+        None => assigned_value_node,
+        Some(compound_operator) => SyntaxTreeNode {
+            value: Some(map_numeric_operator(
+                compound_operator.trim_end_matches('='),
+            )),
+            type_: SyntaxTreeNodeType::Expression,
+            children: Vec::from([
+                SyntaxTreeNode {
+                    value: expression_tokens[assignment_index - 1].value.clone(),
+                    type_: SyntaxTreeNodeType::Literal,
+                    children: vec![],
+                },
+                assigned_value_node,
+            ]),
+        },
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+
+    if let [.., element_type_token, open_token, close_token, _] =
+        &expression_tokens[..assignment_index]
+    {
+        if matches!(
+            element_type_token.type_,
+            TokenType::TypeDeclarationToken | TokenType::NameIdentifierToken
+        ) && matches!(open_token.type_, TokenType::OpenCollectionToken)
+            && matches!(close_token.type_, TokenType::CloseCollectionToken)
+        {
+            children.push(SyntaxTreeNode {
+                value: element_type_token
+                    .value
+                    .as_ref()
+                    .map(|type_name| format!("{}[]", type_name)),
+                type_: SyntaxTreeNodeType::Type,
+                children: vec![],
+            });
+        }
+    }
+
+    if assignment_index >= 2
+        && matches!(
+            expression_tokens[assignment_index - 2].type_,
+            TokenType::TypeDeclarationToken | TokenType::NameIdentifierToken
+        )
+    {
+        children.push(SyntaxTreeNode {
+            value: expression_tokens[assignment_index - 2].value.clone(),
+            type_: SyntaxTreeNodeType::Type,
+            children: vec![],
+        });
+    }
+    children.push(match children.first() {
+        Some(declared_type_node) => {
+            apply_target_type(assigned_value_node, declared_type_node.value.as_deref())
+        }
+        None => assigned_value_node,
+    });
+
+    Ok(SyntaxTreeNode {
+        value: expression_tokens[assignment_index - 1].value.clone(),
+        type_: SyntaxTreeNodeType::Assignment,
+        children,
+    })
+}
+
+fn parse_with_expression(
+    expression_tokens: &[Token],
+    with_index: usize,
+) -> Result<SyntaxTreeNode, String> {
+    let target_node: SyntaxTreeNode = match parse_expression(&expression_tokens[..with_index]) {
+        Ok(valid_node) => valid_node,
+        Err(error) => return Err(error),
+    };
+    let initializer_nodes: Vec<SyntaxTreeNode> = match parse_member_initializers(
+        &expression_tokens[with_index + 2..expression_tokens.len() - 1],
+    ) {
+        Ok(valid_nodes) => valid_nodes,
+        Err(error) => return Err(error),
+    };
+
+    if initializer_nodes.is_empty() {
+        return Ok(target_node);
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([target_node]);
+    for initializer_node in initializer_nodes {
+        children.push(SyntaxTreeNode {
+            value: initializer_node.value,
+            type_: SyntaxTreeNodeType::MemberAccess,
+            children: vec![],
+        });
+        children.extend(initializer_node.children);
+    }
+
+    Ok(SyntaxTreeNode {
+        value: Some(String::from("assoc")),
+        type_: SyntaxTreeNodeType::Expression,
+        children,
+    })
+}
+
+fn parse_deconstruction(
+    expression_tokens: &[Token],
+    assignment_index: usize,
+) -> Result<SyntaxTreeNode, String> {
+    let open_paren_index: usize = match expression_tokens
+        .iter()
+        .position(|token| matches!(token.type_, TokenType::OpenParenthesisToken))
+    {
+        Some(index)
+            if find_index_of_matching_parenthesis(expression_tokens, index)
+                == Some(assignment_index - 1) =>
+        {
+            index
+        }
+        _ => {
+            return Err(format!(
+                "Failed to parse deconstruction on line {}",
+                expression_tokens[0].line
+            ))
+        }
+    };
+
+    let element_tokens: &[Token] = &expression_tokens[open_paren_index + 1..assignment_index - 1];
+    let mut comma_indices: Vec<usize> =
+        find_top_level_indices(element_tokens, |token| matches!(token.type_, TokenType::CommaToken));
+    comma_indices.push(element_tokens.len());
+
+    let mut name_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut is_declaration: bool = open_paren_index > 0;
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        match &element_tokens[i..j] {
+            [name_token] if matches!(name_token.type_, TokenType::NameIdentifierToken) => {
+                name_nodes.push(create_capture_node(name_token))
+            }
+            [_, name_token] if matches!(name_token.type_, TokenType::NameIdentifierToken) => {
+                is_declaration = true;
+                name_nodes.push(create_capture_node(name_token));
+            }
+            _ => {
+                return Err(format!(
+                    "Failed to parse deconstruction element on line {}",
+                    expression_tokens[0].line
+                ))
+            }
+        }
+        i = j + 1;
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    if is_declaration {
+        children.push(SyntaxTreeNode {
+            value: Some(String::from("var")),
+            type_: SyntaxTreeNodeType::Type,
+            children: vec![],
+        });
+    }
+    children.push(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Collection,
+        children: name_nodes,
+    });
+    children.push(match parse_expression(&expression_tokens[assignment_index + 1..]) {
+        Ok(valid_node) => valid_node,
+        Err(error) => return Err(error),
+    });
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Assignment,
+        children,
+    })
+}
+
+fn apply_target_type(value_node: SyntaxTreeNode, declared_type_name: Option<&str>) -> SyntaxTreeNode {
+    match (&value_node.type_, value_node.children.first(), declared_type_name) {
+        (SyntaxTreeNodeType::ObjectCreation, Some(type_node), Some(type_name))
+            if matches!(type_node.type_, SyntaxTreeNodeType::Type)
+                && type_node.value.is_none()
+                && type_name != "var" =>
+        {
+            let mut children: Vec<SyntaxTreeNode> = value_node.children;
+            children[0].value = Some(type_name.to_string());
+
+            SyntaxTreeNode {
+                value: value_node.value,
+                type_: SyntaxTreeNodeType::ObjectCreation,
+                children,
+            }
+        }
+        _ => value_node,
+    }
+}
+
+fn parse_binary_expression(
+    expression_tokens: &[Token],
+    operator_index: usize,
+) -> Result<SyntaxTreeNode, String> {
+    if expression_tokens[operator_index].value.as_deref() == Some("is") {
+        return Ok(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::PatternMatch,
+            children: Vec::from([
+                match parse_expression(&expression_tokens[..operator_index]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                },
+                match parse_pattern(&expression_tokens[(operator_index + 1)..]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                },
+            ]),
+        });
+    }
+
+    let (value, type_): (String, SyntaxTreeNodeType) =
+        match expression_tokens[operator_index].value.as_deref() {
+            Some("||" | "|") => (String::from("or"), SyntaxTreeNodeType::Expression),
+            Some("&&" | "&") => (String::from("and"), SyntaxTreeNodeType::Expression),
+            Some("==") => (String::from("="), SyntaxTreeNodeType::EqualityCheck),
+            Some("!=") => (String::from("not="), SyntaxTreeNodeType::EqualityCheck),
+            Some(operator) => (operator.to_string(), SyntaxTreeNodeType::Expression),
+            None => return Err(String::from("binary operator has no value")),
+        };
+
+    Ok(SyntaxTreeNode {
+        value: Some(value),
+        type_,
+        children: Vec::from([
+            match parse_expression(&expression_tokens[..operator_index]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+            match parse_expression(&expression_tokens[(operator_index + 1)..]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+        ]),
+    })
+}
+
+fn parse_numerical_expression(expression_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let binary_operator_indices: Vec<usize> = find_top_level_indices(expression_tokens, |token| {
+        matches!(token.type_, TokenType::NumericOperationToken)
+    })
+    .into_iter()
+    .filter(|index| {
+        *index > 0
+            && !matches!(
+                expression_tokens[index - 1].type_,
+                TokenType::NumericOperationToken
+                    | TokenType::BooleanOperationToken
+                    | TokenType::RelationalOperatorToken
+                    | TokenType::EqualityOperatorToken
+            )
+    })
+    .collect::<Vec<usize>>();
+
+    let operator_index: usize = match binary_operator_indices
+        .iter()
+        .rev()
+        .find(|index| matches!(expression_tokens[**index].value.as_deref(), Some("+" | "-")))
+        .or(binary_operator_indices.last())
+    {
+        Some(index) => *index,
+        None => return Err(String::from("numeric expression has no binary operator")),
+    };
+
+    Ok(SyntaxTreeNode {
+        value: Some(map_numeric_operator(
+            expression_tokens[operator_index].value.as_deref().unwrap(),
+        )),
+        type_: SyntaxTreeNodeType::Expression,
+        children: Vec::from([
+            match parse_expression(&expression_tokens[..operator_index]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+            match parse_expression(&expression_tokens[operator_index + 1..]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+        ]),
+    })
+}
+
+fn map_numeric_operator(operator: &str) -> String {
+    match operator {
+        "%" => String::from("rem"),
+        _ => operator.to_string(),
+    }
+}
+
+fn parse_loop(loop_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let (loop_node, body_start_index): (SyntaxTreeNode, usize) =
+        match loop_tokens[0].value.as_deref() {
+            Some("for") => match parse_for_loop(loop_tokens) {
+                Ok(valid_node) => (valid_node, 3),
+                Err(error) => return Err(error),
+            },
+            Some("foreach") => match parse_foreach_loop(loop_tokens) {
+                Ok(valid_node) => (valid_node, 2),
+                Err(error) => return Err(error),
+            },
+            _ => match parse_conditional_loop(loop_tokens) {
+                Ok(valid_node) => (valid_node, 1),
+                Err(error) => return Err(error),
+            },
+        };
+
+    match validate_loop_control_flow(&loop_node.children[body_start_index..]) {
+        Ok(()) => Ok(loop_node),
+        Err(error) => Err(format!(
+            "Unsupported control flow in {} loop: {}",
+            loop_node.value.clone().unwrap(),
+            error
+        )),
+    }
+}
+
+fn validate_loop_control_flow(body_nodes: &[SyntaxTreeNode]) -> Result<(), String> {
+    let last_statement_index: Option<usize> = body_nodes
+        .iter()
+        .rposition(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment));
+
+    for (index, node) in body_nodes.iter().enumerate() {
+        match (&node.type_, node.value.as_deref()) {
+            (SyntaxTreeNodeType::LoopControl, Some(keyword))
+                if Some(index) != last_statement_index =>
+            {
+                return Err(format!(
+                    "statements after `{}` are unreachable and cannot be translated",
+                    keyword
+                ));
+            }
+            (SyntaxTreeNodeType::Branch, _) if contains_loop_control(node) => {
+                let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+                    split_branch_bodies(node);
+
+                if let Err(error) = validate_loop_control_flow(then_nodes) {
+                    return Err(error);
+                }
+
+                if let Err(error) = validate_loop_control_flow(else_nodes) {
+                    return Err(error);
+                }
+            }
+            (SyntaxTreeNodeType::Loop | SyntaxTreeNodeType::LoopControl, _) => {}
+            _ if contains_loop_control(node) => {
+                return Err(String::from(
+                    "break or continue nested inside an expression cannot be translated safely",
+                ));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn contains_loop_control(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::LoopControl => true,
+        SyntaxTreeNodeType::Loop => false,
+        _ => syntax_tree_node.children.iter().any(contains_loop_control),
+    }
+}
+
+pub(crate) fn split_branch_bodies(
+    branch_node: &SyntaxTreeNode,
+) -> (&[SyntaxTreeNode], &[SyntaxTreeNode]) {
+    let branch_body_nodes: &[SyntaxTreeNode] = &branch_node.children[1..];
+
+    match branch_body_nodes.split_last() {
+        Some((else_node, then_nodes))
+            if matches!(else_node.type_, SyntaxTreeNodeType::Branch)
+                && else_node.value.as_deref() == Some("else") =>
+        {
+            (then_nodes, &else_node.children)
+        }
+        _ => (branch_body_nodes, &[]),
+    }
+}
+
+fn parse_conditional_loop(loop_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let (condition_tokens, body_tokens): (&[Token], &[Token]) = match loop_tokens[0]
+        .value
+        .as_deref()
+    {
+        Some("while") => match find_index_of_matching_parenthesis(loop_tokens, 1) {
+            Some(close_paren_index) => (
+                &loop_tokens[2..close_paren_index],
+                &loop_tokens[close_paren_index + 1..],
+            ),
+            None => return Err(String::from("cannot find end of while condition")),
+        },
+        Some("do") => {
+            let end_of_body_index: usize = match find_end_of_statement(loop_tokens, 1) {
+                Some(index) => index,
+                None => return Err(String::from("cannot find end of do body")),
+            };
+
+            if !matches!(&loop_tokens.get(end_of_body_index + 1), Some(Token { type_: TokenType::LoopToken, value: Some(w), .. }) if w == "while")
+            {
+                return Err(String::from("do body is not followed by a while condition"));
+            }
+
+            match find_index_of_matching_parenthesis(loop_tokens, end_of_body_index + 2) {
+                Some(close_paren_index) => (
+                    &loop_tokens[end_of_body_index + 3..close_paren_index],
+                    &loop_tokens[1..=end_of_body_index],
+                ),
+                None => return Err(String::from("cannot find end of do-while condition")),
+            }
+        }
+        _ => return Err(format!("Unsupported loop: {}", loop_tokens[0])),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([match parse_expression(condition_tokens) {
+        Ok(valid_node) => valid_node,
+        Err(error) => return Err(error),
+    }]);
+    children.extend(match parse_embedded_statement(body_tokens) {
+        Ok(valid_nodes) => valid_nodes,
+        Err(error) => return Err(error),
+    });
+
+    Ok(SyntaxTreeNode {
+        value: loop_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Loop,
+        children,
+    })
+}
+
+fn parse_for_loop(loop_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let close_paren_index: usize = match find_index_of_matching_parenthesis(loop_tokens, 1) {
+        Some(index) => index,
+        None => return Err(String::from("cannot find end of for loop header")),
+    };
+
+    let header_tokens: &[Token] = &loop_tokens[2..close_paren_index];
+    let header_semicolon_indices: Vec<usize> = find_top_level_indices(header_tokens, |token| {
+        matches!(token.type_, TokenType::SemicolonToken)
+    });
+
+    if header_semicolon_indices.len() != 2 {
+        return Err(String::from(
+            "for loop header must contain an initializer, condition and iterator clause",
+        ));
+    }
+
+    let initializer_tokens: &[Token] = &header_tokens[..header_semicolon_indices[0]];
+    let condition_tokens: &[Token] =
+        &header_tokens[header_semicolon_indices[0] + 1..header_semicolon_indices[1]];
+    let iterator_tokens: &[Token] = &header_tokens[header_semicolon_indices[1] + 1..];
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([
+        SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Block,
+            children: match parse_variable_declarators(initializer_tokens) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        },
+        match condition_tokens.is_empty() {
+            true => SyntaxTreeNode {
+                value: Some(String::from("true")),
+                type_: SyntaxTreeNodeType::Literal,
+                children: vec![],
+            },
+            false => match parse_expression(condition_tokens) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+        },
+        SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Block,
+            children: match parse_collection(iterator_tokens) {
+                Ok(valid_nodes) => valid_nodes
+                    .into_iter()
+                    .map(discard_postfix_value)
+                    .collect::<Vec<SyntaxTreeNode>>(),
+                Err(error) => return Err(error),
+            },
+        },
+    ]);
+    children.extend(
+        match parse_embedded_statement(&loop_tokens[close_paren_index + 1..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: loop_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Loop,
+        children,
+    })
+}
+
+fn parse_foreach_loop(loop_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let close_paren_index: usize = match find_index_of_matching_parenthesis(loop_tokens, 1) {
+        Some(index) => index,
+        None => return Err(String::from("cannot find end of foreach loop header")),
+    };
+
+    let header_tokens: &[Token] = &loop_tokens[2..close_paren_index];
+    let in_index: usize = match find_top_level_indices(header_tokens, |token| {
+        matches!(token.type_, TokenType::NameIdentifierToken) && token.value.as_deref() == Some("in")
+    })
+    .first()
+    {
+        Some(index) => *index,
+        None => return Err(String::from("foreach loop header is missing the in keyword")),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([
+        match parse_iteration_variable(&header_tokens[..in_index]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        },
+        match parse_expression(&header_tokens[in_index + 1..]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        },
+    ]);
+    children.extend(
+        match parse_embedded_statement(&loop_tokens[close_paren_index + 1..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: loop_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Loop,
+        children,
+    })
+}
+
+fn parse_iteration_variable(variable_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    match (variable_tokens.first(), variable_tokens.last()) {
+        (Some(first), Some(_))
+            if matches!(first.type_, TokenType::OpenParenthesisToken)
+                && find_index_of_matching_parenthesis(variable_tokens, 0)
+                    == Some(variable_tokens.len() - 1) =>
+        {
+            let element_tokens: &[Token] = &variable_tokens[1..variable_tokens.len() - 1];
+            let mut comma_indices: Vec<usize> = find_top_level_indices(element_tokens, |token| {
+                matches!(token.type_, TokenType::CommaToken)
+            });
+            comma_indices.push(element_tokens.len());
+
+            let mut element_nodes: Vec<SyntaxTreeNode> = vec![];
+            let mut i: usize = 0;
+            for j in comma_indices {
+                element_nodes.push(match parse_iteration_variable(&element_tokens[i..j]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                });
+                i = j + 1;
+            }
+
+            Ok(SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::Collection,
+                children: element_nodes,
+            })
+        }
+        (Some(_), Some(last)) if matches!(last.type_, TokenType::NameIdentifierToken) => {
+            Ok(SyntaxTreeNode {
+                value: last.value.clone(),
+                type_: SyntaxTreeNodeType::Literal,
+                children: vec![],
+            })
+        }
+        (Some(first), Some(_))
+            if matches!(
+                first.type_,
+                TokenType::TypeDeclarationToken | TokenType::NameIdentifierToken
+            ) =>
+        {
+            parse_iteration_variable(&variable_tokens[1..])
+        }
+        _ => Err(String::from("Failed to parse foreach iteration variable")),
+    }
+}
+
+fn parse_variable_declarators(initializer_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut comma_indices: Vec<usize> = find_top_level_indices(initializer_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
+    });
+
+    if comma_indices.is_empty() {
+        return parse_collection(initializer_tokens);
+    }
+
+    let declared_type_token: Option<&Token> = match (
+        initializer_tokens.first(),
+        initializer_tokens.get(1),
+        initializer_tokens.get(2),
+    ) {
+        (Some(first), Some(second), Some(third))
+            if matches!(
+                first.type_,
+                TokenType::TypeDeclarationToken | TokenType::NameIdentifierToken
+            ) && matches!(second.type_, TokenType::NameIdentifierToken)
+                && matches!(third.type_, TokenType::AssignmentOperatorToken) =>
+        {
+            Some(first)
+        }
+        _ => None,
+    };
+
+    let mut initializer_nodes: Vec<SyntaxTreeNode> = vec![];
+    comma_indices.push(initializer_tokens.len());
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        let mut declarator_tokens: Vec<Token> = vec![];
+        if let Some(type_token) = declared_type_token.filter(|_| i > 0) {
+            declarator_tokens.push(type_token.clone());
+        }
+        declarator_tokens.extend_from_slice(&initializer_tokens[i..j]);
+
+        initializer_nodes.push(match parse_expression(&declarator_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        });
+        i = j + 1;
+    }
+
+    Ok(initializer_nodes)
+}
+
+fn parse_collection(collection_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut collection_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    if collection_tokens.is_empty() {
+        return Ok(collection_nodes);
+    }
+
+    let mut comma_indices: Vec<usize> = find_top_level_indices(collection_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
+    });
+    comma_indices.push(collection_tokens.len());
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        collection_nodes.push(match parse_expression(&collection_tokens[i..j]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        });
+        i = j + 1;
+    }
+
+    Ok(collection_nodes)
+}
+
+fn parse_try(try_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let end_of_try_body_index: usize = match find_end_of_statement(try_tokens, 1) {
+        Some(index) => index,
+        None => {
+            return Err(format!(
+                "cannot find end of try block on line {}",
+                try_tokens[0].line
+            ))
+        }
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+        value: try_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Block,
+        children: match parse_embedded_statement(&try_tokens[1..=end_of_try_body_index]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    }]);
+
+    let mut token_index: usize = end_of_try_body_index + 1;
+    while token_index < try_tokens.len() {
+        let clause_token: &Token = &try_tokens[token_index];
+
+        let body_start_index: usize = match clause_token.value.as_deref() {
+            Some("catch") => match find_catch_body_start(try_tokens, token_index + 1) {
+                Some(index) => index,
+                None => {
+                    return Err(format!(
+                        "cannot find body of catch clause on line {}",
+                        clause_token.line
+                    ))
+                }
+            },
+            Some("finally") => token_index + 1,
+            _ => {
+                return Err(format!(
+                    "expected catch or finally clause on line {}",
+                    clause_token.line
+                ))
+            }
+        };
+
+        let end_of_body_index: usize = match find_end_of_statement(try_tokens, body_start_index) {
+            Some(index) => index,
+            None => {
+                return Err(format!(
+                    "cannot find end of {} clause on line {}",
+                    clause_token.value.clone().unwrap(),
+                    clause_token.line
+                ))
+            }
+        };
+
+        let body_nodes: Vec<SyntaxTreeNode> =
+            match parse_embedded_statement(&try_tokens[body_start_index..=end_of_body_index]) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            };
+
+        children.push(match clause_token.value.as_deref() {
+            Some("catch") => match parse_catch(
+                &try_tokens[token_index + 1..body_start_index],
+                body_nodes,
+            ) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            },
+            _ => SyntaxTreeNode {
+                value: clause_token.value.clone(),
+                type_: SyntaxTreeNodeType::Block,
+                children: body_nodes,
+            },
+        });
+
+        token_index = end_of_body_index + 1;
+    }
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Try,
+        children,
+    })
+}
+
+fn parse_using(using_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let close_paren_index: usize = match find_index_of_matching_parenthesis(using_tokens, 1) {
+        Some(index) => index,
+        None => {
+            return Err(format!(
+                "cannot find end of using statement header on line {}",
+                using_tokens[0].line
+            ))
+        }
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Block,
+        children: match parse_variable_declarators(&using_tokens[2..close_paren_index]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    }]);
+    children.extend(
+        match parse_embedded_statement(&using_tokens[close_paren_index + 1..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Using,
+        children,
+    })
+}
+
+fn find_lambda_arrow(expression_tokens: &[Token]) -> Option<usize> {
+    let arrow_index: usize = match expression_tokens.first()?.type_ {
+        TokenType::NameIdentifierToken => 1,
+        TokenType::OpenParenthesisToken => {
+            find_index_of_matching_parenthesis(expression_tokens, 0)? + 1
+        }
+        _ => return None,
+    };
+
+    match expression_tokens.get(arrow_index)?.type_ {
+        TokenType::ArrowToken => Some(arrow_index),
+        _ => None,
+    }
+}
+
+fn parse_lambda(lambda_tokens: &[Token], arrow_index: usize) -> Result<SyntaxTreeNode, String> {
+    let parameter_tokens: &[Token] = match lambda_tokens[0].type_ {
+        TokenType::OpenParenthesisToken => &lambda_tokens[1..arrow_index - 1],
+        _ => &lambda_tokens[..arrow_index],
+    };
+    let body_tokens: &[Token] = &lambda_tokens[arrow_index + 1..];
+
+    if body_tokens.is_empty() {
+        return Err(format!(
+            "lambda on line {} has no body",
+            lambda_tokens[arrow_index].line
+        ));
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = match parse_method_arguments(parameter_tokens) {
+        Ok(valid_nodes) => valid_nodes,
+        Err(error) => return Err(error),
+    };
+    children.extend(match body_tokens[0].type_ {
+        TokenType::OpenScopeToken => match parse_embedded_statement(body_tokens) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+        _ => Vec::from([match parse_expression(body_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        }]),
+    });
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Lambda,
+        children,
+    })
+}
+
+fn parse_anonymous_method(delegate_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let body_start_index: usize = match delegate_tokens.get(1) {
+        Some(Token {
+            type_: TokenType::OpenParenthesisToken,
+            ..
+        }) => match find_index_of_matching_parenthesis(delegate_tokens, 1) {
+            Some(index) => index + 1,
+            None => {
+                return Err(format!(
+                    "cannot find end of anonymous method parameters on line {}",
+                    delegate_tokens[0].line
+                ))
+            }
+        },
+        _ => 1,
+    };
+
+    if !matches!(
+        delegate_tokens.get(body_start_index),
+        Some(Token {
+            type_: TokenType::OpenScopeToken,
+            ..
+        })
+    ) {
+        return Err(format!(
+            "anonymous method on line {} must have a block body",
+            delegate_tokens[0].line
+        ));
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = match body_start_index {
+        1 => vec![],
+        _ => match parse_method_arguments(&delegate_tokens[2..body_start_index - 1]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    };
+    children.extend(
+        match parse_embedded_statement(&delegate_tokens[body_start_index..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Lambda,
+        children,
+    })
+}
+
+fn parse_object_creation(creation_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    let mut type_name: Option<String> = None;
+    let mut index: usize = 1;
+
+    if let Some(Token {
+        type_: TokenType::NameIdentifierToken | TokenType::TypeDeclarationToken,
+        value,
+        ..
+    }) = creation_tokens.get(index)
+    {
+        type_name = value.clone();
+        index += 1;
+    }
+
+    let mut argument_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut has_type: bool = type_name.is_some();
+
+    if matches!(
+        creation_tokens.get(index),
+        Some(Token {
+            type_: TokenType::OpenCollectionToken,
+            ..
+        })
+    ) {
+        let close_bracket_index: usize = match find_index_of_matching_bracket(creation_tokens, index)
+        {
+            Some(close_index) => close_index,
+            None => return Err(String::from("cannot find end of array size")),
+        };
+
+        type_name = Some(format!("{}[]", type_name.unwrap_or_default()));
+        has_type = true;
+        argument_nodes.extend(
+            match parse_collection(&creation_tokens[index + 1..close_bracket_index]) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
+        index = close_bracket_index + 1;
+    }
+
+    if matches!(
+        creation_tokens.get(index),
+        Some(Token {
+            type_: TokenType::OpenParenthesisToken,
+            ..
+        })
+    ) {
+        let close_paren_index: usize =
+            match find_index_of_matching_parenthesis(creation_tokens, index) {
+                Some(close_index) => close_index,
+                None => return Err(String::from("cannot find end of constructor arguments")),
+            };
+
+        has_type = true;
+        argument_nodes.extend(
+            match parse_collection(&creation_tokens[index + 1..close_paren_index]) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
+        index = close_paren_index + 1;
+    }
+
+    if has_type {
+        children.push(SyntaxTreeNode {
+            value: type_name,
+            type_: SyntaxTreeNodeType::Type,
+            children: vec![],
+        });
+    }
+    children.extend(argument_nodes);
+
+    if matches!(
+        creation_tokens.get(index),
+        Some(Token {
+            type_: TokenType::OpenScopeToken,
+            ..
+        })
+    ) {
+        let close_scope_index: usize = match find_index_of_last_closing_scope(creation_tokens, index)
+        {
+            Some(close_index) => close_index,
+            None => return Err(String::from("cannot find end of object initializer")),
+        };
+
+        let initializer_tokens: &[Token] = &creation_tokens[index + 1..close_scope_index];
+        children.extend(match has_type {
+            true => match parse_object_initializer(initializer_tokens) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+            false => match parse_member_initializers(initializer_tokens) {
                 Ok(valid_nodes) => valid_nodes,
                 Err(error) => return Err(error),
             },
-        );
+        });
+        index = close_scope_index + 1;
+    }
+
+    if index != creation_tokens.len() || (!has_type && index == 1) {
+        return Err(format!(
+            "Failed to parse object creation on line {}",
+            creation_tokens[0].line
+        ));
+    }
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::ObjectCreation,
+        children,
+    })
+}
+
+fn parse_object_initializer(initializer_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    if is_member_initializer(initializer_tokens) {
+        return parse_member_initializers(initializer_tokens);
+    }
+
+    let mut element_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut comma_indices: Vec<usize> = find_top_level_indices(initializer_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
+    });
+    comma_indices.push(initializer_tokens.len());
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        let element_tokens: &[Token] = &initializer_tokens[i..j];
+        i = j + 1;
+
+        if element_tokens.is_empty() {
+            continue;
+        }
+
+        if matches!(element_tokens[0].type_, TokenType::OpenScopeToken)
+            && find_index_of_last_closing_scope(element_tokens, 0) == Some(element_tokens.len() - 1)
+        {
+            element_nodes.push(SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::Collection,
+                children: match parse_collection(&element_tokens[1..element_tokens.len() - 1]) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                },
+            });
+            continue;
+        }
+
+        element_nodes.push(match parse_expression(element_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        });
+    }
+
+    Ok(Vec::from([SyntaxTreeNode {
+        value: Some(String::from("initializer")),
+        type_: SyntaxTreeNodeType::Collection,
+        children: element_nodes,
+    }]))
+}
+
+fn is_member_initializer(initializer_tokens: &[Token]) -> bool {
+    match initializer_tokens {
+        [name_token, assignment_token, ..]
+            if matches!(name_token.type_, TokenType::NameIdentifierToken) =>
+        {
+            matches!(assignment_token, Token { type_: TokenType::AssignmentOperatorToken, value: None, .. })
+        }
+        [open_token, ..] if matches!(open_token.type_, TokenType::OpenCollectionToken) => {
+            find_index_of_matching_bracket(initializer_tokens, 0).is_some_and(|close_index| {
+                matches!(
+                    initializer_tokens.get(close_index + 1),
+                    Some(Token {
+                        type_: TokenType::AssignmentOperatorToken,
+                        value: None,
+                        ..
+                    })
+                )
+            })
+        }
+        _ => false,
+    }
+}
+
+fn find_index_of_matching_bracket(source_tokens: &[Token], open_index: usize) -> Option<usize> {
+    let mut open_bracket_count: u32 = 0;
+
+    for (index, token) in source_tokens.iter().enumerate().skip(open_index) {
+        match token.type_ {
+            TokenType::OpenCollectionToken => open_bracket_count += 1,
+            TokenType::CloseCollectionToken => {
+                open_bracket_count -= 1;
+                if open_bracket_count == 0 {
+                    return Some(index);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    None
+}
+
+fn parse_member_initializers(initializer_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut initializer_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut comma_indices: Vec<usize> = find_top_level_indices(initializer_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
+    });
+    comma_indices.push(initializer_tokens.len());
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        let member_tokens: &[Token] = &initializer_tokens[i..j];
+        i = j + 1;
+
+        if member_tokens.is_empty() {
+            continue;
+        }
+
+        if member_tokens.len() > 2
+            && matches!(member_tokens[0].type_, TokenType::NameIdentifierToken)
+            && matches!(&member_tokens[1], Token { type_: TokenType::AssignmentOperatorToken, value: None, .. })
+        {
+            initializer_nodes.push(SyntaxTreeNode {
+                value: member_tokens[0].value.clone(),
+                type_: SyntaxTreeNodeType::MemberInitializer,
+                children: Vec::from([match parse_expression(&member_tokens[2..]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                }]),
+            });
+            continue;
+        }
+
+        if let Some(close_bracket_index) = find_index_of_matching_bracket(member_tokens, 0).filter(
+            |close_index| {
+                matches!(member_tokens[0].type_, TokenType::OpenCollectionToken)
+                    && matches!(
+                        member_tokens.get(close_index + 1),
+                        Some(Token {
+                            type_: TokenType::AssignmentOperatorToken,
+                            value: None,
+                            ..
+                        })
+                    )
+            },
+        ) {
+            let mut children: Vec<SyntaxTreeNode> =
+                match parse_collection(&member_tokens[1..close_bracket_index]) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                };
+            children.push(match parse_expression(&member_tokens[close_bracket_index + 2..]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            });
+
+            initializer_nodes.push(SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::MemberInitializer,
+                children,
+            });
+            continue;
+        }
+
+        let projected_node: SyntaxTreeNode = match parse_expression(member_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        };
+
+        initializer_nodes.push(match (&projected_node.type_, &projected_node.value) {
+            (SyntaxTreeNodeType::MemberAccess | SyntaxTreeNodeType::Literal, Some(member_name)) => {
+                SyntaxTreeNode {
+                    value: Some(member_name.clone()),
+                    type_: SyntaxTreeNodeType::MemberInitializer,
+                    children: Vec::from([projected_node]),
+                }
+            }
+            _ => {
+                return Err(format!(
+                    "anonymous type member on line {} needs a name",
+                    member_tokens[0].line
+                ))
+            }
+        });
+    }
+
+    Ok(initializer_nodes)
+}
+
+fn is_query_start(expression_tokens: &[Token]) -> bool {
+    matches!(
+        expression_tokens,
+        [from_token, _, in_token, ..] | [from_token, _, _, in_token, ..]
+            if is_contextual_keyword(from_token, "from")
+                && is_contextual_keyword(in_token, "in")
+    )
+}
+
+fn find_query_start(expression_tokens: &[Token]) -> Option<usize> {
+    find_top_level_indices(expression_tokens, |token| is_contextual_keyword(token, "from"))
+        .into_iter()
+        .find(|from_index| is_query_start(&expression_tokens[*from_index..]))
+}
+
+fn parse_query(query_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let mut clause_start_indices: Vec<usize> = find_top_level_indices(query_tokens, |token| {
+        QUERY_CLAUSE_KEYWORDS
+            .iter()
+            .any(|keyword| is_contextual_keyword(token, keyword))
+    });
+    clause_start_indices.push(query_tokens.len());
+
+    let mut clause_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    for clause_bounds in clause_start_indices.windows(2) {
+        let clause_tokens: &[Token] = &query_tokens[clause_bounds[0]..clause_bounds[1]];
+        let line: usize = clause_tokens[0].line;
+
+        let clause_node: SyntaxTreeNode = match parse_query_clause(clause_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        };
+
+        let previous_keyword: Option<&str> = clause_nodes
+            .last()
+            .and_then(|previous_node| previous_node.value.as_deref());
+        match (clause_node.value.as_deref(), previous_keyword) {
+            (Some("into"), Some("join")) => {
+                let join_node: &mut SyntaxTreeNode = clause_nodes.last_mut().unwrap();
+                if join_node.children.len() > 4 {
+                    return Err(format!("`join` on line {} has more than one `into`", line));
+                }
+                join_node.children.extend(clause_node.children);
+            }
+            (Some("into"), Some("select" | "group")) => clause_nodes.push(clause_node),
+            (Some("into"), _) => {
+                return Err(format!(
+                    "`into` on line {} must follow a select, group or join clause",
+                    line
+                ))
+            }
+            (Some(keyword), Some(previous_keyword @ ("select" | "group"))) => {
+                return Err(format!(
+                    "`{}` on line {} follows a {} clause without `into`",
+                    keyword, line, previous_keyword
+                ))
+            }
+            _ => clause_nodes.push(clause_node),
+        }
+    }
+
+    if !matches!(
+        clause_nodes
+            .last()
+            .and_then(|last_node| last_node.value.as_deref()),
+        Some("select" | "group")
+    ) {
+        return Err(format!(
+            "query expression on line {} must end with a select or group clause",
+            query_tokens[0].line
+        ));
+    }
+
+    Ok(SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Query,
+        children: clause_nodes,
+    })
+}
+
+fn parse_query_clause(clause_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let keyword: &str = clause_tokens[0].value.as_deref().unwrap();
+    let line: usize = clause_tokens[0].line;
+
+    let find_keyword = |keyword: &str| -> Result<usize, String> {
+        match find_top_level_indices(clause_tokens, |token| is_contextual_keyword(token, keyword))
+            .first()
+        {
+            Some(index) => Ok(*index),
+            None => Err(format!("query clause on line {} is missing `{}`", line, keyword)),
+        }
+    };
+    let parse_part = |part_tokens: &[Token]| -> Result<SyntaxTreeNode, String> {
+        match part_tokens.is_empty() {
+            true => Err(format!("incomplete `{}` query clause on line {}", keyword, line)),
+            false => parse_expression(part_tokens),
+        }
+    };
+    let create_range_variable_node = |name_token: &Token| -> SyntaxTreeNode {
+        SyntaxTreeNode {
+            value: name_token.value.clone(),
+            type_: SyntaxTreeNodeType::Literal,
+            children: vec![],
+        }
+    };
+
+    let parts: Vec<Result<SyntaxTreeNode, String>> = match keyword {
+        "from" | "join" => {
+            let in_index: usize = match find_keyword("in") {
+                Ok(index) => index,
+                Err(error) => return Err(error),
+            };
+
+            match keyword {
+                "from" => Vec::from([
+                    Ok(create_range_variable_node(&clause_tokens[in_index - 1])),
+                    parse_part(&clause_tokens[in_index + 1..]),
+                ]),
+                _ => {
+                    let (on_index, equals_index): (usize, usize) =
+                        match (find_keyword("on"), find_keyword("equals")) {
+                            (Ok(on_index), Ok(equals_index)) => (on_index, equals_index),
+                            (Err(error), _) | (_, Err(error)) => return Err(error),
+                        };
+
+                    Vec::from([
+                        Ok(create_range_variable_node(&clause_tokens[in_index - 1])),
+                        parse_part(&clause_tokens[in_index + 1..on_index]),
+                        parse_part(&clause_tokens[on_index + 1..equals_index]),
+                        parse_part(&clause_tokens[equals_index + 1..]),
+                    ])
+                }
+            }
+        }
+        "let" => match clause_tokens.get(2) {
+            Some(Token {
+                type_: TokenType::AssignmentOperatorToken,
+                value: None,
+                ..
+            }) => Vec::from([
+                Ok(create_range_variable_node(&clause_tokens[1])),
+                parse_part(&clause_tokens[3..]),
+            ]),
+            _ => return Err(format!("malformed `let` query clause on line {}", line)),
+        },
+        "group" => {
+            let by_index: usize = match find_keyword("by") {
+                Ok(index) => index,
+                Err(error) => return Err(error),
+            };
+
+            Vec::from([
+                parse_part(&clause_tokens[1..by_index]),
+                parse_part(&clause_tokens[by_index + 1..]),
+            ])
+        }
+        "orderby" => {
+            let mut comma_indices: Vec<usize> = find_top_level_indices(clause_tokens, |token| {
+                matches!(token.type_, TokenType::CommaToken)
+            });
+            comma_indices.push(clause_tokens.len());
+
+            let mut ordering_parts: Vec<Result<SyntaxTreeNode, String>> = vec![];
+            let mut i: usize = 1;
+            for j in comma_indices {
+                let ordering_tokens: &[Token] = &clause_tokens[i..j];
+                i = j + 1;
+
+                let (key_tokens, direction): (&[Token], &str) = match ordering_tokens.last() {
+                    Some(direction_token) if is_contextual_keyword(direction_token, "descending") => {
+                        (&ordering_tokens[..ordering_tokens.len() - 1], "descending")
+                    }
+                    Some(direction_token) if is_contextual_keyword(direction_token, "ascending") => {
+                        (&ordering_tokens[..ordering_tokens.len() - 1], "ascending")
+                    }
+                    _ => (ordering_tokens, "ascending"),
+                };
+
+                ordering_parts.push(match parse_part(key_tokens) {
+                    Ok(key_node) => Ok(SyntaxTreeNode {
+                        value: Some(String::from(direction)),
+                        type_: SyntaxTreeNodeType::QueryClause,
+                        children: Vec::from([key_node]),
+                    }),
+                    Err(error) => Err(error),
+                });
+            }
+
+            ordering_parts
+        }
+        "into" => match clause_tokens {
+            [_, name_token] => Vec::from([Ok(create_range_variable_node(name_token))]),
+            _ => return Err(format!("malformed `into` query clause on line {}", line)),
+        },
+        _ => Vec::from([parse_part(&clause_tokens[1..])]),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    for part in parts {
+        children.push(match part {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        });
+    }
+
+    Ok(SyntaxTreeNode {
+        value: Some(keyword.to_string()),
+        type_: SyntaxTreeNodeType::QueryClause,
+        children,
+    })
+}
+
+fn parse_catch(
+    declaration_tokens: &[Token],
+    body_nodes: Vec<SyntaxTreeNode>,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    let mut exception_name: Option<String> = None;
+    let mut token_index: usize = 0;
+
+    if let Some(Token {
+        type_: TokenType::OpenParenthesisToken,
+        ..
+    }) = declaration_tokens.first()
+    {
+        let close_parenthesis_index: usize =
+            find_index_of_matching_parenthesis(declaration_tokens, 0).unwrap();
+
+        match &declaration_tokens[1..close_parenthesis_index] {
+            [type_token] => children.push(create_type_node(type_token)),
+            [type_token, name_token] => {
+                children.push(create_type_node(type_token));
+                exception_name = name_token.value.clone();
+            }
+            _ => {
+                return Err(format!(
+                    "Unsupported catch declaration on line {}",
+                    declaration_tokens[0].line
+                ))
+            }
+        }
+
+        token_index = close_parenthesis_index + 1;
+    }
+
+    if token_index < declaration_tokens.len() {
+        children.push(SyntaxTreeNode {
+            value: declaration_tokens[token_index].value.clone(),
+            type_: SyntaxTreeNodeType::Block,
+            children: Vec::from([match parse_expression(
+                &declaration_tokens[token_index + 2..declaration_tokens.len() - 1],
+            ) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            }]),
+        });
+    }
+
+    if exception_name.is_none() && body_nodes.iter().any(contains_rethrow) {
+        exception_name = Some(String::from("ex"));
+    }
+
+    match &exception_name {
+        Some(name) => children.extend(
+            body_nodes
+                .into_iter()
+                .map(|body_node| bind_rethrow(body_node, name)),
+        ),
+        None => children.extend(body_nodes),
+    }
+
+    Ok(SyntaxTreeNode {
+        value: exception_name,
+        type_: SyntaxTreeNodeType::Catch,
+        children,
+    })
+}
+
+fn contains_rethrow(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Throw => syntax_tree_node.children.is_empty(),
+        _ => syntax_tree_node.children.iter().any(contains_rethrow),
+    }
+}
+
+fn bind_rethrow(syntax_tree_node: SyntaxTreeNode, exception_name: &str) -> SyntaxTreeNode {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Throw if syntax_tree_node.children.is_empty() => SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Throw,
+            children: Vec::from([SyntaxTreeNode {
+                value: Some(exception_name.to_string()),
+                type_: SyntaxTreeNodeType::Literal,
+                children: vec![],
+            }]),
+        },
+        _ => SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children: syntax_tree_node
+                .children
+                .into_iter()
+                .map(|child| bind_rethrow(child, exception_name))
+                .collect::<Vec<SyntaxTreeNode>>(),
+        },
+    }
+}
+
+fn parse_switch(switch_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let close_parenthesis_index: usize = match find_index_of_matching_parenthesis(switch_tokens, 1)
+    {
+        Some(index) => index,
+        None => return Err(String::from("cannot find end of switch subject")),
+    };
+
+    let close_scope_index: usize =
+        match find_index_of_last_closing_scope(switch_tokens, close_parenthesis_index + 1) {
+            Some(index) => index,
+            None => return Err(String::from("cannot find end of switch body")),
+        };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([
+        match parse_expression(&switch_tokens[2..close_parenthesis_index]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        },
+    ]);
+
+    let section_tokens: &[Token] = &switch_tokens[close_parenthesis_index + 2..close_scope_index];
+
+    let mut token_index: usize = 0;
+    while token_index < section_tokens.len() {
+        let section_line: usize = section_tokens[token_index].line;
+        let mut section_nodes: Vec<SyntaxTreeNode> = vec![];
+
+        while matches!(
+            section_tokens.get(token_index),
+            Some(Token {
+                type_: TokenType::SwitchLabelToken,
+                ..
+            })
+        ) {
+            let colon_index: usize =
+                match find_top_level_indices(&section_tokens[token_index..], |token| {
+                    matches!(token.type_, TokenType::ColonToken)
+                })
+                .first()
+                {
+                    Some(index) => index + token_index,
+                    None => {
+                        return Err(format!(
+                            "cannot find end of switch label on line {}",
+                            section_tokens[token_index].line
+                        ))
+                    }
+                };
+
+            section_nodes.push(
+                match parse_switch_label(&section_tokens[token_index..colon_index]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                },
+            );
+            token_index = colon_index + 1;
+        }
+
+        if section_nodes.is_empty() {
+            return Err(format!(
+                "expected case or default label in switch on line {}",
+                section_line
+            ));
+        }
+
+        let body_start_index: usize = token_index;
+        while token_index < section_tokens.len()
+            && !matches!(
+                section_tokens[token_index].type_,
+                TokenType::SwitchLabelToken
+            )
+        {
+            token_index = match section_tokens[token_index].type_ {
+                TokenType::CommentToken => token_index + 1,
+                _ => match find_end_of_statement(section_tokens, token_index) {
+                    Some(index) => index + 1,
+                    None => {
+                        return Err(format!(
+                            "cannot find end of statement at {}",
+                            section_tokens[token_index]
+                        ))
+                    }
+                },
+            };
+        }
+        let body_tokens: &[Token] = &section_tokens[body_start_index..token_index];
+
+        if let Some(goto_index) = body_tokens
+            .iter()
+            .position(|token| matches!(token.type_, TokenType::GotoToken))
+        {
+            return Err(format!(
+                "Unsupported fall-through in switch: `{}` on line {} cannot be translated",
+                body_tokens[goto_index..]
+                    .iter()
+                    .take_while(|token| !matches!(token.type_, TokenType::SemicolonToken))
+                    .filter_map(|token| token.value.clone())
+                    .collect::<Vec<String>>()
+                    .join(" "),
+                body_tokens[goto_index].line
+            ));
+        }
+
+        let mut body_nodes: Vec<SyntaxTreeNode> = match parse_internal_scope(body_tokens) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        };
+
+        if let Some(last_statement_index) = body_nodes
+            .iter()
+            .rposition(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment))
+        {
+            if matches!(
+                (
+                    &body_nodes[last_statement_index].type_,
+                    body_nodes[last_statement_index].value.as_deref()
+                ),
+                (SyntaxTreeNodeType::LoopControl, Some("break"))
+            ) {
+                body_nodes.remove(last_statement_index);
+            }
+        }
+
+        if body_nodes.iter().any(contains_switch_break) {
+            return Err(format!(
+                "Unsupported control flow in switch section on line {}: `break` must be the last statement of the section",
+                section_line
+            ));
+        }
+
+        section_nodes.extend(body_nodes);
+
+        children.push(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::SwitchSection,
+            children: section_nodes,
+        });
+    }
+
+    Ok(SyntaxTreeNode {
+        value: switch_tokens[0].value.clone(),
+        type_: SyntaxTreeNodeType::Switch,
+        children,
+    })
+}
+
+fn parse_switch_label(label_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    match label_tokens[0].value.as_deref() {
+        Some("default") => Ok(SyntaxTreeNode {
+            value: label_tokens[0].value.clone(),
+            type_: SyntaxTreeNodeType::SwitchLabel,
+            children: vec![],
+        }),
+        _ => parse_case_label(&label_tokens[1..], label_tokens[0].line),
+    }
+}
+
+fn parse_case_label(label_tokens: &[Token], line: usize) -> Result<SyntaxTreeNode, String> {
+    let (pattern_tokens, guard_tokens): (&[Token], &[Token]) =
+        match find_top_level_indices(label_tokens, |token| is_contextual_keyword(token, "when"))
+            .first()
+        {
+            Some(when_index) => (
+                &label_tokens[..*when_index],
+                &label_tokens[when_index + 1..],
+            ),
+            None => (label_tokens, &[]),
+        };
+
+    if pattern_tokens.is_empty() {
+        return Err(format!("switch label on line {} has no pattern", line));
+    }
+
+    let pattern_node: SyntaxTreeNode = match parse_pattern(pattern_tokens) {
+        Ok(valid_node) => valid_node,
+        Err(error) => return Err(error),
+    };
 
+    if guard_tokens.is_empty() && pattern_node.value.as_deref() == Some("discard") {
         return Ok(SyntaxTreeNode {
-            value: expression_tokens[0].value.clone(),
-            type_: SyntaxTreeNodeType::Branch,
-            children,
+            value: Some(String::from("default")),
+            type_: SyntaxTreeNodeType::SwitchLabel,
+            children: vec![],
         });
     }
 
-    if matches!(&expression_tokens[0], Token { type_: TokenType::BranchingOperatorToken, value: Some(i)} if i == "else")
-    {
-        return Ok(SyntaxTreeNode {
-            value: expression_tokens[0].value.clone(),
-            type_: SyntaxTreeNodeType::Branch,
-            children: match parse_internal_scope(&expression_tokens[2..]) {
-                Ok(valid_nodes) => valid_nodes,
-                Err(error) => return Err(error),
-            },
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([pattern_node]);
+
+    if !guard_tokens.is_empty() {
+        children.push(match parse_expression(guard_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
         });
     }
 
-    if let Some(assignment_index) = expression_tokens
-        .iter()
-        .position(|token| matches!(token.type_, TokenType::AssignmentOperatorToken))
-    {
-        if matches!(
-            expression_tokens[assignment_index - 1].type_,
-            TokenType::NameIdentifierToken
-        ) {
-            let child_assignment_node = match expression_tokens[assignment_index].value {
-                None => match parse_expression(&expression_tokens[(assignment_index + 1)..]) {
+    Ok(SyntaxTreeNode {
+        value: Some(String::from("case")),
+        type_: SyntaxTreeNodeType::SwitchLabel,
+        children,
+    })
+}
+
+fn parse_switch_expression(
+    expression_tokens: &[Token],
+    switch_index: usize,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> =
+        Vec::from(
+            [match parse_expression(&expression_tokens[..switch_index]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            }],
+        );
+
+    let arm_tokens: &[Token] = &expression_tokens[switch_index + 2..expression_tokens.len() - 1];
+
+    let mut comma_indices: Vec<usize> = find_top_level_indices(arm_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
+    });
+    comma_indices.push(arm_tokens.len());
+
+    let mut i: usize = 0;
+    for j in comma_indices {
+        let arm: &[Token] = &arm_tokens[i..j];
+        i = j + 1;
+
+        if arm.is_empty() {
+            continue;
+        }
+
+        let arrow_index: usize =
+            match find_top_level_indices(arm, |token| matches!(token.type_, TokenType::ArrowToken))
+                .first()
+            {
+                Some(index) => *index,
+                None => {
+                    return Err(format!(
+                        "switch expression arm on line {} has no `=>`",
+                        arm[0].line
+                    ))
+                }
+            };
+
+        children.push(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::SwitchSection,
+            children: Vec::from([
+                match parse_case_label(&arm[..arrow_index], arm[0].line) {
                     Ok(valid_node) => valid_node,
                     Err(error) => return Err(error),
                 },
-                Some(_) => SyntaxTreeNode {
-                    value: Some("=".to_string()),
-                    type_: SyntaxTreeNodeType::Expression,
-                    children: Vec::from([
-                        match parse_expression(
-                            &expression_tokens[assignment_index - 1..assignment_index],
-                        ) {
-                            Ok(valid_node) => valid_node,
-                            Err(error) => return Err(error),
-                        },
-                        match parse_expression(&expression_tokens[assignment_index + 1..]) {
-                            Ok(valid_node) => valid_node,
-                            Err(error) => return Err(error),
-                        },
-                    ]),
+                match parse_expression(&arm[arrow_index + 1..]) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
                 },
-            };
+            ]),
+        });
+    }
 
-            return Ok(SyntaxTreeNode {
-                value: expression_tokens[assignment_index - 1].value.clone(),
-                type_: SyntaxTreeNodeType::Assignment,
-                children: Vec::from([child_assignment_node]),
-            });
+    Ok(SyntaxTreeNode {
+        value: Some(String::from("switch expression")),
+        type_: SyntaxTreeNodeType::Switch,
+        children,
+    })
+}
+
+fn parse_pattern(pattern_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    if pattern_tokens.is_empty() {
+        return Err(String::from("Failed to parse empty pattern"));
+    }
+
+    for combinator in ["or", "and"] {
+        if let Some(combinator_index) = find_top_level_indices(pattern_tokens, |token| {
+            is_contextual_keyword(token, combinator)
+        })
+        .last()
+        .copied()
+        {
+            return Ok(create_pattern_node(
+                combinator,
+                Vec::from([
+                    match parse_pattern(&pattern_tokens[..combinator_index]) {
+                        Ok(valid_node) => valid_node,
+                        Err(error) => return Err(error),
+                    },
+                    match parse_pattern(&pattern_tokens[combinator_index + 1..]) {
+                        Ok(valid_node) => valid_node,
+                        Err(error) => return Err(error),
+                    },
+                ]),
+            ));
         }
     }
 
-    if matches!(expression_tokens[0].type_, TokenType::NameIdentifierToken)
-        && matches!(expression_tokens[1].type_, TokenType::OpenParenthesisToken)
-        && matches!(
-            expression_tokens.last().unwrap().type_,
-            TokenType::CloseParenthesisToken
-        )
-    {
-        return Ok(SyntaxTreeNode {
-            value: expression_tokens[0].value.clone(),
-            type_: SyntaxTreeNodeType::Expression,
-            children: match parse_collection(&expression_tokens[2..expression_tokens.len() - 1]) {
+    if is_contextual_keyword(&pattern_tokens[0], "not") {
+        return Ok(create_pattern_node(
+            "not",
+            Vec::from([match parse_pattern(&pattern_tokens[1..]) {
                 Ok(valid_node) => valid_node,
                 Err(error) => return Err(error),
-            },
-        });
+            }]),
+        ));
     }
 
-    if matches!(expression_tokens[0].type_, TokenType::OpenCollectionToken)
-        && matches!(
-            expression_tokens[expression_tokens.len() - 1].type_,
-            TokenType::CloseCollectionToken
-        )
+    if let Token {
+        type_: TokenType::RelationalOperatorToken,
+        value: Some(operator),
+        ..
+    } = &pattern_tokens[0]
     {
-        return Ok(SyntaxTreeNode {
-            value: None,
-            type_: SyntaxTreeNodeType::Collection,
-            children: match parse_collection(&expression_tokens[1..expression_tokens.len() - 1]) {
+        return Ok(create_pattern_node(
+            operator,
+            Vec::from([match parse_expression(&pattern_tokens[1..]) {
                 Ok(valid_node) => valid_node,
                 Err(error) => return Err(error),
-            },
-        });
+            }]),
+        ));
     }
 
-    if expression_tokens
-        .iter()
-        .any(|token| matches!(token.type_, TokenType::NumericOperationToken))
+    if matches!(pattern_tokens[0].type_, TokenType::OpenCollectionToken)
+        && matches!(
+            pattern_tokens[pattern_tokens.len() - 1].type_,
+            TokenType::CloseCollectionToken
+        )
     {
-        return parse_numerical_expression(expression_tokens);
+        return parse_list_pattern(&pattern_tokens[1..pattern_tokens.len() - 1]);
     }
-    // if let Some(numeric_operator_index) = expression_tokens
-    //     .iter()
-    //     .position(|token| matches!(token.type_, TokenType::NumericOperationToken))
-    // {
-    //     return Ok(SyntaxTreeNode {
-    //         value: expression_tokens[numeric_operator_index].value.clone(),
-    //         type_: SyntaxTreeNodeType::Expression,
-    //         children: Vec::from([
-    //             match parse_expression(&expression_tokens[..numeric_operator_index]) {
-    //                 Ok(valid_node) => valid_node,
-    //                 Err(error) => return Err(error),
-    //             },
-    //             match parse_expression(&expression_tokens[numeric_operator_index + 1..]) {
-    //                 Ok(valid_node) => valid_node,
-    //                 Err(error) => return Err(error),
-    //             },
-    //         ]),
-    //     });
-    // }
-
-    if let Some(equality_index) = expression_tokens
-        .iter()
-        .position(|token| matches!(token.type_, TokenType::EqualityOperatorToken))
-    {
-        return Ok(SyntaxTreeNode {
-            value: Some("=".to_string()),
-            type_: SyntaxTreeNodeType::EqualityCheck,
-            children: Vec::from([
-                match parse_expression(&expression_tokens[..equality_index]) {
-                    Ok(valid_node) => valid_node,
-                    Err(error) => return Err(error),
-                },
-                match parse_expression(&expression_tokens[(equality_index + 1)..]) {
-                    Ok(valid_node) => valid_node,
-                    Err(error) => return Err(error),
-                },
-            ]),
-        });
+
+    match pattern_tokens {
+        [Token {
+            value: Some(discard),
+            ..
+        }] if discard == "_" => return Ok(create_pattern_node("discard", vec![])),
+        [Token {
+            type_: TokenType::TypeDeclarationToken,
+            value: Some(var),
+            ..
+        }, name_token @ Token {
+            type_: TokenType::NameIdentifierToken,
+            ..
+        }] if var == "var" => {
+            return Ok(create_pattern_node(
+                "var",
+                Vec::from([create_capture_node(name_token)]),
+            ))
+        }
+        [type_token, name_token @ Token {
+            type_: TokenType::NameIdentifierToken,
+            ..
+        }] if is_pattern_type_token(type_token) => {
+            return Ok(create_pattern_node(
+                "type",
+                Vec::from([
+                    create_type_node(type_token),
+                    create_capture_node(name_token),
+                ]),
+            ))
+        }
+        [type_token] if is_pattern_type_token(type_token) => {
+            return Ok(create_pattern_node(
+                "type",
+                Vec::from([create_type_node(type_token)]),
+            ))
+        }
+        [type_name, Token {
+            type_: TokenType::DotMethodToken,
+            ..
+        }, member_name] => {
+            return Ok(create_pattern_node(
+                "constant",
+                Vec::from([SyntaxTreeNode {
+                    value: Some(format!(
+                        "{}.{}",
+                        type_name.value.clone().unwrap_or_default(),
+                        member_name.value.clone().unwrap_or_default()
+                    )),
+                    type_: SyntaxTreeNodeType::Literal,
+                    children: vec![],
+                }]),
+            ))
+        }
+        _ => {}
     }
 
-    if matches!(expression_tokens[0].type_, TokenType::NameIdentifierToken)
-        && matches!(expression_tokens[1].type_, TokenType::DotMethodToken)
-        && matches!(expression_tokens[2].type_, TokenType::NameIdentifierToken)
-    {
-        let mut children: Vec<SyntaxTreeNode> = vec![];
-        children.push(match parse_expression(&expression_tokens[0..=0]) {
-            Ok(valid_node) => valid_node,
+    let (type_node, recursive_tokens): (Option<SyntaxTreeNode>, &[Token]) = match pattern_tokens {
+        [type_token, open_token, ..]
+            if is_pattern_type_token(type_token)
+                && matches!(
+                    open_token.type_,
+                    TokenType::OpenScopeToken | TokenType::OpenParenthesisToken
+                ) =>
+        {
+            (Some(create_type_node(type_token)), &pattern_tokens[1..])
+        }
+        _ => (None, pattern_tokens),
+    };
+
+    let close_index: Option<usize> = match recursive_tokens[0].type_ {
+        TokenType::OpenScopeToken => find_index_of_last_closing_scope(recursive_tokens, 0),
+        TokenType::OpenParenthesisToken => find_index_of_matching_parenthesis(recursive_tokens, 0),
+        _ => None,
+    };
+
+    if let Some(close_index) = close_index {
+        let inner_tokens: &[Token] = &recursive_tokens[1..close_index];
+        let capture_tokens: &[Token] = &recursive_tokens[close_index + 1..];
+
+        let is_positional: bool =
+            matches!(recursive_tokens[0].type_, TokenType::OpenParenthesisToken);
+
+        if is_positional
+            && type_node.is_none()
+            && capture_tokens.is_empty()
+            && find_top_level_indices(inner_tokens, |token| {
+                matches!(token.type_, TokenType::CommaToken)
+            })
+            .is_empty()
+        {
+            return parse_pattern(inner_tokens);
+        }
+
+        let mut children: Vec<SyntaxTreeNode> =
+            type_node.into_iter().collect::<Vec<SyntaxTreeNode>>();
+        let subpattern_nodes: Result<Vec<SyntaxTreeNode>, String> = match is_positional {
+            true => parse_positional_subpatterns(inner_tokens),
+            false => parse_property_subpatterns(inner_tokens),
+        };
+        children.extend(match subpattern_nodes {
+            Ok(valid_nodes) => valid_nodes,
             Err(error) => return Err(error),
         });
-        children.extend(
-            match parse_collection(&expression_tokens[4..expression_tokens.len() - 1]) {
-                Ok(valid_node) => valid_node,
-                Err(error) => return Err(error),
-            },
-        );
 
-        return Ok(SyntaxTreeNode {
-            value: expression_tokens[2].value.clone(),
-            type_: SyntaxTreeNodeType::Expression,
+        match capture_tokens {
+            [] => {}
+            [name_token @ Token {
+                type_: TokenType::NameIdentifierToken,
+                ..
+            }] => children.push(create_capture_node(name_token)),
+            _ => {
+                return Err(format!(
+                    "Failed to parse pattern designation on line {}",
+                    capture_tokens[0].line
+                ))
+            }
+        }
+
+        return Ok(create_pattern_node(
+            match is_positional {
+                true => "positional",
+                false => "property",
+            },
             children,
-        });
+        ));
     }
 
-    Err(format!(
-        "Failed to parse expressions: {}",
-        expression_tokens
-            .iter()
-            .map(|token| token.to_string())
-            .collect::<Vec<String>>()
-            .join(", ")
+    Ok(create_pattern_node(
+        "constant",
+        Vec::from([match parse_expression(pattern_tokens) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        }]),
     ))
 }
 
-fn parse_numerical_expression(expression_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
-    let mut numeric_operators: Vec<(usize, &Token)> = expression_tokens
-        .iter()
-        .enumerate()
-        .filter(|(_, token)| matches!(token.type_, TokenType::NumericOperationToken))
-        .collect::<Vec<(usize, &Token)>>();
-
-    numeric_operators.sort_by_key(|(index, token)| match (index, token.value.as_deref()) {
-        (_, Some("/")) => 4,
-        (_, Some("*")) => 3,
-        (_, Some("+")) => 2,
-        (_, Some("-")) => 1,
-        _ => 0,
+fn parse_positional_subpatterns(
+    subpattern_tokens: &[Token],
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut subpattern_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    if subpattern_tokens.is_empty() {
+        return Ok(subpattern_nodes);
+    }
+
+    let mut comma_indices: Vec<usize> = find_top_level_indices(subpattern_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
     });
+    comma_indices.push(subpattern_tokens.len());
 
-    Ok(SyntaxTreeNode {
-        value: numeric_operators[0].1.value.clone(),
-        type_: SyntaxTreeNodeType::Expression,
-        children: Vec::from([
-            match parse_expression(&expression_tokens[..numeric_operators[0].0]) {
-                Ok(valid_node) => valid_node,
-                Err(error) => return Err(error),
-            },
-            match parse_expression(&expression_tokens[numeric_operators[0].0 + 1..]) {
-                Ok(valid_node) => valid_node,
-                Err(error) => return Err(error),
-            },
-        ]),
-    })
+    let mut i: usize = 0;
+    for j in comma_indices {
+        subpattern_nodes.push(match parse_pattern(&subpattern_tokens[i..j]) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        });
+        i = j + 1;
+    }
+
+    Ok(subpattern_nodes)
 }
 
-fn parse_collection(collection_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
-    let mut collection_nodes: Vec<SyntaxTreeNode> = vec![];
+fn parse_property_subpatterns(subpattern_tokens: &[Token]) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut property_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut comma_indices: Vec<usize> = find_top_level_indices(subpattern_tokens, |token| {
+        matches!(token.type_, TokenType::CommaToken)
+    });
+    comma_indices.push(subpattern_tokens.len());
 
     let mut i: usize = 0;
-    for j in i..collection_tokens.len() + 1 {
-        if j < collection_tokens.len()
-            && !matches!(collection_tokens[j].type_, TokenType::CommaToken)
-        {
+    for j in comma_indices {
+        let member_tokens: &[Token] = &subpattern_tokens[i..j];
+        i = j + 1;
+
+        if member_tokens.is_empty() {
             continue;
         }
 
-        collection_nodes.push(match parse_expression(&collection_tokens[i..j]) {
-            Ok(valid_node) => valid_node,
-            Err(error) => return Err(error),
+        let colon_index: usize = match find_top_level_indices(member_tokens, |token| {
+            matches!(token.type_, TokenType::ColonToken)
+        })
+        .first()
+        {
+            Some(index) => *index,
+            None => {
+                return Err(format!(
+                    "property pattern member on line {} has no `:`",
+                    member_tokens[0].line
+                ))
+            }
+        };
+
+        let property_path: Vec<String> = member_tokens[..colon_index]
+            .iter()
+            .filter(|token| matches!(token.type_, TokenType::NameIdentifierToken))
+            .filter_map(|token| token.value.clone())
+            .collect::<Vec<String>>();
+
+        let mut property_node: SyntaxTreeNode =
+            match parse_pattern(&member_tokens[colon_index + 1..]) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            };
+
+        for (depth, property_name) in property_path.iter().enumerate().rev() {
+            property_node = SyntaxTreeNode {
+                value: Some(property_name.clone()),
+                type_: SyntaxTreeNodeType::PatternProperty,
+                children: Vec::from([property_node]),
+            };
+
+            if depth > 0 {
+                property_node = create_pattern_node("property", Vec::from([property_node]));
+            }
+        }
+
+        property_nodes.push(property_node);
+    }
+
+    Ok(property_nodes)
+}
+
+fn parse_list_pattern(element_tokens: &[Token]) -> Result<SyntaxTreeNode, String> {
+    let mut element_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    if !element_tokens.is_empty() {
+        let mut comma_indices: Vec<usize> = find_top_level_indices(element_tokens, |token| {
+            matches!(token.type_, TokenType::CommaToken)
         });
-        i = j + 1;
+        comma_indices.push(element_tokens.len());
+
+        let mut i: usize = 0;
+        for j in comma_indices {
+            let element: &[Token] = &element_tokens[i..j];
+            i = j + 1;
+
+            element_nodes.push(match element {
+                [Token {
+                    type_: TokenType::DotMethodToken,
+                    ..
+                }, Token {
+                    type_: TokenType::DotMethodToken,
+                    ..
+                }, slice_tokens @ ..] => create_pattern_node(
+                    "slice",
+                    match slice_tokens.is_empty() {
+                        true => vec![],
+                        false => Vec::from([match parse_pattern(slice_tokens) {
+                            Ok(valid_node) => valid_node,
+                            Err(error) => return Err(error),
+                        }]),
+                    },
+                ),
+                _ => match parse_pattern(element) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                },
+            });
+        }
     }
 
-    Ok(collection_nodes)
+    Ok(create_pattern_node("list", element_nodes))
+}
+
+fn create_pattern_node(kind: &str, children: Vec<SyntaxTreeNode>) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(kind.to_string()),
+        type_: SyntaxTreeNodeType::Pattern,
+        children,
+    }
+}
+
+fn create_type_node(type_token: &Token) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: type_token.value.clone(),
+        type_: SyntaxTreeNodeType::Type,
+        children: vec![],
+    }
+}
+
+fn create_capture_node(name_token: &Token) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: name_token.value.clone(),
+        type_: SyntaxTreeNodeType::Literal,
+        children: vec![],
+    }
+}
+
+fn is_pattern_type_token(token: &Token) -> bool {
+    match (&token.type_, token.value.as_deref()) {
+        (TokenType::TypeDeclarationToken, Some(type_name)) => type_name != "var",
+        (TokenType::NameIdentifierToken, Some(type_name)) => {
+            type_name.starts_with(char::is_uppercase)
+        }
+        _ => false,
+    }
+}
+
+fn is_contextual_keyword(token: &Token, keyword: &str) -> bool {
+    matches!(token.type_, TokenType::NameIdentifierToken) && token.value.as_deref() == Some(keyword)
+}
+
+fn contains_switch_break(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::LoopControl, Some("break")) => true,
+        (SyntaxTreeNodeType::Loop | SyntaxTreeNodeType::Switch, _) => false,
+        _ => syntax_tree_node.children.iter().any(contains_switch_break),
+    }
 }
@@ -19,23 +19,52 @@ impl Display for SyntaxTreeNode {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Debug)]
 pub enum SyntaxTreeNodeType {
+    CompilationUnit,
     Namespace,
+    ReferClojureExclude,
     Class,
+    Interface,
     Method,
     MethodArgument,
+    Field,
+    Modifier,
+    Constructor,
+    Type,
     Literal,
     Expression,
     Assignment,
     EqualityCheck,
     Branch,
+    Loop,
+    LoopControl,
+    Return,
+    Try,
+    Catch,
+    Throw,
+    Using,
+    Lambda,
+    Query,
+    QueryClause,
+    Switch,
+    SwitchSection,
+    SwitchLabel,
+    Pattern,
+    PatternProperty,
+    PatternMatch,
+    Block,
     Comment,
     Collection,
+    MemberAccess,
+    Invocation,
+    ObjectCreation,
+    MemberInitializer,
+    ElementAccess,
 }
 
 impl Display for SyntaxTreeNodeType {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self)
+        write!(f, "{:?}", self)
     }
 }
@@ -0,0 +1,233 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn classes_become_records_with_positional_and_map_constructors() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Customer { \
+                 public string Title; \
+                 public int Age; \
+                 public Customer(string title, int age) { Title = title; Age = age; } \
+             } \
+             class Factory { \
+                 public static Customer Build() { return new Customer(\"a\", 3); } \
+                 public static Customer Blank() { return new Customer { Title = \"b\" }; } \
+             }"
+        ),
+        "(ns shop) (defrecord Customer [title age]) \
+         (defn build [] (->Customer \"a\" 3)) \
+         (defn blank [] (map->Customer {:title \"b\"}))"
+    );
+}
+
+#[test]
+fn constructor_with_validation_becomes_a_factory() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Account { \
+                 public string Owner; \
+                 public decimal Balance = 0; \
+                 public Account(string owner) { \
+                     if (owner == null) { throw new ArgumentException(\"owner\"); } \
+                     Owner = owner; \
+                 } \
+             } \
+             class Bank { public static Account Open() { return new Account(\"c\"); } }"
+        ),
+        "(ns shop) (defrecord Account [owner balance]) \
+         (defn make-account [owner] \
+             (when (nil? owner) (throw (ex-info \"owner\" {:type :argument}))) \
+             (->Account owner 0)) \
+         (defn open [] (make-account \"c\"))"
+    );
+}
+
+#[test]
+fn instance_methods_take_the_record_and_read_fields_by_keyword() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Customer { \
+                 public int Age; \
+                 public bool IsAdult() { return this.Age > 17; } \
+                 public int Doubled() { return Age * 2; } \
+             }"
+        ),
+        "(ns shop) (defrecord Customer [age]) \
+         (defn adult? [customer] (> (:age customer) 17)) \
+         (defn doubled [customer] (* (:age customer) 2))"
+    );
+}
+
+#[test]
+fn field_assignment_in_void_method_returns_the_updated_record() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Counter { \
+                 public int Total; \
+                 public void Increment(int amount) { Total += amount; } \
+                 public void Rename(int total) { this.Total = total; } \
+             }"
+        ),
+        "(ns shop) (defrecord Counter [total]) \
+         (defn increment [counter amount] \
+             (let [counter (assoc counter :total (+ (:total counter) amount))] counter)) \
+         (defn rename [counter total] \
+             (let [counter (assoc counter :total total)] counter))"
+    );
+}
+
+#[test]
+fn bare_return_in_updating_method_returns_the_record() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Counter { \
+                 public int Total; \
+                 public void Clear(bool enabled) { if (!enabled) { return; } Total = 0; } \
+             }"
+        ),
+        "(ns shop) (defrecord Counter [total]) \
+         (defn clear [counter enabled] \
+             (if-not enabled counter (let [counter (assoc counter :total 0)] counter)))"
+    );
+}
+
+#[test]
+fn updating_method_returning_its_own_type_keeps_its_returns() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Counter { \
+                 public int Total; \
+                 public Counter Bump() { Total += 1; return this; } \
+             }"
+        ),
+        "(ns shop) (defrecord Counter [total]) \
+         (defn bump [counter] \
+             (let [counter (assoc counter :total (+ (:total counter) 1))] counter))"
+    );
+}
+
+#[test]
+fn updating_method_with_another_return_type_is_rejected() {
+    let error: String = transpile_error(
+        "namespace Shop; \
+         class Counter { public int Total; public int Advance() { Total += 1; return Total; } }",
+    );
+
+    assert!(error.contains("instance method Advance of class Counter assigns field Total"));
+}
+
+#[test]
+fn same_method_in_two_classes_is_rejected() {
+    let error: String = transpile_error(
+        "namespace Zoo; \
+         class Dog { public string Sound; public string Speak() { return Sound; } } \
+         class Bird { public string Sound; public string Speak() { return Sound; } }",
+    );
+
+    assert!(error.contains("method Speak is defined in both class Dog and class Bird"));
+}
+
+#[test]
+fn same_interface_method_in_two_classes_becomes_a_protocol() {
+    assert_eq!(
+        transpile(
+            "namespace Zoo; \
+             interface IAnimal { string Speak(); } \
+             class Dog : IAnimal { public string Sound; public string Speak() { return Sound; } } \
+             class Bird : IAnimal { public string Sound; public string Speak() { return Sound; } }"
+        ),
+        "(ns zoo) (defprotocol IAnimal (speak [this])) \
+         (defrecord Dog [sound] IAnimal (speak [dog] (:sound dog))) \
+         (defrecord Bird [sound] IAnimal (speak [bird] (:sound bird)))"
+    );
+}
+
+#[test]
+fn overloaded_constructors_are_rejected() {
+    let error: String = transpile_error(
+        "namespace Geo; \
+         class Point { public int X; public Point(int x) { X = x; } public Point() { X = 0; } }",
+    );
+
+    assert!(error.contains("Unable to lower class Point: overloaded constructors"));
+}
+
+#[test]
+fn deconstructing_into_more_names_than_fields_is_rejected() {
+    let error: String = transpile_error(
+        "namespace Geo; \
+         record Point(int X, int Y); \
+         class Shapes { public static int Sum(Point p) { var (a, b, c) = p; return a; } }",
+    );
+
+    assert!(error.contains("Unable to deconstruct record Point into 3 values"));
+}
+
+#[test]
+fn calls_to_updating_methods_rebind_their_receiver() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Counter { \
+                 public int Total; \
+                 public void Increment() { Total = Total + 1; } \
+                 public Counter Twice() { Increment(); Increment(); return this; } \
+             } \
+             class Till { \
+                 public static int Ring(Counter c) { c.Increment(); c.Increment(); return c.Total; } \
+             }"
+        ),
+        "(ns shop) (defrecord Counter [total]) \
+         (defn increment [counter] \
+             (let [counter (assoc counter :total (+ (:total counter) 1))] counter)) \
+         (defn twice [counter] \
+             (let [counter (increment counter)] (let [counter (increment counter)] counter))) \
+         (defn ring [c] (let [c (increment c)] (let [c (increment c)] (:total c))))"
+    );
+}
+
+#[test]
+fn updating_call_on_a_field_is_rejected() {
+    let error: String = transpile_error(
+        "namespace Shop; \
+         class Counter { public int Total; public void Increment() { Total = Total + 1; } } \
+         class Panel { public Counter Clicks; public void Click() { Clicks.Increment(); } }",
+    );
+
+    assert!(error.contains("call to Increment updates a record of class Counter but its receiver is not a local variable"));
+}
+
+#[test]
+fn derived_classes_carry_the_fields_of_their_base_class() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Item { public string Sku; } \
+             class Crate : Item { public int Size; }"
+        ),
+        "(ns shop) (defrecord Item [sku]) (defrecord Crate [sku size])"
+    );
+}
+
+#[test]
+fn factory_defaults_unassigned_fields_by_type() {
+    assert_eq!(
+        transpile(
+            "namespace Shop; \
+             class Order { \
+                 public int Quantity; public bool Paid; public double Weight; public string Note; \
+                 public Order(string note) { if (note == null) { throw new ArgumentException(\"note\"); } Note = note; } \
+             }"
+        ),
+        "(ns shop) (defrecord Order [quantity paid weight note]) \
+         (defn make-order [note] \
+             (when (nil? note) (throw (ex-info \"note\" {:type :argument}))) \
+             (->Order 0 false 0.0 note))"
+    );
+}
@@ -0,0 +1,31 @@
+use crate::tests::transpile;
+
+#[test]
+fn else_if_chain_becomes_flat_cond() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Tier(int n) { \
+             if (n > 100) { return 3; } else if (n > 10) { return 2; } else { return 1; } }"
+        ),
+        "(ns app) (defn tier [n] (cond (> n 100) 3 (> n 10) 2 :else 1))"
+    );
+}
+
+#[test]
+fn single_else_stays_an_if() {
+    assert_eq!(
+        transpile("namespace App; int Pair(int n) { if (n > 0) { return 1; } else { return 0; } }"),
+        "(ns app) (defn pair [n] (if (> n 0) 1 0))"
+    );
+}
+
+#[test]
+fn chain_followed_by_a_return_uses_it_as_else() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Nested(int n) { if (n > 100) { return 3; } \
+             else if (n > 10) { return 2; } else if (n > 5) { return 4; } return 1; }"
+        ),
+        "(ns app) (defn nested [n] (cond (> n 100) 3 (> n 10) 2 (> n 5) 4 :else 1))"
+    );
+}
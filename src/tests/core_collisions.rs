@@ -0,0 +1,114 @@
+use crate::tests::{transpile, transpile_warnings};
+
+#[test]
+fn methods_shadowing_core_vars_are_excluded_from_refer_clojure() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Count(int x) { return x; } int Total(int x) { return Count(x); }"
+        ),
+        "(ns app (:refer-clojure :exclude [count])) (defn count [x] x) (defn total [x] (count x))"
+    );
+}
+
+#[test]
+fn methods_colliding_with_special_forms_are_mangled_with_their_calls() {
+    assert_eq!(
+        transpile("namespace App; int Let(int x) { return x; } int Run(int x) { return Let(x); }"),
+        "(ns app) (defn let' [x] x) (defn run [x] (let' x))"
+    );
+}
+
+#[test]
+fn colliding_locals_are_mangled() {
+    assert_eq!(
+        transpile("namespace App; int Run(int str) { int let = str + 1; return let; }"),
+        "(ns app) (defn run [str'] (let [let' (+ str' 1)] let'))"
+    );
+}
+
+#[test]
+fn every_collision_is_reported() {
+    assert_eq!(
+        transpile_warnings("namespace App; int Count(int str) { return str; }"),
+        Vec::from([
+            String::from(
+                "method `count` in namespace app shadows clojure.core/count and was added to :refer-clojure :exclude"
+            ),
+            String::from(
+                "local `str` in method count collides with clojure.core and was renamed to `str'`"
+            ),
+        ])
+    );
+}
+
+#[test]
+fn calls_to_undeclared_methods_resolving_to_core_are_reported() {
+    assert_eq!(
+        transpile_warnings(
+            "namespace App; int Run(int x) { Print(x); Use(x); Print(x); return Next(Find(x)); }"
+        ),
+        Vec::from([
+            String::from(
+                "call to undeclared method `Print` in namespace app resolves to clojure.core/print"
+            ),
+            String::from(
+                "call to undeclared method `Use` in namespace app resolves to clojure.core/use"
+            ),
+            String::from(
+                "call to undeclared method `Next` in namespace app resolves to clojure.core/next"
+            ),
+            String::from(
+                "call to undeclared method `Find` in namespace app resolves to clojure.core/find"
+            ),
+        ])
+    );
+}
+
+#[test]
+fn calls_to_undeclared_methods_becoming_special_forms_are_reported() {
+    assert_eq!(
+        transpile_warnings("namespace App; int Run(int x) { return Do(x); }"),
+        Vec::from([String::from(
+            "call to undeclared method `Do` in namespace app becomes the special form `do`"
+        )])
+    );
+}
+
+#[test]
+fn calls_to_declared_methods_are_not_reported_as_call_sites() {
+    let warnings: Vec<String> = transpile_warnings(
+        "namespace App; int Find(int x) { return x; } int Run(int x) { return Find(x); }",
+    );
+
+    assert!(warnings
+        .iter()
+        .all(|warning| !warning.starts_with("call to")));
+}
+
+#[test]
+fn call_site_warnings_use_the_converted_namespace_name() {
+    assert_eq!(
+        transpile_warnings("namespace MyShop.Orders; int Run(int x) { return Inc(x); }"),
+        Vec::from([String::from(
+            "call to undeclared method `Inc` in namespace my-shop.orders resolves to clojure.core/inc"
+        )])
+    );
+}
+
+#[test]
+fn colliding_fields_are_reported_as_fields() {
+    assert_eq!(
+        transpile_warnings(
+            "namespace App; class Limits { public const int Max = 3; } \
+             class Scale { public static int Apply(int x) { return x * Limits.Max; } }"
+        ),
+        Vec::from([
+            String::from(
+                "field `max` in namespace app shadows clojure.core/max and was added to :refer-clojure :exclude"
+            ),
+            String::from(
+                "method `apply` in namespace app shadows clojure.core/apply and was added to :refer-clojure :exclude"
+            ),
+        ])
+    );
+}
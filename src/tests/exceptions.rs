@@ -0,0 +1,70 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn try_catch_finally_maps_to_host_exception_classes() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Parse(int n) { try { return Convert(n); } \
+             catch (FormatException e) { return 0; } finally { Close(n); } }"
+        ),
+        "(ns app) (defn parse [n] (try (convert n) (catch NumberFormatException e 0) (finally (close n))))"
+    );
+}
+
+#[test]
+fn thrown_exceptions_become_ex_info_or_host_constructors() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Check(int n) { if (n < 0) { throw new ArgumentException(\"negative\"); } return n; } \
+             int Host(int n) { if (n < 0) { throw new InvalidOperationException(\"bad\"); } return n; }"
+        ),
+        "(ns app) (defn check [n] (when (< n 0) (throw (ex-info \"negative\" {:type :argument}))) n) \
+         (defn host [n] (when (< n 0) (throw (IllegalStateException. \"bad\"))) n)"
+    );
+}
+
+#[test]
+fn filters_on_ex_info_catches_read_from_ex_data() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Restore(int n) { try { return Fetch(n); } \
+             catch (OrderException e) when (e.Code == 5) { return e.Code; } }"
+        ),
+        "(ns app) (defn restore [n] (try (fetch n) \
+         (catch clojure.lang.ExceptionInfo exception-info \
+         (if (and (= (:type (ex-data exception-info)) :order) (let [e exception-info] (= (:code (ex-data e)) 5))) \
+         (let [e exception-info] (:code (ex-data e))) \
+         (throw exception-info)))))"
+    );
+}
+
+#[test]
+fn filters_on_host_catches_use_interop() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Code(int n) { try { return Fetch(n); } catch (IOException e) when (e.ErrorCode == 3) { return 0; } } \
+             int Text(int n) { try { return Fetch(n); } catch (IOException e) when (e.Message == \"x\") { return 1; } }"
+        ),
+        "(ns app) (defn code [n] (try (fetch n) \
+         (catch java.io.IOException e (if (= (.getErrorCode e) 3) 0 (throw e))))) \
+         (defn text [n] (try (fetch n) (catch java.io.IOException e (if (= (ex-message e) \"x\") 1 (throw e)))))"
+    );
+}
+
+#[test]
+fn rethrow_inside_catch_throws_the_caught_exception() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Kept(int n) { try { return Fetch(n); } catch { Log(n); throw; } }"
+        ),
+        "(ns app) (defn kept [n] (try (fetch n) (catch Throwable ex (log n) (throw ex))))"
+    );
+}
+
+#[test]
+fn rethrow_outside_catch_is_rejected() {
+    assert!(transpile_error("namespace App; int Bare(int n) { throw; }")
+        .contains("`throw;` outside of a catch clause in method Bare cannot be translated"));
+}
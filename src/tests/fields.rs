@@ -0,0 +1,68 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn only_const_literals_are_marked_const() {
+    assert_eq!(
+        transpile(
+            "namespace App; class Settings { \
+             public const int MaxItems = 10; \
+             public static readonly int Limit = 20; \
+             public static readonly string Title = \"x\"; \
+             public static int Counter; }"
+        ),
+        "(ns app) (def ^:const max-items 10) (def limit 20) (def title \"x\") (def counter 0)"
+    );
+}
+
+#[test]
+fn static_members_are_referenced_by_their_def_name() {
+    assert_eq!(
+        transpile(
+            "namespace App; class Settings { public const int MaxItems = 10; \
+             public static int Scale(int n) { return n * Settings.MaxItems; } }"
+        ),
+        "(ns app) (def ^:const max-items 10) (defn scale [n] (* n max-items))"
+    );
+}
+
+#[test]
+fn auto_properties_become_record_fields_and_computed_properties_functions() {
+    assert_eq!(
+        transpile(
+            "namespace App; class Person { \
+             public int Age { get; set; } \
+             public string Title { get; set; } \
+             public bool Adult => Age > 17; }"
+        ),
+        "(ns app) (defrecord Person [age title]) (defn adult [person] (> (:age person) 17))"
+    );
+}
+
+#[test]
+fn assigned_static_fields_are_held_in_atoms() {
+    assert_eq!(
+        transpile(
+            "namespace App; class Stats { public static int Hits = 0; \
+             static void Hit() { Hits++; } \
+             static int Report() { Hits = Hits + 2; return Hits; } } \
+             class Admin { static void Clear() { Stats.Hits = 0; } }"
+        ),
+        "(ns app) (def hits (atom 0)) (defn hit [] (swap! hits inc)) \
+         (defn report [] (swap! hits + 2) @hits) (defn clear [] (reset! hits 0))"
+    );
+}
+
+#[test]
+fn static_fields_are_defined_after_the_fields_they_read() {
+    assert_eq!(
+        transpile(
+            "namespace App; class Sizes { const int Twice = Base * 2; const int Base = 2; \
+             static readonly int Limit = Twice + 1; }"
+        ),
+        "(ns app) (def ^:const base 2) (def twice (* base 2)) (def limit (+ twice 1))"
+    );
+    assert!(transpile_error(
+        "namespace App; class Loop { static readonly int A = B + 1; static readonly int B = A + 1; }"
+    )
+    .contains("static fields A, B of class Loop depend on each other"));
+}
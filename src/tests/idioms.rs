@@ -0,0 +1,43 @@
+use crate::tests::transpile;
+
+#[test]
+fn else_less_if_with_several_statements_becomes_when() {
+    assert_eq!(
+        transpile("namespace App; int Show(int n) { if (n > 0) { Draw(n); Log(n); } return n; }"),
+        "(ns app) (defn show [n] (when (> n 0) (draw n) (log n)) n)"
+    );
+}
+
+#[test]
+fn negated_conditions_become_when_not_and_if_not() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Hide(int n) { if (!Ready(n)) { Draw(n); Log(n); } return n; } \
+             int Flip(int n) { if (!Ready(n)) { return 1; } else { return 2; } }"
+        ),
+        "(ns app) (defn hide [n] (when-not (ready n) (draw n) (log n)) n) \
+         (defn flip [n] (if-not (ready n) 1 2))"
+    );
+}
+
+#[test]
+fn assign_then_null_check_becomes_if_let_or_when_let() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Lookup(int n) { var found = Locate(n); if (found != null) { return Consume(found); } return 0; } \
+             int Touch(int n) { var found = Locate(n); if (found != null) { Draw(found); Log(found); } return n; }"
+        ),
+        "(ns app) (defn lookup [n] (if-let [found (locate n)] (consume found) 0)) \
+         (defn touch [n] (when-let [found (locate n)] (draw found) (log found)) n)"
+    );
+}
+
+#[test]
+fn null_conditional_chain_becomes_some_thread() {
+    assert_eq!(
+        transpile("namespace App; int Chain(Customer c) { return c?.Address?.City; }"),
+        "(ns app) (defn chain [c] (some-> c :address :city))"
+    );
+}
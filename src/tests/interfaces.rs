@@ -0,0 +1,124 @@
+use crate::tests::{transpile, transpile_warnings};
+
+#[test]
+fn interfaces_become_protocols_implemented_inline() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             interface IShape { double Area(); } \
+             class Square : IShape { public double Side; public double Area() { return Side * Side; } } \
+             class Use { public static double Measure(IShape s) { return s.Area(); } }"
+        ),
+        "(ns geo) (defprotocol IShape (area [this])) \
+         (defrecord Square [side] IShape (area [square] (* (:side square) (:side square)))) \
+         (defn measure [s] (area s))"
+    );
+}
+
+#[test]
+fn implementer_without_fields_is_an_empty_record() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             interface IShape { double Area(); } \
+             class Unit : IShape { public double Area() { return 1; } } \
+             class Use { public static double Measure() { return new Unit().Area(); } }"
+        ),
+        "(ns geo) (defprotocol IShape (area [this])) \
+         (defrecord Unit [] IShape (area [unit] 1)) \
+         (defn measure [] (area (->Unit)))"
+    );
+}
+
+#[test]
+fn default_methods_are_copied_unless_overridden() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             interface IShape { double Area(); string Describe() { return \"shape\"; } } \
+             class Square : IShape { public double Side; public double Area() { return Side; } } \
+             class Cube : IShape { public double Side; public double Area() { return Side; } \
+                 public string Describe() { return \"cube\"; } }"
+        ),
+        "(ns geo) (defprotocol IShape (area [this]) (describe [this])) \
+         (defrecord Square [side] IShape (area [square] (:side square)) (describe [square] \"shape\")) \
+         (defrecord Cube [side] IShape (area [cube] (:side cube)) (describe [cube] \"cube\"))"
+    );
+}
+
+#[test]
+fn inherited_interfaces_are_implemented_alongside_their_base() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             interface IShape { double Area(); } \
+             interface ISolid : IShape { double Volume(double depth); } \
+             class Cube : ISolid { public double Side; \
+                 public double Area() { return Side * Side; } \
+                 public double Volume(double depth) { return Area() * depth; } }"
+        ),
+        "(ns geo) (defprotocol IShape (area [this])) (defprotocol ISolid (volume [this depth])) \
+         (defrecord Cube [side] \
+             IShape (area [cube] (* (:side cube) (:side cube))) \
+             ISolid (volume [cube depth] (* (area cube) depth)))"
+    );
+}
+
+#[test]
+fn explicit_implementations_go_to_their_own_protocol() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             interface IShape { string Describe(); } \
+             interface IPrintable { string Describe(); } \
+             class Label : IShape, IPrintable { public string Text; \
+                 string IShape.Describe() { return \"shape\"; } \
+                 string IPrintable.Describe() { return Text; } }"
+        ),
+        "(ns geo) (defprotocol IShape (describe [this])) (defprotocol IPrintable (describe [this])) \
+         (defrecord Label [text] \
+             IShape (describe [label] \"shape\") \
+             IPrintable (describe [label] (:text label)))"
+    );
+}
+
+#[test]
+fn colliding_explicit_implementations_are_flagged() {
+    let warnings: Vec<String> = transpile_warnings(
+        "namespace Geo; \
+         interface IShape { string Describe(); } \
+         interface IPrintable { string Describe(); } \
+         class Label : IShape, IPrintable { public string Text; \
+             string IShape.Describe() { return \"shape\"; } \
+             string IPrintable.Describe() { return Text; } }",
+    );
+
+    assert_eq!(warnings.len(), 1);
+    assert!(warnings[0]
+        .contains("explicit interface implementation of `Describe` in class Label collides across IShape, IPrintable"));
+}
+
+#[test]
+fn explicit_implementation_without_collision_is_not_flagged() {
+    assert!(transpile_warnings(
+        "namespace Geo; \
+         interface IShape { double Area(); } \
+         class Square : IShape { public double Side; double IShape.Area() { return Side; } }"
+    )
+    .is_empty());
+}
+
+#[test]
+fn interface_properties_backed_by_fields_get_accessors() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             interface ILabeled { string Caption { get; } } \
+             class Pin : ILabeled { public string Caption { get; set; } } \
+             class Flag : ILabeled { public string Caption; }"
+        ),
+        "(ns geo) (defprotocol ILabeled (caption [this])) \
+         (defrecord Pin [caption] ILabeled (caption [pin] (:caption pin))) \
+         (defrecord Flag [caption] ILabeled (caption [flag] (:caption flag)))"
+    );
+}
@@ -0,0 +1,46 @@
+use crate::config::project_config::ProjectConfig;
+use crate::tests::{transpile, transpile_with_config};
+
+#[test]
+fn expression_lambda_becomes_fn() {
+    assert_eq!(
+        transpile("namespace App; int Twice(int items) { return Each(items, x => x * 2); }"),
+        "(ns app) (defn twice [items] (each items (fn [x] (* x 2))))"
+    );
+}
+
+#[test]
+fn block_lambda_with_several_parameters_becomes_fn() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Sum2(int items) { return Fold(items, (a, b) => { int total = a + b; return total; }); }"
+        ),
+        "(ns app) (defn sum2 [items] (fold items (fn [a b] (let [total (+ a b)] total))))"
+    );
+}
+
+#[test]
+fn anonymous_method_becomes_fn() {
+    assert_eq!(
+        transpile("namespace App; int Anon(int items) { return Each(items, delegate (int x) { return x + 1; }); }"),
+        "(ns app) (defn anon [items] (each items (fn [x] (+ x 1))))"
+    );
+}
+
+#[test]
+fn shorthand_is_used_for_single_argument_expression_lambdas_when_enabled() {
+    let project_config: ProjectConfig = ProjectConfig {
+        anonymous_function_shorthand: true,
+        ..ProjectConfig::default()
+    };
+
+    assert_eq!(
+        transpile_with_config(
+            "namespace App; \
+             int Twice(int items) { return Each(items, x => x * 2); } \
+             int Pairs(int items) { return Fold(items, (a, b) => a + b); }",
+            &project_config
+        ),
+        "(ns app) (defn twice [items] (each items #(* % 2))) (defn pairs [items] (fold items (fn [a b] (+ a b))))"
+    );
+}
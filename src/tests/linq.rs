@@ -0,0 +1,60 @@
+use crate::tests::transpile;
+
+#[test]
+fn where_select_to_list_becomes_a_thread_last_pipeline() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Big(List<Order> orders) { return orders.Where(o => o.Total > 100).Select(o => o.Id).ToList(); }"
+        ),
+        "(ns app) (defn big [orders] (->> orders (filter (fn [o] (> (:total o) 100))) (map :id) vec))"
+    );
+}
+
+#[test]
+fn ordering_paging_and_aggregates_map_to_sequence_functions() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Stats(List<Order> orders) { return orders.OrderBy(o => o.Total).Take(3).Sum(o => o.Total); }"
+        ),
+        "(ns app) (defn stats [orders] (->> orders (sort-by :total) (take 3) (map :total) (reduce +)))"
+    );
+}
+
+#[test]
+fn quantifiers_and_element_operators_terminate_the_pipeline() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Has(List<Order> orders) { return orders.Any(o => o.Total > 5); } \
+             int Firsts(List<Order> orders) { return orders.FirstOrDefault(o => o.Total > 5); }"
+        ),
+        "(ns app) (defn has [orders] (->> orders (some (fn [o] (> (:total o) 5))) boolean)) \
+         (defn firsts [orders] (->> orders (filter (fn [o] (> (:total o) 5))) first))"
+    );
+}
+
+#[test]
+fn grouping_and_dictionaries_use_group_by_and_juxt() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Lookup(List<Order> orders) { return orders.ToDictionary(o => o.Id, o => o.Total); } \
+             int Groups(List<Order> orders) { return orders.GroupBy(o => o.Kind).Count(); }"
+        ),
+        "(ns app) (defn lookup [orders] (->> orders (map (juxt :id :total)) (into {}))) \
+         (defn groups [orders] (->> orders (group-by :kind) count))"
+    );
+}
+
+#[test]
+fn selectors_after_group_by_destructure_the_key_and_group() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             Dictionary<string, int> Tally(List<Line> lines) { return lines.GroupBy(l => l.Sku).ToDictionary(g => g.Key, g => g.Count()); } \
+             List<string> Busy(List<Line> lines) { return lines.GroupBy(l => l.Sku).Where(g => g.Count() > 1).Select(g => g.Key).ToList(); }"
+        ),
+        "(ns app) (defn tally [lines] (->> lines (group-by :sku) (map (juxt key (fn [[g-key g]] (count g)))) (into {}))) \
+         (defn busy [lines] (->> lines (group-by :sku) (filter (fn [[g-key g]] (> (count g) 1))) (map key) vec))"
+    );
+}
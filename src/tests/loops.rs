@@ -0,0 +1,245 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn while_loop_threads_mutated_locals_through_loop_recur() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Sum(int n) { int i = 0; int total = 0; \
+             while (i < n) { total += i; i++; } return total; }"
+        ),
+        "(ns app) (defn sum [n] (let [i 0 total 0] \
+         (let [[total i] (loop [total total i i] \
+         (if (< i n) (let [total (+ total i) i (inc i)] (recur total i)) [total i]))] \
+         total)))"
+    );
+}
+
+#[test]
+fn do_while_loop_runs_the_body_before_the_condition() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Climb(int n) { int i = 0; do { i++; } while (i < n); return i; }"
+        ),
+        "(ns app) (defn climb [n] (let [i 0] \
+         (let [i (loop [i i] (let [i (inc i)] (if (< i n) (recur i) i)))] i)))"
+    );
+}
+
+#[test]
+fn while_loop_without_mutated_locals_uses_when() {
+    assert_eq!(
+        transpile("namespace App; int Wait(int n) { while (Ready(n)) { Tick(n); } return n; }"),
+        "(ns app) (defn wait [n] (loop [] (when (ready n) (tick n) (recur))) n)"
+    );
+}
+
+#[test]
+fn counting_for_loop_with_side_effects_becomes_dotimes() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Show(int n) { for (int i = 0; i < n; i++) { Draw(i); } return n; }"
+        ),
+        "(ns app) (defn show [n] (dotimes [i n] (draw i)) n)"
+    );
+}
+
+#[test]
+fn accumulating_for_loop_becomes_reduce_over_range() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Sum(int n) { int total = 0; \
+             for (int i = 0; i < n; i++) { total += i; } return total; }"
+        ),
+        "(ns app) (defn sum [n] (let [total 0] \
+         (let [total (reduce (fn [total i] (let [total (+ total i)] total)) total (range n))] \
+         total)))"
+    );
+}
+
+#[test]
+fn stepped_inclusive_for_loop_uses_range_bounds() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Evens(int n) { int total = 0; \
+             for (int i = 2; i <= n; i += 2) { total += i * i; } return total; }"
+        ),
+        "(ns app) (defn evens [n] (let [total 0] \
+         (let [total (reduce (fn [total i] (let [total (+ total (* i i))] total)) total \
+         (range 2 (inc n) 2))] total)))"
+    );
+}
+
+#[test]
+fn non_counting_for_loop_falls_back_to_loop_recur() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Halve(int n) { int steps = 0; \
+             for (int i = n; i > 1; i = i / 2) { steps++; } return steps; }"
+        ),
+        "(ns app) (defn halve [n] (let [steps 0] \
+         (let [steps (loop [i n steps steps] \
+         (if (> i 1) (let [steps (inc steps)] (let [i (/ i 2)] (recur i steps))) steps))] \
+         steps)))"
+    );
+}
+
+#[test]
+fn side_effecting_foreach_becomes_doseq() {
+    assert_eq!(
+        transpile("namespace App; int Show(int items) { foreach (var item in items) { Draw(item); } return 0; }"),
+        "(ns app) (defn show [items] (doseq [item items] (draw item)) 0)"
+    );
+}
+
+#[test]
+fn accumulating_foreach_becomes_reduce() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Sum(int items) { int total = 0; \
+             foreach (var item in items) { total += item; } return total; }"
+        ),
+        "(ns app) (defn sum [items] (let [total 0] \
+         (let [total (reduce (fn [total item] (let [total (+ total item)] total)) total items)] \
+         total)))"
+    );
+}
+
+#[test]
+fn deconstructing_foreach_destructures_the_binding() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Pairs(int lookup) { \
+             foreach (var (label, amount) in lookup) { Draw(label, amount); } return 0; }"
+        ),
+        "(ns app) (defn pairs [lookup] (doseq [[label amount] lookup] (draw label amount)) 0)"
+    );
+}
+
+#[test]
+fn foreach_adding_each_element_becomes_mapv() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Doubled(int items) { var result = new List<int>(); \
+             foreach (var item in items) { result.Add(item * 2); } return result; }"
+        ),
+        "(ns app) (defn doubled [items] (let [result []] \
+         (let [result (into result (mapv (fn [item] (* item 2)) items))] result)))"
+    );
+}
+
+#[test]
+fn foreach_with_locals_and_a_guard_becomes_a_comprehension() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Big(int items) { var result = new List<int>(); \
+             foreach (var item in items) { var scaled = item * 10; \
+             if (scaled > 50) { result.Add(scaled); } } return result; }"
+        ),
+        "(ns app) (defn big [items] (let [result []] \
+         (let [result (into result (for [item items :let [scaled (* item 10)] :when (> scaled 50)] scaled))] \
+         result)))"
+    );
+}
+
+#[test]
+fn foreach_adding_from_several_places_threads_the_collection_through_reduce() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Split(int items) { var small = new List<int>(); int total = 0; \
+             foreach (var item in items) { total += item; \
+             if (item < 10) { small.Add(item); } else { small.Add(0); } small.Add(total); } \
+             return small; }"
+        ),
+        "(ns app) (defn split [items] (let [small [] total 0] \
+         (let [[total small] (reduce (fn [[total small] item] \
+         (let [total (+ total item)] \
+         (let [small (if (< item 10) (conj small item) (conj small 0))] \
+         (let [small (conj small total)] [total small])))) \
+         [total small] items)] small)))"
+    );
+}
+
+#[test]
+fn dictionary_additions_inside_loops_become_assoc() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Squares(int n) { var lookup = new Dictionary<int, int>(); \
+             for (int i = 0; i < n; i++) { lookup.Add(i, i * i); } return lookup; }"
+        ),
+        "(ns app) (defn squares [n] (let [lookup {}] \
+         (let [lookup (reduce (fn [lookup i] (let [lookup (assoc lookup i (* i i))] lookup)) lookup (range n))] \
+         lookup)))"
+    );
+}
+
+#[test]
+fn while_loop_adding_to_a_list_threads_it_through_recur() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Drain(int n) { var seen = new List<int>(); \
+             while (n > 0) { seen.Add(n); n--; } return seen; }"
+        ),
+        "(ns app) (defn drain [n] (let [seen []] \
+         (let [[seen n] (loop [seen seen n n] \
+         (if (> n 0) (let [seen (conj seen n)] (let [n (dec n)] (recur seen n))) [seen n]))] \
+         seen)))"
+    );
+}
+
+#[test]
+fn break_inside_reduce_becomes_reduced() {
+    assert_eq!(
+        transpile(
+            "namespace App; int FirstLarge(int items) { int found = -1; \
+             foreach (var item in items) { if (item > 10) { found = item; break; } } return found; }"
+        ),
+        "(ns app) (defn first-large [items] (let [found -1] \
+         (let [found (reduce (fn [found item] (if (> item 10) (let [found item] (reduced found)) found)) \
+         found items)] found)))"
+    );
+}
+
+#[test]
+fn continue_inside_reduce_skips_to_the_next_element() {
+    assert_eq!(
+        transpile(
+            "namespace App; int SumOdd(int items) { int total = 0; \
+             foreach (var item in items) { if (item % 2 == 0) { continue; } total += item; } return total; }"
+        ),
+        "(ns app) (defn sum-odd [items] (let [total 0] \
+         (let [total (reduce (fn [total item] (if (= (rem item 2) 0) total \
+         (let [total (+ total item)] total))) total items)] total)))"
+    );
+}
+
+#[test]
+fn break_inside_loop_recur_exits_without_recurring() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Scan(int n) { int i = 0; \
+             while (i < n) { i++; if (i == 5) { break; } } return i; }"
+        ),
+        "(ns app) (defn scan [n] (let [i 0] \
+         (let [i (loop [i i] (if (< i n) (let [i (inc i)] (if (= i 5) i (recur i))) i))] i)))"
+    );
+}
+
+#[test]
+fn continue_inside_for_loop_still_runs_the_increment() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Skip(int n) { \
+             for (int i = 0; i < n; i++) { if (i == 3) { continue; } Draw(i); } return n; }"
+        ),
+        "(ns app) (defn skip [n] (loop [i 0] (if (< i n) \
+         (if (= i 3) (let [i (inc i)] (recur i)) (do (draw i) (let [i (inc i)] (recur i)))) nil)) n)"
+    );
+}
+
+#[test]
+fn statements_after_break_are_reported() {
+    assert!(transpile_error(
+        "namespace App; int Bad(int n) { while (n > 0) { if (n == 2) { break; Draw(n); } n--; } return n; }"
+    )
+    .contains("Unsupported control flow in while loop: statements after `break` are unreachable"));
+}
@@ -0,0 +1,74 @@
+use crate::tests::{transpile, transpile_warnings};
+
+#[test]
+fn chained_property_access_nests_keyword_lookups() {
+    assert_eq!(
+        transpile("namespace App; int Deep(Customer c) { return c.Address.City; }"),
+        "(ns app) (defn deep [c] (:city (:address c)))"
+    );
+}
+
+#[test]
+fn method_call_on_a_call_result_uses_interop() {
+    assert_eq!(
+        transpile("namespace App; int Chain(int n) { return Build(n).Finish(); }"),
+        "(ns app) (defn chain [n] (.Finish (build n)))"
+    );
+}
+
+#[test]
+fn indexers_use_nth_for_lists_and_get_for_dictionaries() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int At(List<int> items) { return items[0]; } \
+             int Lookup(Dictionary<string, int> table) { return table[\"key\"]; }"
+        ),
+        "(ns app) (defn at [items] (nth items 0)) (defn lookup [table] (get table \"key\"))"
+    );
+}
+
+#[test]
+fn host_type_members_use_count_or_interop() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Len(string text) { return text.Length; } \
+             int Upper(string text) { return text.ToUpper(); }"
+        ),
+        "(ns app) (defn len [text] (count text)) (defn upper [text] (.ToUpper text))"
+    );
+}
+
+#[test]
+fn methods_on_types_declared_elsewhere_use_interop() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             string Head(string path) { var reader = new StreamReader(path); return reader.ReadLine(); } \
+             int Lookup(Repo repo) { return repo.Find(3); }"
+        ),
+        "(ns app) (defn head [path] (let [reader (StreamReader. path)] (.ReadLine reader))) \
+         (defn lookup [repo] (.Find repo 3))"
+    );
+}
+
+#[test]
+fn member_calls_on_untyped_receivers_are_checked_against_clojure_core() {
+    assert_eq!(
+        transpile_warnings(
+            "namespace App; int Loose(List<int> xs) { return xs.Select(x => x.Find(2)).Sum(); }"
+        ),
+        Vec::from([String::from(
+            "call to undeclared member method `Find` in namespace app resolves to clojure.core/find"
+        )])
+    );
+}
+
+#[test]
+fn array_locals_keep_their_declared_type() {
+    assert_eq!(
+        transpile("namespace App; int Head(int[] xs) { int[] copy = Clone(xs); return copy[0] + copy.Length; }"),
+        "(ns app) (defn head [xs] (let [copy (clone xs)] (+ (nth copy 0) (count copy))))"
+    );
+}
@@ -0,0 +1,57 @@
+//! End-to-end tests that run C# source through the whole pipeline and compare the Clojure output.
+//! Whitespace is collapsed before comparing so expectations can be written on a single line.
+
+use crate::config::project_config::ProjectConfig;
+
+mod classes;
+mod conditionals;
+mod core_collisions;
+mod exceptions;
+mod fields;
+mod idioms;
+mod interfaces;
+mod lambdas;
+mod linq;
+mod loops;
+mod members;
+mod mutation;
+mod namespaces;
+mod naming;
+mod objects;
+mod operators;
+mod patterns;
+mod queries;
+mod rebinding;
+mod records;
+mod resources;
+mod returns;
+mod switches;
+
+pub(crate) fn transpile(source_code: &str) -> String {
+    transpile_with_config(source_code, &ProjectConfig::default())
+}
+
+pub(crate) fn transpile_with_config(source_code: &str, project_config: &ProjectConfig) -> String {
+    match crate::transpile_source(source_code.to_string(), project_config) {
+        Ok((transpiled_code, _)) => collapse_whitespace(&transpiled_code),
+        Err(error) => panic!("expected source to transpile, got error: {}", error),
+    }
+}
+
+pub(crate) fn transpile_warnings(source_code: &str) -> Vec<String> {
+    match crate::transpile_source(source_code.to_string(), &ProjectConfig::default()) {
+        Ok((_, warnings)) => warnings,
+        Err(error) => panic!("expected source to transpile, got error: {}", error),
+    }
+}
+
+pub(crate) fn transpile_error(source_code: &str) -> String {
+    match crate::transpile_source(source_code.to_string(), &ProjectConfig::default()) {
+        Ok((transpiled_code, _)) => panic!("expected an error, got output: {}", transpiled_code),
+        Err(error) => error,
+    }
+}
+
+fn collapse_whitespace(code: &str) -> String {
+    code.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
@@ -0,0 +1,107 @@
+use crate::config::project_config::{MutableStateFallback, ProjectConfig};
+use crate::tests::{transpile, transpile_warnings, transpile_with_config};
+
+#[test]
+fn local_mutated_by_a_lambda_is_held_in_a_volatile() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Counted(int items) { int seen = 0; Each(items, item => { seen += item; }); return seen; }"
+        ),
+        "(ns app) (defn counted [items] \
+         ;; WARNING: `seen` is captured and mutated by a lambda, so it is held in volatile! instead of a let binding; review by hand \
+         (let [seen (volatile! 0)] (each items (fn [item] (vswap! seen + item))) @seen))"
+    );
+}
+
+#[test]
+fn local_assigned_inside_an_expression_is_reset_in_place() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Drain(int n) { int current = 0; \
+             while ((current = Fetch(n)) > 0) { Draw(current); } return current; }"
+        ),
+        "(ns app) (defn drain [n] \
+         ;; WARNING: `current` is assigned inside an expression, so it is held in volatile! instead of a let binding; review by hand \
+         (let [current (volatile! 0)] \
+         (loop [] (when (> (vreset! current (fetch n)) 0) (draw @current) (recur))) @current))"
+    );
+}
+
+#[test]
+fn atom_fallback_is_configurable() {
+    let project_config: ProjectConfig = ProjectConfig {
+        mutable_state_fallback: MutableStateFallback::Atom,
+        ..ProjectConfig::default()
+    };
+
+    assert_eq!(
+        transpile_with_config(
+            "namespace App; int Counted(int items) { int seen = 0; Each(items, item => { seen += item; }); return seen; }",
+            &project_config
+        ),
+        "(ns app) (defn counted [items] \
+         ;; WARNING: `seen` is captured and mutated by a lambda, so it is held in atom instead of a let binding; review by hand \
+         (let [seen (atom 0)] (each items (fn [item] (swap! seen + item))) @seen))"
+    );
+}
+
+#[test]
+fn fallback_is_reported_as_a_warning() {
+    assert_eq!(
+        transpile_warnings(
+            "namespace App; int Counted(int items) { int seen = 0; Each(items, item => { seen += item; }); return seen; }"
+        ),
+        Vec::from([String::from(
+            "local `seen` in method counted is captured and mutated by a lambda and was moved into volatile!"
+        )])
+    );
+}
+
+#[test]
+fn postfix_step_in_an_expression_yields_the_old_value() {
+    assert_eq!(
+        transpile("namespace App; int Advance(int x) { int y = x++; return y + x; }"),
+        "(ns app) (defn advance [x] \
+         ;; WARNING: `x` is assigned inside an expression, so it is held in volatile! instead of a let binding; review by hand \
+         (let [x (volatile! x)] (let [y (let [x-before @x] (vswap! x inc) x-before)] (+ y @x))))"
+    );
+}
+
+#[test]
+fn postfix_step_in_an_indexer_reads_the_current_element() {
+    assert_eq!(
+        transpile("namespace App; int Pick(int[] a, int i) { var v = a[i++]; return v + a[i]; }"),
+        "(ns app) (defn pick [a i] \
+         ;; WARNING: `i` is assigned inside an expression, so it is held in volatile! instead of a let binding; review by hand \
+         (let [i (volatile! i)] (let [v (nth a (let [i-before @i] (vswap! i inc) i-before))] (+ v (nth a @i)))))"
+    );
+}
+
+#[test]
+fn prefix_step_in_an_expression_yields_the_new_value() {
+    assert_eq!(
+        transpile("namespace App; int Advance(int x) { int y = --x; return y; }"),
+        "(ns app) (defn advance [x] \
+         ;; WARNING: `x` is assigned inside an expression, so it is held in volatile! instead of a let binding; review by hand \
+         (let [x (volatile! x)] (let [y (vswap! x dec)] y)))"
+    );
+}
+
+#[test]
+fn postfix_step_with_the_atom_fallback_derefs_the_atom() {
+    let project_config: ProjectConfig = ProjectConfig {
+        mutable_state_fallback: MutableStateFallback::Atom,
+        ..ProjectConfig::default()
+    };
+
+    assert_eq!(
+        transpile_with_config(
+            "namespace App; int Drain(int n) { int left = n; while (left-- > 0) { Draw(left); } return left; }",
+            &project_config
+        ),
+        "(ns app) (defn drain [n] \
+         ;; WARNING: `left` is assigned inside an expression, so it is held in atom instead of a let binding; review by hand \
+         (let [left (atom n)] \
+         (loop [] (when (> (let [left-before @left] (swap! left dec) left-before) 0) (draw @left) (recur))) @left))"
+    );
+}
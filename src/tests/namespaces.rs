@@ -0,0 +1,48 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn file_scoped_namespace_covers_the_rest_of_the_file() {
+    assert_eq!(
+        transpile("namespace Shop.Orders; int One() { return 1; }"),
+        "(ns shop.orders) (defn one [] 1)"
+    );
+}
+
+#[test]
+fn nested_namespaces_are_joined_with_their_parent() {
+    assert_eq!(
+        transpile("namespace Outer { int One() { return 1; } namespace Inner { int Two() { return 2; } } }"),
+        "(ns outer) (defn one [] 1) (ns outer.inner) (defn two [] 2)"
+    );
+}
+
+#[test]
+fn namespace_without_members_of_its_own_is_omitted() {
+    assert_eq!(
+        transpile("namespace Outer { namespace Inner { int Two() { return 2; } } }"),
+        "(ns outer.inner) (defn two [] 2)"
+    );
+}
+
+#[test]
+fn multiple_namespaces_are_emitted_in_order() {
+    assert_eq!(
+        transpile(
+            "namespace First { int One() { return 1; } } namespace Second { int Two() { return 2; } }"
+        ),
+        "(ns first) (defn one [] 1) (ns second) (defn two [] 2)"
+    );
+}
+
+#[test]
+fn using_directives_are_skipped() {
+    assert_eq!(
+        transpile("using System; using System.Linq; namespace App; int One() { return 1; }"),
+        "(ns app) (defn one [] 1)"
+    );
+}
+
+#[test]
+fn code_outside_a_namespace_is_rejected() {
+    assert!(transpile_error("int One() { return 1; }").contains("outside of namespace"));
+}
@@ -0,0 +1,69 @@
+use crate::config::project_config::ProjectConfig;
+use crate::tests::{transpile, transpile_with_config};
+use crate::transpiler::naming::to_kebab_case;
+
+#[test]
+fn pascal_and_camel_case_become_kebab_case() {
+    assert_eq!(to_kebab_case("CalculateTotal"), "calculate-total");
+    assert_eq!(to_kebab_case("unitPrice"), "unit-price");
+    assert_eq!(to_kebab_case("ParseHTTPResponse"), "parse-http-response");
+    assert_eq!(to_kebab_case("MAX_ITEMS"), "max-items");
+    assert_eq!(to_kebab_case("_value"), "value");
+    assert_eq!(to_kebab_case("_"), "_");
+}
+
+#[test]
+fn namespaces_are_lower_case_dotted_names() {
+    assert_eq!(
+        transpile("namespace MyCompany.OrderService; int One() { return 1; }"),
+        "(ns my-company.order-service) (defn one [] 1)"
+    );
+}
+
+#[test]
+fn boolean_methods_become_predicates() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             bool IsValid(int orderCount) { return orderCount > 0; } \
+             bool HasItems(int itemCount) { return itemCount > 0; }"
+        ),
+        "(ns app) \
+         (defn valid? [order-count] (> order-count 0)) \
+         (defn has-items? [item-count] (> item-count 0))"
+    );
+}
+
+#[test]
+fn call_sites_use_the_converted_method_name() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             bool IsValid(int total) { return total > 0; } \
+             bool Check(int total) { return IsValid(total); }"
+        ),
+        "(ns app) (defn valid? [total] (> total 0)) (defn check [total] (valid? total))"
+    );
+}
+
+#[test]
+fn overrides_replace_or_keep_names() {
+    let mut project_config: ProjectConfig = ProjectConfig::default();
+    project_config.naming_overrides.insert(
+        String::from("CalculateTotal"),
+        String::from("calculateTotal"),
+    );
+    project_config
+        .naming_overrides
+        .insert(String::from("IsValid"), String::new());
+
+    assert_eq!(
+        transpile_with_config(
+            "namespace App; \
+             bool IsValid(int a) { return a > 0; } \
+             int CalculateTotal(int a) { return a; }",
+            &project_config
+        ),
+        "(ns app) (defn IsValid [a] (> a 0)) (defn calculateTotal [a] a)"
+    );
+}
@@ -0,0 +1,51 @@
+use crate::tests::transpile;
+
+#[test]
+fn object_initializer_becomes_a_map() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Build(int n) { var c = new Customer { Label = \"x\", Age = 3 }; return Store(c); } \
+             int Target(int n) { Customer c = new() { Label = \"y\" }; return Store(c); }"
+        ),
+        "(ns app) (defn build [n] (let [c {:label \"x\" :age 3}] (store c))) \
+         (defn target [n] (let [c {:label \"y\"}] (store c)))"
+    );
+}
+
+#[test]
+fn collection_initializers_become_vectors_maps_and_sets() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Nums(int n) { var items = new List<int> { 1, 2, 3 }; return Store(items); } \
+             int Table(int n) { var table = new Dictionary<string, int> { [\"a\"] = 1 }; return Store(table); } \
+             int Arr(int n) { var items = new[] { 1, 2 }; return Store(items); } \
+             int Tags(int n) { var tags = new HashSet<string> { \"a\" }; return Store(tags); }"
+        ),
+        "(ns app) (defn nums [n] (let [items [1 2 3]] (store items))) \
+         (defn table [n] (let [table {\"a\" 1}] (store table))) \
+         (defn arr [n] (let [items [1 2]] (store items))) \
+         (defn tags [n] (let [tags #{\"a\"}] (store tags)))"
+    );
+}
+
+#[test]
+fn unknown_types_are_constructed_through_interop() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Host(int n) { var sb = new StringBuilder(n); return Store(sb); }"
+        ),
+        "(ns app) (defn host [n] (let [sb (StringBuilder. n)] (store sb)))"
+    );
+}
+
+#[test]
+fn host_constructor_with_initializer_sets_properties_through_setters() {
+    assert_eq!(
+        transpile(
+            "namespace App; class Net { static Client Connect(string host) { return new Client(host) { Timeout = 30, KeepAlive = true }; } }"
+        ),
+        "(ns app) (defn connect [host] (doto (Client. host) (.setTimeout 30) (.setKeepAlive true)))"
+    );
+}
@@ -0,0 +1,51 @@
+use crate::tests::transpile;
+
+#[test]
+fn boolean_operators_bind_looser_than_comparisons() {
+    assert_eq!(
+        transpile(
+            "namespace App; bool Check(int a, int b) { return a < b && !(a == b) || a != 1 && b >= 2; }"
+        ),
+        "(ns app) (defn check [a b] (or (and (< a b) (not (= a b))) (and (not= a 1) (>= b 2))))"
+    );
+}
+
+#[test]
+fn arithmetic_follows_precedence_and_parentheses() {
+    assert_eq!(
+        transpile("namespace App; int Calc(int a, int b) { return (a + b) * 2 % 3 - a / b; }"),
+        "(ns app) (defn calc [a b] (- (rem (* (+ a b) 2) 3) (/ a b)))"
+    );
+}
+
+#[test]
+fn increments_and_decrements_become_inc_and_dec() {
+    assert_eq!(
+        transpile("namespace App; int Step(int a) { a++; a--; return a; }"),
+        "(ns app) (defn step [a] (let [a (inc a) a (dec a)] a))"
+    );
+}
+
+#[test]
+fn unary_minus_on_a_number_is_folded_into_the_literal() {
+    assert_eq!(
+        transpile("namespace App; int Neg(int a) { int b = -1; int c = a - -2; return -1 * c; }"),
+        "(ns app) (defn neg [a] (let [b -1 c (- a -2)] (* -1 c)))"
+    );
+}
+
+#[test]
+fn unary_minus_on_an_expression_stays_a_call() {
+    assert_eq!(
+        transpile("namespace App; int Neg(int a) { return -a; }"),
+        "(ns app) (defn neg [a] (- a))"
+    );
+}
+
+#[test]
+fn prefix_and_postfix_steps_as_statements_rebind_the_local() {
+    assert_eq!(
+        transpile("namespace App; int Tally(int n) { int c = n; c++; ++c; c--; return c; }"),
+        "(ns app) (defn tally [n] (let [c n c (inc c) c (inc c) c (dec c)] c))"
+    );
+}
@@ -0,0 +1,136 @@
+use crate::tests::transpile;
+
+#[test]
+fn property_pattern_with_capture_binds_the_subject() {
+    assert_eq!(
+        transpile(
+            "namespace App; record Customer(string Label, int Age); \
+             bool Adult(Customer o) { if (o is Customer { Age: > 18 } c) { return Greet(c); } return false; }"
+        ),
+        "(ns app) (defrecord Customer [label age]) \
+         (defn adult [o] (if (and (instance? Customer o) (> (:age o) 18)) (let [c o] (greet c)) false))"
+    );
+}
+
+#[test]
+fn not_null_pattern_becomes_some() {
+    assert_eq!(
+        transpile("namespace App; bool Known(int o) { return o is not null; }"),
+        "(ns app) (defn known [o] (some? o))"
+    );
+}
+
+#[test]
+fn list_patterns_check_length_and_bind_elements() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Head(int items) { return items switch \
+             { [] => 0, [var lead] => lead, [var lead, ..] => lead + 1 }; }"
+        ),
+        "(ns app) (defn head [items] (cond \
+         (and (sequential? items) (= (count items) 0)) 0 \
+         (and (sequential? items) (= (count items) 1)) (let [lead (nth items 0)] lead) \
+         (and (sequential? items) (>= (count items) 1)) (let [lead (nth items 0)] (+ lead 1)) \
+         :else (throw (ex-info \"No matching pattern\" {:value items}))))"
+    );
+}
+
+#[test]
+fn switch_expression_with_discard_arm_uses_it_as_else() {
+    assert_eq!(
+        transpile("namespace App; int Bucket(int n) { return n switch { < 0 => -1, 0 or 1 => 0, _ => 1 }; }"),
+        "(ns app) (defn bucket [n] (cond (< n 0) -1 (or (= n 0) (= n 1)) 0 :else 1))"
+    );
+}
+
+#[test]
+fn switch_expression_without_discard_arm_throws_when_nothing_matches() {
+    assert_eq!(
+        transpile(
+            "namespace App; string Describe(int n) { return n switch { > 0 => \"positive\", < 0 => \"negative\" }; }"
+        ),
+        "(ns app) (defn describe [n] (cond (> n 0) \"positive\" (< n 0) \"negative\" \
+         :else (throw (ex-info \"No matching pattern\" {:value n}))))"
+    );
+}
+
+#[test]
+fn constant_switch_expression_without_discard_arm_throws_from_case() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             string Grade(int n) { return n switch { 1 => \"one\", 2 => \"two\" }; } \
+             string Pick(int n) { return Make(n) switch { 1 => \"one\", 2 => \"two\" }; }"
+        ),
+        "(ns app) (defn grade [n] (case n 1 \"one\" 2 \"two\" \
+         (throw (ex-info \"No matching pattern\" {:value n})))) \
+         (defn pick [n] (let [switch-value (make n)] (case switch-value 1 \"one\" 2 \"two\" \
+         (throw (ex-info \"No matching pattern\" {:value switch-value})))))"
+    );
+}
+
+#[test]
+fn var_arm_already_covers_every_value() {
+    assert_eq!(
+        transpile(
+            "namespace App; string Classify(int n) { return n switch { > 0 => \"pos\", var other => \"rest\" }; }"
+        ),
+        "(ns app) (defn classify [n] (cond (> n 0) \"pos\" :else (let [other n] \"rest\")))"
+    );
+}
+
+#[test]
+fn positional_patterns_on_records_use_keyword_lookups() {
+    assert_eq!(
+        transpile(
+            "namespace App; record Point(int X, int Y); \
+             string Quad(Point p) { return p switch \
+             { Point(0, 0) => \"origin\", Point(var x, 0) => \"axis\", _ => \"other\" }; }"
+        ),
+        "(ns app) (defrecord Point [x y]) (defn quad [p] (cond \
+         (and (instance? Point p) (= (:x p) 0) (= (:y p) 0)) \"origin\" \
+         (and (instance? Point p) (= (:y p) 0)) (let [x (:x p)] \"axis\") \
+         :else \"other\"))"
+    );
+}
+
+#[test]
+fn positional_patterns_on_unknown_types_fall_back_to_nth() {
+    assert_eq!(
+        transpile(
+            "namespace App; string Quad(int p) { return p switch { (0, 0) => \"origin\", _ => \"other\" }; }"
+        ),
+        "(ns app) (defn quad [p] (cond (and (= (nth p 0) 0) (= (nth p 1) 0)) \"origin\" :else \"other\"))"
+    );
+}
+
+#[test]
+fn combined_patterns_hoist_each_subject_under_its_own_name() {
+    assert_eq!(
+        transpile(
+            "namespace App; record Point(int X, int Y); \
+             bool Both(Point p) { return Make(p) is Point { X: > 0 } && Other(p) is Point { Y: > 0 }; }"
+        ),
+        "(ns app) (defrecord Point [x y]) (defn both [p] \
+         (let [pattern-value (make p) pattern-value-2 (other p)] \
+         (and (and (instance? Point pattern-value) (> (:x pattern-value) 0)) \
+         (and (instance? Point pattern-value-2) (> (:y pattern-value-2) 0)))))"
+    );
+}
+
+#[test]
+fn is_pattern_naming_a_constant_compares_values() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             class Limits { \
+                 public const int Top = 3; \
+                 public static bool IsTop(int n) { return n is Top; } \
+                 public static bool IsText(object o) { return o is String; } \
+             }"
+        ),
+        "(ns app) (def ^:const top 3) \
+         (defn top? [n] (= n top)) \
+         (defn text? [o] (instance? String o))"
+    );
+}
@@ -0,0 +1,66 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn where_and_let_clauses_become_for_modifiers() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Lets(List<Order> orders) { return from o in orders let t = o.Total * 2 where t > 10 select t; }"
+        ),
+        "(ns app) (defn lets [orders] (for [o orders :let [t (* (:total o) 2)] :when (> t 10)] t))"
+    );
+}
+
+#[test]
+fn orderby_sorts_the_filtered_sequence_before_projecting() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Rich(List<Customer> customers) { \
+             return from c in customers where c.Age > 18 orderby c.Label select c.Label; }"
+        ),
+        "(ns app) (defn rich [customers] (for [c (sort-by (fn [c] (:label c)) \
+         (for [c customers :when (> (:age c) 18)] c))] (:label c)))"
+    );
+}
+
+#[test]
+fn join_is_expressed_through_an_index_map() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Joined(List<Customer> customers, List<Order> orders) { \
+             return from c in customers join o in orders on c.Id equals o.CustomerId select new { c.Label, o.Total }; }"
+        ),
+        "(ns app) (defn joined [customers orders] \
+         (let [o-index (group-by (fn [o] (:customer-id o)) orders)] \
+         (for [c customers o (get o-index (:id c))] {:label (:label c) :total (:total o)})))"
+    );
+}
+
+#[test]
+fn group_into_iterates_over_group_by_entries() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Kinds(List<Order> orders) { return from o in orders group o by o.Kind into g select g.Key; }"
+        ),
+        "(ns app) (defn kinds [orders] (for [[g-key g] (group-by (fn [o] (:kind o)) orders)] g-key))"
+    );
+}
+
+#[test]
+fn query_without_a_select_or_group_is_rejected() {
+    assert!(transpile_error(
+        "namespace App; List<int> Raw(List<int> xs) { var a = from x in xs; return a; }"
+    )
+    .contains("query expression on line 1 must end with a select or group clause"));
+}
+
+#[test]
+fn clauses_after_select_need_a_continuation() {
+    assert!(transpile_error(
+        "namespace App; List<int> Late(List<int> xs) { return from x in xs select x where x > 1; }"
+    )
+    .contains("`where` on line 1 follows a select clause without `into`"));
+    assert!(transpile_error(
+        "namespace App; List<int> Early(List<int> xs) { return from x in xs into y select y; }"
+    )
+    .contains("`into` on line 1 must follow a select, group or join clause"));
+}
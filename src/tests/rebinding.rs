@@ -0,0 +1,42 @@
+use crate::tests::transpile;
+
+#[test]
+fn sequential_reassignments_become_shadowing_bindings() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Step(int n) { int total = n; total = total + 1; return total; }"
+        ),
+        "(ns app) (defn step [n] (let [total n total (+ total 1)] total))"
+    );
+}
+
+#[test]
+fn compound_assignments_use_the_matching_arithmetic() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Total(int n) { int total = 0; total += n; total *= 2; total -= 1; return total; }"
+        ),
+        "(ns app) (defn total [n] (let [total 0 total (+ total n) total (* total 2) total (- total 1)] total))"
+    );
+}
+
+#[test]
+fn reassignments_in_both_branches_merge_into_one_binding() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Pick(int n) { int result = 0; \
+             if (n > 0) { result = n; } else { result = -n; } return result; }"
+        ),
+        "(ns app) (defn pick [n] (let [result 0] (let [result (if (> n 0) n (- n))] result)))"
+    );
+}
+
+#[test]
+fn reassignment_in_one_branch_keeps_the_old_value_otherwise() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Bump(int n) { int result = 1; if (n > 3) { result = 2; } return result; }"
+        ),
+        "(ns app) (defn bump [n] (let [result 1] (let [result (if (> n 3) 2 result)] result)))"
+    );
+}
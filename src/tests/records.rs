@@ -0,0 +1,78 @@
+use crate::tests::transpile;
+
+#[test]
+fn positional_records_and_record_structs_become_defrecords() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             record Point(int X, int Y); \
+             record struct Size(int Width, int Height); \
+             class Shapes { public static Size Unit() { return new Size(1, 1); } }"
+        ),
+        "(ns geo) (defrecord Point [x y]) (defrecord Size [width height]) \
+         (defn unit [] (->Size 1 1))"
+    );
+}
+
+#[test]
+fn nominal_records_are_built_from_maps_with_their_defaults() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             record Person { public string Title { get; init; } public int Age { get; init; } = 1; } \
+             class People { public static Person Adult() { return new Person { Title = \"x\" }; } }"
+        ),
+        "(ns app) (defrecord Person [title age]) \
+         (defn adult [] (map->Person {:age 1 :title \"x\"}))"
+    );
+}
+
+#[test]
+fn with_expressions_become_assoc() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             record Point(int X, int Y); \
+             class Shapes { \
+                 public static Point Shift(Point p) { return p with { X = 5 }; } \
+                 public static Point Both(Point p) { return p with { X = 1, Y = p.X + 1 }; } \
+                 public static Point Copy(Point p) { return p with { }; } \
+             }"
+        ),
+        "(ns geo) (defrecord Point [x y]) \
+         (defn shift [p] (assoc p :x 5)) \
+         (defn both [p] (assoc p :x 1 :y (+ (:x p) 1))) \
+         (defn copy [p] p)"
+    );
+}
+
+#[test]
+fn deconstruction_becomes_map_destructuring() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             record Point(int X, int Y); \
+             class Shapes { \
+                 public static int Total(Point p) { var (a, b) = p; return a + b; } \
+                 public static int Left(Point p) { var (a, _) = p; return a; } \
+                 public static int Moved(Point p) { var q = p with { Y = 0 }; var (a, b) = q; return a + b; } \
+             }"
+        ),
+        "(ns geo) (defrecord Point [x y]) \
+         (defn total [p] (let [{a :x b :y} p] (+ a b))) \
+         (defn left [p] (let [{a :x} p] a)) \
+         (defn moved [p] (let [q (assoc p :y 0) {a :x b :y} q] (+ a b)))"
+    );
+}
+
+#[test]
+fn records_compare_by_value() {
+    assert_eq!(
+        transpile(
+            "namespace Geo; \
+             record Point(int X, int Y); \
+             class Shapes { public static bool Same(Point p, Point q) { return p == q; } }"
+        ),
+        "(ns geo) (defrecord Point [x y]) (defn same [p q] (= p q))"
+    );
+}
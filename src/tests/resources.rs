@@ -0,0 +1,23 @@
+use crate::tests::transpile;
+
+#[test]
+fn using_statement_becomes_with_open() {
+    assert_eq!(
+        transpile(
+            "namespace App; int ReadAll(string path) { \
+             using (var reader = new StreamReader(path)) { return Consume(reader); } }"
+        ),
+        "(ns app) (defn read-all [path] (with-open [reader (StreamReader. path)] (consume reader)))"
+    );
+}
+
+#[test]
+fn using_declaration_scopes_over_the_rest_of_the_block() {
+    assert_eq!(
+        transpile(
+            "namespace App; int ReadDecl(string path) { \
+             using var reader = new StreamReader(path); Draw(path); return Consume(reader); }"
+        ),
+        "(ns app) (defn read-decl [path] (with-open [reader (StreamReader. path)] (draw path) (consume reader)))"
+    );
+}
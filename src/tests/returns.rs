@@ -0,0 +1,75 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn guard_clause_becomes_the_then_branch() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Guard(int n) { if (n < 0) { return 0; } int doubled = n * 2; return doubled + 1; }"
+        ),
+        "(ns app) (defn guard [n] (if (< n 0) 0 (let [doubled (* n 2)] (+ doubled 1))))"
+    );
+}
+
+#[test]
+fn mid_body_return_moves_the_rest_into_the_other_branch() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Middle(int n) { Draw(n); if (n > 5) { return 5; } else { Log(n); } return n; }"
+        ),
+        "(ns app) (defn middle [n] (draw n) (if (> n 5) 5 (do (log n) n)))"
+    );
+}
+
+#[test]
+fn return_inside_foreach_carries_its_value_out_of_reduce() {
+    assert_eq!(
+        transpile(
+            "namespace App; int IndexOf(int items, int target) { int i = 0; \
+             foreach (var item in items) { if (item == target) { return i; } i++; } return -1; }"
+        ),
+        "(ns app) (defn index-of [items target] \
+         (let [i 0 has-returned false returned-value nil] \
+         (let [[returned-value has-returned i] (reduce (fn [[returned-value has-returned i] item] \
+         (if (= item target) \
+         (let [returned-value i has-returned true] (reduced [returned-value has-returned i])) \
+         (let [i (inc i)] [returned-value has-returned i]))) \
+         [returned-value has-returned i] items)] \
+         (if has-returned returned-value -1))))"
+    );
+}
+
+#[test]
+fn return_inside_while_carries_its_value_out_of_loop_recur() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Search(int n) { int i = 0; \
+             while (i < n) { if (Check(i)) { return i * 2; } i++; } return -1; }"
+        ),
+        "(ns app) (defn search [n] (let [i 0 has-returned false returned-value nil] \
+         (let [[returned-value has-returned i] \
+         (loop [returned-value returned-value has-returned has-returned i i] \
+         (if (< i n) (if (check i) \
+         (let [returned-value (* i 2) has-returned true] [returned-value has-returned i]) \
+         (let [i (inc i)] (recur returned-value has-returned i))) \
+         [returned-value has-returned i]))] \
+         (if has-returned returned-value -1))))"
+    );
+}
+
+#[test]
+fn return_inside_nested_loop_is_reported() {
+    assert!(transpile_error(
+        "namespace App; int Nest(int n) { for (int i = 0; i < n; i++) { \
+         for (int j = 0; j < n; j++) { if (i == j) { return i; } } } return 0; }"
+    )
+    .contains("`return` inside `for` loop in method nest cannot be restructured: it is nested inside another loop"));
+}
+
+#[test]
+fn return_inside_switch_inside_loop_is_reported() {
+    assert!(transpile_error(
+        "namespace App; int Pick(int n) { foreach (var item in n) { \
+         switch (item) { case 1: return 1; } } return 0; }"
+    )
+    .contains("only returns directly in the loop body or inside `if` branches are supported"));
+}
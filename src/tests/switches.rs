@@ -0,0 +1,130 @@
+use crate::tests::{transpile, transpile_error};
+
+#[test]
+fn constant_labels_become_case_with_grouped_labels() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Grade(int level) { switch (level) { \
+             case 1: case 2: return 10; case 3: return 20; default: return 0; } }"
+        ),
+        "(ns app) (defn grade [level] (case level (1 2) 10 3 20 0))"
+    );
+}
+
+#[test]
+fn string_labels_become_case_constants() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             string Label(string code) { switch (code) { case \"a\": return \"Alpha\"; default: return \"Other\"; } }"
+        ),
+        "(ns app) (defn label [code] (case code \"a\" \"Alpha\" \"Other\"))"
+    );
+}
+
+#[test]
+fn enum_labels_are_compared_with_condp_as_host_fields() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Hue() { var c = Color.Red; switch (c) { case Color.Red: return 1; case Color.Blue: return 2; default: return 0; } }"
+        ),
+        "(ns app) (defn hue [] (let [c Color/Red] (condp = c Color/Red 1 Color/Blue 2 0)))"
+    );
+}
+
+#[test]
+fn grouped_enum_labels_are_matched_by_set_membership() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             int Hue(Color c) { switch (c) { case Color.Red: return 1; case Color.Blue: case Color.Green: return 2; default: return 0; } }"
+        ),
+        "(ns app) (defn hue [c] (condp contains? c #{Color/Red} 1 #{Color/Blue Color/Green} 2 0))"
+    );
+}
+
+#[test]
+fn enum_switch_without_default_still_has_a_fallback() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             string Tag(Color c) { return c switch { Color.Red => \"r\", Color.Blue => \"b\" }; } \
+             int Hue(Color c) { int h = 0; switch (c) { case Color.Red: h = 1; break; } return h; }"
+        ),
+        "(ns app) (defn tag [c] (condp = c Color/Red \"r\" Color/Blue \"b\" \
+             (throw (ex-info \"No matching pattern\" {:value c})))) \
+         (defn hue [c] (let [h 0] (let [h (condp = c Color/Red 1 h)] h)))"
+    );
+}
+
+#[test]
+fn switch_without_default_falls_back_to_nil() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Report(int n) { switch (n) { \
+             case 1: Draw(n); break; case 2: Draw(2); break; } return n; }"
+        ),
+        "(ns app) (defn report [n] (case n 1 (draw n) 2 (draw 2) nil) n)"
+    );
+}
+
+#[test]
+fn guarded_labels_become_cond() {
+    assert_eq!(
+        transpile(
+            "namespace App; int Sign(int n) { switch (n) { \
+             case 0: return 0; case int v when v > 0: return 1; default: return -1; } }"
+        ),
+        "(ns app) (defn sign [n] (cond (= n 0) 0 (and (int? n) (let [v n] (> v 0))) (let [v n] 1) :else -1))"
+    );
+}
+
+#[test]
+fn goto_case_is_reported_with_its_line() {
+    assert!(transpile_error(
+        "namespace App;\nint Jump(int n) { switch (n) { case 1: goto case 2; case 2: return 2; default: return 0; } }"
+    )
+    .contains("Unsupported fall-through in switch: `goto case 2` on line 2"));
+}
+
+#[test]
+fn negative_literals_remain_case_constants() {
+    assert_eq!(
+        transpile(
+            "namespace App; string Sign(int code) { switch (code) { case -1: return \"minus\"; \
+             case 1: return \"plus\"; default: return \"zero\"; } }"
+        ),
+        "(ns app) (defn sign [code] (case code -1 \"minus\" 1 \"plus\" \"zero\"))"
+    );
+}
+
+#[test]
+fn labels_naming_constants_are_compared_by_value() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             class Limits { \
+                 public const int Top = 3; \
+                 public static string Rate(int n) { const int K = 2; \
+                     switch (n) { case Top: return \"top\"; case K: return \"k\"; case 1: return \"one\"; default: return \"other\"; } } \
+             }"
+        ),
+        "(ns app) (def ^:const top 3) \
+         (defn rate [n] (let [k 2] (condp = n top \"top\" k \"k\" 1 \"one\" \"other\")))"
+    );
+}
+
+#[test]
+fn labels_naming_constants_of_another_class_use_their_def() {
+    assert_eq!(
+        transpile(
+            "namespace App; \
+             class Limits { public const int Top = 3; } \
+             class Grader { public static string Rate(int n) { \
+                 switch (n) { case Limits.Top: return \"top\"; case > Limits.Top: return \"over\"; default: return \"under\"; } } }"
+        ),
+        "(ns app) (def ^:const top 3) \
+         (defn rate [n] (cond (= n top) \"top\" (> n top) \"over\" :else \"under\"))"
+    );
+}
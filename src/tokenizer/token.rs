@@ -1,25 +1,33 @@
 use std::fmt::Display;
 
+#[derive(Clone)]
 pub struct Token {
     pub type_: TokenType,
     pub value: Option<String>,
+    pub line: usize,
 }
 
 impl Display for Token {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", format!("{{type: {:?}, value: {:?}}}", self.type_, self.value))
+        write!(
+            f,
+            "{{type: {:?}, value: {:?}, line: {}}}",
+            self.type_, self.value, self.line
+        )
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum TokenType {
     NamespaceToken,
+    UsingToken,
     ClassToken,
 
     TypeDeclarationToken,
     NameIdentifierToken,
 
     DotMethodToken,
+    NullConditionalToken,
 
     OpenParenthesisToken,
     CloseParenthesisToken,
@@ -37,6 +45,7 @@ pub enum TokenType {
 
     NullLiteralToken,
     NumericLiteralToken,
+    StringLiteralToken,
     BooleanLiteralToken,
 
     NumericOperationToken,
@@ -44,8 +53,20 @@ pub enum TokenType {
 
     AssignmentOperatorToken,
     EqualityOperatorToken,
+    RelationalOperatorToken,
 
     BranchingOperatorToken,
+    LoopToken,
+    LoopControlToken,
+    SwitchToken,
+    SwitchLabelToken,
+    GotoToken,
+    ColonToken,
+    ArrowToken,
+
+    ExceptionHandlingToken,
+    ThrowToken,
+    NewToken,
 
     CommentToken,
 }
@@ -7,9 +7,45 @@ pub fn tokenize(source_code_text: String) -> Result<Vec<Token>, String> {
     let mut token_queue: Vec<Token> = Vec::new();
     let source_queue: Vec<char> = source_code_text.chars().collect::<Vec<char>>();
     let mut source_queue: Peekable<Iter<char>> = source_queue.iter().peekable();
+    let mut current_line: usize = 1;
 
     while let Some(character) = source_queue.next() {
         if char::is_whitespace(*character) {
+            if *character == '\n' {
+                current_line += 1;
+            }
+            continue;
+        }
+
+        if *character == '"' {
+            let mut parsed_string: String = String::from(*character);
+            let mut is_escaped: bool = false;
+
+            loop {
+                let next_character: char = match source_queue.next() {
+                    Some(next_character) => *next_character,
+                    None => {
+                        return Err(format!(
+                            "Unterminated string literal on line {}.",
+                            current_line
+                        ))
+                    }
+                };
+                parsed_string.push(next_character);
+
+                match next_character {
+                    '"' if !is_escaped => break,
+                    '\\' => is_escaped = !is_escaped,
+                    _ => is_escaped = false,
+                }
+            }
+
+            token_queue.push(Token {
+                type_: TokenType::StringLiteralToken,
+                value: Some(parsed_string),
+                line: current_line,
+            });
+
             continue;
         }
 
@@ -22,6 +58,7 @@ pub fn tokenize(source_code_text: String) -> Result<Vec<Token>, String> {
             token_queue.push(Token {
                 type_: TokenType::CommentToken,
                 value: Some(parsed_comment[2..].to_string()),
+                line: current_line,
             });
 
             for _ in 1..parsed_length {
@@ -31,93 +68,175 @@ pub fn tokenize(source_code_text: String) -> Result<Vec<Token>, String> {
             continue;
         }
 
-        if char::is_alphanumeric(*character) {
+        if char::is_alphanumeric(*character) || *character == '_' {
             let (parsed_identifier, parsed_ident_length): (String, usize) = parse_with_predicate(
                 *character,
                 source_queue.clone(),
-                match char::is_alphanumeric(*character) {
-                    true => |c: &&char| char::is_alphanumeric(**c) || **c == '<' || **c == '>',
+                match char::is_alphabetic(*character) || *character == '_' {
+                    true => |c: &&char| {
+                        char::is_alphanumeric(**c) || **c == '_' || **c == '<' || **c == '>'
+                    },
                     false => {
                         |c: &&char| char::is_numeric(**c) || **c == '.' || **c == 'f' || **c == 'd'
                     }
                 },
             );
+            let (parsed_identifier, parsed_ident_length): (String, usize) =
+                complete_generic_type_arguments(
+                    parsed_identifier,
+                    parsed_ident_length,
+                    source_queue.clone(),
+                );
 
             token_queue.push(match parsed_identifier.as_str() {
                 "namespace" => Token {
                     type_: TokenType::NamespaceToken,
                     value: None,
+                    line: current_line,
+                },
+                "using" => Token {
+                    type_: TokenType::UsingToken,
+                    value: None,
+                    line: current_line,
                 },
                 "class" => Token {
                     type_: TokenType::ClassToken,
                     value: None,
+                    line: current_line,
                 },
                 "var" | "int" | "double" | "string" | "bool" => Token {
                     type_: TokenType::TypeDeclarationToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 generic if is_generic_type(generic) => Token {
                     type_: TokenType::TypeDeclarationToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 "true" | "false" => Token {
                     type_: TokenType::BooleanLiteralToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 "null" => Token {
                     type_: TokenType::NullLiteralToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 "return" => Token {
                     type_: TokenType::ReturnToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 "if" | "else" => Token {
                     type_: TokenType::BranchingOperatorToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "while" | "do" | "for" | "foreach" => Token {
+                    type_: TokenType::LoopToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "switch" => Token {
+                    type_: TokenType::SwitchToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "case" | "default" => Token {
+                    type_: TokenType::SwitchLabelToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "goto" => Token {
+                    type_: TokenType::GotoToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "try" | "catch" | "finally" => Token {
+                    type_: TokenType::ExceptionHandlingToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "throw" => Token {
+                    type_: TokenType::ThrowToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "new" => Token {
+                    type_: TokenType::NewToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
+                },
+                "break" | "continue" => Token {
+                    type_: TokenType::LoopControlToken,
+                    value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 _ if char::is_numeric(*character) => Token {
                     type_: TokenType::NumericLiteralToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
                 _ => Token {
                     type_: TokenType::NameIdentifierToken,
                     value: Some(parsed_identifier),
+                    line: current_line,
                 },
             });
 
             for _ in 1..parsed_ident_length {
                 source_queue.next();
             }
+
+            continue;
         }
 
         if char::is_ascii_punctuation(character) {
-            let (parsed_symbols, parsed_symbol_length): (String, usize) =
+            let (parsed_symbols, _): (String, usize) =
                 parse_with_predicate(*character, source_queue.clone(), |c| {
-                    char::is_ascii_punctuation(*c)
+                    char::is_ascii_punctuation(c)
                 });
+            let parsed_symbol_pair: String = parsed_symbols.chars().take(2).collect::<String>();
 
-            let matching_token: Option<Token> = match parsed_symbols.as_str() {
-                "==" => Some(Token {
+            let matching_token: Option<Token> = match parsed_symbol_pair.as_str() {
+                "==" | "!=" => Some(Token {
                     type_: TokenType::EqualityOperatorToken,
-                    value: None,
+                    value: Some(parsed_symbol_pair),
+                    line: current_line,
+                }),
+                "<=" | ">=" => Some(Token {
+                    type_: TokenType::RelationalOperatorToken,
+                    value: Some(parsed_symbol_pair),
+                    line: current_line,
                 }),
                 "&&" | "||" => Some(Token {
                     type_: TokenType::BooleanOperationToken,
-                    value: Some(parsed_symbols),
+                    value: Some(parsed_symbol_pair),
+                    line: current_line,
+                }),
+                "=>" => Some(Token {
+                    type_: TokenType::ArrowToken,
+                    value: None,
+                    line: current_line,
+                }),
+                "?." => Some(Token {
+                    type_: TokenType::NullConditionalToken,
+                    value: None,
+                    line: current_line,
                 }),
-                "+=" | "-=" | "*=" | "/=" => Some(Token {
+                "+=" | "-=" | "*=" | "/=" | "%=" | "++" | "--" => Some(Token {
                     type_: TokenType::AssignmentOperatorToken,
-                    value: Some(parsed_symbols),
+                    value: Some(parsed_symbol_pair),
+                    line: current_line,
                 }),
                 _ => None,
             };
 
-            if matching_token.is_some() {
-                for _ in 1..parsed_symbol_length {
-                    source_queue.next();
-                }
-                token_queue.push(matching_token.unwrap());
+            if let Some(matching_token) = matching_token {
+                source_queue.next();
+                token_queue.push(matching_token);
                 continue;
             }
 
@@ -125,50 +244,72 @@ pub fn tokenize(source_code_text: String) -> Result<Vec<Token>, String> {
                 '=' => Token {
                     type_: TokenType::AssignmentOperatorToken,
                     value: None,
+                    line: current_line,
                 },
                 '(' => Token {
                     type_: TokenType::OpenParenthesisToken,
                     value: None,
+                    line: current_line,
                 },
                 ')' => Token {
                     type_: TokenType::CloseParenthesisToken,
                     value: None,
+                    line: current_line,
                 },
                 '{' => Token {
                     type_: TokenType::OpenScopeToken,
                     value: None,
+                    line: current_line,
                 },
                 '}' => Token {
                     type_: TokenType::CloseScopeToken,
                     value: None,
+                    line: current_line,
                 },
                 '[' => Token {
                     type_: TokenType::OpenCollectionToken,
                     value: None,
+                    line: current_line,
                 },
                 ']' => Token {
                     type_: TokenType::CloseCollectionToken,
                     value: None,
+                    line: current_line,
                 },
                 ';' => Token {
                     type_: TokenType::SemicolonToken,
                     value: None,
+                    line: current_line,
                 },
-                '+' | '-' | '*' | '/' => Token {
+                '+' | '-' | '*' | '/' | '%' => Token {
                     type_: TokenType::NumericOperationToken,
                     value: Some(character.to_string()),
+                    line: current_line,
+                },
+                '<' | '>' => Token {
+                    type_: TokenType::RelationalOperatorToken,
+                    value: Some(character.to_string()),
+                    line: current_line,
                 },
-                '|' | '&' => Token {
+                '|' | '&' | '!' => Token {
                     type_: TokenType::BooleanOperationToken,
                     value: Some(character.to_string()),
+                    line: current_line,
+                },
+                ':' => Token {
+                    type_: TokenType::ColonToken,
+                    value: None,
+                    line: current_line,
                 },
                 ',' => Token {
                     type_: TokenType::CommaToken,
                     value: None,
+                    line: current_line,
                 },
                 '.' => Token {
                     type_: TokenType::DotMethodToken,
                     value: None,
+                    line: current_line,
                 },
                 _ => return Err(format!("Unrecognized character '{}'.", *character)),
             });
@@ -188,6 +329,46 @@ fn is_generic_type(text_value: &str) -> bool {
     }
 }
 
+fn complete_generic_type_arguments(
+    parsed_identifier: String,
+    parsed_length: usize,
+    mut source_queue: Peekable<Iter<char>>,
+) -> (String, usize) {
+    let mut nesting_depth: usize = parsed_identifier.matches('<').count();
+    if nesting_depth <= parsed_identifier.matches('>').count() {
+        return (parsed_identifier, parsed_length);
+    }
+    nesting_depth -= parsed_identifier.matches('>').count();
+
+    let mut completed_identifier: String = parsed_identifier.clone();
+    let mut completed_length: usize = parsed_length;
+
+    for _ in 1..parsed_length {
+        source_queue.next();
+    }
+
+    for character in source_queue {
+        match *character {
+            '<' => nesting_depth += 1,
+            '>' => nesting_depth -= 1,
+            ',' | '[' | ']' | '?' | '_' => {}
+            c if char::is_alphanumeric(c) || c == ' ' => {}
+            _ => return (parsed_identifier, parsed_length),
+        }
+
+        completed_length += 1;
+        if *character != ' ' {
+            completed_identifier.push(*character);
+        }
+
+        if nesting_depth == 0 {
+            return (completed_identifier, completed_length);
+        }
+    }
+
+    (parsed_identifier, parsed_length)
+}
+
 fn parse_with_predicate(
     initial_character: char,
     mut source_queue: Peekable<Iter<char>>,
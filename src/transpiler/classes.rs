@@ -0,0 +1,1935 @@
+// Lowering errors are propagated with explicit matches, as in the syntax tree builder.
+#![allow(clippy::question_mark)]
+
+use crate::syntax_tree::syntax_tree_builder::group_consecutive_assignments;
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::mutation::{create_call_node, create_literal_node, hold_names_in_atoms};
+use crate::transpiler::objects::find_default_value;
+use crate::transpiler::patterns::is_constant_literal;
+use crate::transpiler::transpiler::{close_open_parentheses, convert_abstract_syntax_tree_to_code};
+use std::collections::HashMap;
+
+const INSTANCE_ARGUMENT_NAME: &str = "this";
+
+struct RecordShape {
+    class_name: String,
+    field_names: Vec<String>,
+    field_initializers: Vec<SyntaxTreeNode>,
+    has_factory: bool,
+}
+
+struct InterfaceShape {
+    interface_name: String,
+    base_names: Vec<String>,
+    method_names: Vec<String>,
+    default_method_nodes: Vec<SyntaxTreeNode>,
+}
+
+struct ClassMembers {
+    class_names: Vec<String>,
+    static_member_names: Vec<String>,
+    constant_names: Vec<String>,
+    instance_method_names: Vec<String>,
+    updating_method_names: HashMap<String, String>,
+    method_owners: HashMap<String, String>,
+    records: Vec<RecordShape>,
+    interfaces: Vec<InterfaceShape>,
+}
+
+pub fn lower_classes(
+    abstract_syntax_tree: SyntaxTreeNode,
+) -> Result<(SyntaxTreeNode, Vec<String>), String> {
+    let mut class_members: ClassMembers = ClassMembers {
+        class_names: vec![],
+        static_member_names: vec![],
+        constant_names: vec![],
+        instance_method_names: vec![],
+        updating_method_names: HashMap::new(),
+        method_owners: HashMap::new(),
+        records: vec![],
+        interfaces: vec![],
+    };
+    let mut warnings: Vec<String> = vec![];
+
+    let mut inherited_fields: HashMap<String, (Vec<String>, Vec<SyntaxTreeNode>)> = HashMap::new();
+    collect_inheritable_fields(&abstract_syntax_tree, &mut inherited_fields);
+    let abstract_syntax_tree: SyntaxTreeNode =
+        merge_inherited_fields(abstract_syntax_tree, &inherited_fields);
+
+    collect_interfaces(&abstract_syntax_tree, &mut class_members);
+    if let Err(error) =
+        collect_class_members(&abstract_syntax_tree, &mut class_members, &mut warnings)
+    {
+        return Err(error);
+    }
+
+    match lower_syntax_tree_node(abstract_syntax_tree, &class_members) {
+        Ok(lowered_node) => Ok((lowered_node, warnings)),
+        Err(error) => Err(error),
+    }
+}
+
+fn collect_inheritable_fields(
+    syntax_tree_node: &SyntaxTreeNode,
+    inherited_fields: &mut HashMap<String, (Vec<String>, Vec<SyntaxTreeNode>)>,
+) {
+    for child in &syntax_tree_node.children {
+        if let (SyntaxTreeNodeType::Class, Some(class_name)) = (&child.type_, &child.value) {
+            inherited_fields.insert(
+                class_name.clone(),
+                (
+                    child
+                        .children
+                        .iter()
+                        .filter(|member_node| matches!(member_node.type_, SyntaxTreeNodeType::Type))
+                        .filter_map(|type_node| type_node.value.clone())
+                        .collect::<Vec<String>>(),
+                    find_instance_field_nodes(child)
+                        .into_iter()
+                        .cloned()
+                        .collect::<Vec<SyntaxTreeNode>>(),
+                ),
+            );
+        }
+
+        collect_inheritable_fields(child, inherited_fields);
+    }
+}
+
+/// Records cannot extend each other, so the instance fields of a base class declared in this
+/// file are copied into every class derived from it.
+fn merge_inherited_fields(
+    syntax_tree_node: SyntaxTreeNode,
+    inherited_fields: &HashMap<String, (Vec<String>, Vec<SyntaxTreeNode>)>,
+) -> SyntaxTreeNode {
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+
+    if matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Class) {
+        let mut base_names: Vec<String> = syntax_tree_node
+            .children
+            .iter()
+            .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+            .filter_map(|type_node| type_node.value.clone())
+            .collect::<Vec<String>>();
+        let mut visited_names: Vec<String> = vec![];
+
+        while let Some(base_name) = base_names.pop() {
+            if visited_names.contains(&base_name) {
+                continue;
+            }
+            if let Some((grand_base_names, field_nodes)) = inherited_fields.get(&base_name) {
+                let inherited_nodes: Vec<SyntaxTreeNode> = field_nodes
+                    .iter()
+                    .filter(|field_node| {
+                        !syntax_tree_node.children.iter().chain(children.iter()).any(
+                            |member_node| {
+                                matches!(member_node.type_, SyntaxTreeNodeType::Field)
+                                    && member_node.value == field_node.value
+                            },
+                        )
+                    })
+                    .cloned()
+                    .collect::<Vec<SyntaxTreeNode>>();
+                children.splice(0..0, inherited_nodes);
+                base_names.extend(grand_base_names.iter().cloned());
+            }
+            visited_names.push(base_name);
+        }
+    }
+
+    children.extend(
+        syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| merge_inherited_fields(child, inherited_fields)),
+    );
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    }
+}
+
+fn collect_interfaces(syntax_tree_node: &SyntaxTreeNode, class_members: &mut ClassMembers) {
+    if let (SyntaxTreeNodeType::Interface, Some(interface_name)) =
+        (&syntax_tree_node.type_, &syntax_tree_node.value)
+    {
+        let method_nodes: Vec<&SyntaxTreeNode> = syntax_tree_node
+            .children
+            .iter()
+            .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Method))
+            .collect::<Vec<&SyntaxTreeNode>>();
+
+        class_members.instance_method_names.extend(
+            method_nodes
+                .iter()
+                .filter_map(|method_node| method_node.value.clone()),
+        );
+        class_members.interfaces.push(InterfaceShape {
+            interface_name: interface_name.clone(),
+            base_names: syntax_tree_node
+                .children
+                .iter()
+                .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+                .filter_map(|type_node| type_node.value.clone())
+                .collect::<Vec<String>>(),
+            method_names: method_nodes
+                .iter()
+                .filter_map(|method_node| method_node.value.clone())
+                .collect::<Vec<String>>(),
+            default_method_nodes: method_nodes
+                .into_iter()
+                .filter(|method_node| {
+                    method_node.children.iter().any(|child| {
+                        !matches!(
+                            child.type_,
+                            SyntaxTreeNodeType::Type | SyntaxTreeNodeType::MethodArgument
+                        )
+                    })
+                })
+                .cloned()
+                .collect::<Vec<SyntaxTreeNode>>(),
+        });
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_interfaces(child, class_members);
+    }
+}
+
+fn collect_class_members(
+    syntax_tree_node: &SyntaxTreeNode,
+    class_members: &mut ClassMembers,
+    warnings: &mut Vec<String>,
+) -> Result<(), String> {
+    if let (SyntaxTreeNodeType::Class, Some(class_name)) =
+        (&syntax_tree_node.type_, &syntax_tree_node.value)
+    {
+        class_members.class_names.push(class_name.clone());
+        flag_explicit_implementation_collisions(syntax_tree_node, class_members, warnings);
+
+        let implemented_method_names: Vec<String> =
+            find_implemented_interfaces(syntax_tree_node, class_members)
+                .iter()
+                .flat_map(|interface| interface.method_names.iter().cloned())
+                .collect::<Vec<String>>();
+
+        for member_node in &syntax_tree_node.children {
+            let method_name: String = match (&member_node.type_, &member_node.value) {
+                (SyntaxTreeNodeType::Field, Some(field_name)) if is_static_field(member_node) => {
+                    class_members.static_member_names.push(field_name.clone());
+                    if has_modifier(member_node, "const") {
+                        class_members.constant_names.push(field_name.clone());
+                    }
+                    continue;
+                }
+                (SyntaxTreeNodeType::Method, Some(method_name))
+                    if has_instance_argument(member_node) =>
+                {
+                    let (method_name, interface_name): (String, Option<String>) =
+                        split_explicit_name(method_name);
+                    class_members
+                        .instance_method_names
+                        .push(method_name.clone());
+
+                    if interface_name.is_some() || implemented_method_names.contains(&method_name) {
+                        continue;
+                    }
+                    method_name
+                }
+                (SyntaxTreeNodeType::Method, Some(method_name)) => {
+                    class_members.static_member_names.push(method_name.clone());
+                    method_name.clone()
+                }
+                _ => continue,
+            };
+
+            match class_members.method_owners.get(&method_name) {
+                Some(owner_name) if owner_name != class_name => {
+                    return Err(format!(
+                        "method {} is defined in both class {} and class {}; methods become namespace-level functions, so declare it on a shared interface to turn it into a protocol method",
+                        method_name, owner_name, class_name
+                    ))
+                }
+                _ => {
+                    class_members
+                        .method_owners
+                        .insert(method_name, class_name.clone());
+                }
+            }
+        }
+
+        let instance_field_nodes: Vec<&SyntaxTreeNode> =
+            find_instance_field_nodes(syntax_tree_node);
+        collect_updating_methods(syntax_tree_node, &instance_field_nodes, class_members);
+
+        if !instance_field_nodes.is_empty()
+            || !find_implemented_interfaces(syntax_tree_node, class_members).is_empty()
+        {
+            let constructor_node: Option<&SyntaxTreeNode> =
+                match find_constructor_node(syntax_tree_node) {
+                    Ok(constructor_node) => constructor_node,
+                    Err(error) => return Err(error),
+                };
+
+            class_members.records.push(RecordShape {
+                class_name: class_name.clone(),
+                field_names: instance_field_nodes
+                    .iter()
+                    .filter_map(|field_node| field_node.value.clone())
+                    .collect::<Vec<String>>(),
+                field_initializers: instance_field_nodes
+                    .iter()
+                    .filter(|field_node| find_field_initializer(field_node).is_some())
+                    .map(|field_node| SyntaxTreeNode {
+                        value: field_node.value.clone(),
+                        type_: SyntaxTreeNodeType::MemberInitializer,
+                        children: Vec::from([find_field_initializer(field_node).unwrap().clone()]),
+                    })
+                    .collect::<Vec<SyntaxTreeNode>>(),
+                has_factory: constructor_node.is_some_and(|constructor_node| {
+                    create_factory_method(class_name, &instance_field_nodes, constructor_node)
+                        .is_some()
+                }),
+            });
+        }
+    }
+
+    for child in &syntax_tree_node.children {
+        if let Err(error) = collect_class_members(child, class_members, warnings) {
+            return Err(error);
+        }
+    }
+
+    Ok(())
+}
+
+fn flag_explicit_implementation_collisions(
+    class_node: &SyntaxTreeNode,
+    class_members: &ClassMembers,
+    warnings: &mut Vec<String>,
+) {
+    let class_name: String = class_node.value.clone().unwrap();
+    let implemented_interfaces: Vec<&InterfaceShape> =
+        find_implemented_interfaces(class_node, class_members);
+    let member_names: Vec<(String, Option<String>)> = class_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Method))
+        .filter_map(|child| child.value.as_deref())
+        .map(split_explicit_name)
+        .collect::<Vec<(String, Option<String>)>>();
+
+    let mut flagged_names: Vec<&String> = vec![];
+
+    for (method_name, _) in member_names
+        .iter()
+        .filter(|(_, interface_name)| interface_name.is_some())
+    {
+        if flagged_names.contains(&method_name) {
+            continue;
+        }
+
+        let mut source_names: Vec<String> = vec![];
+        for (other_name, interface_name) in &member_names {
+            let source_name: String = interface_name.clone().unwrap_or(class_name.clone());
+            if other_name == method_name && !source_names.contains(&source_name) {
+                source_names.push(source_name);
+            }
+        }
+        for interface in &implemented_interfaces {
+            if interface.method_names.contains(method_name)
+                && !source_names.contains(&interface.interface_name)
+            {
+                source_names.push(interface.interface_name.clone());
+            }
+        }
+
+        if source_names.len() > 1 {
+            warnings.push(format!(
+                "explicit interface implementation of `{}` in class {} collides across {}; protocol functions share the namespace, so all of them become `{}`",
+                method_name,
+                class_name,
+                source_names.join(", "),
+                method_name
+            ));
+        }
+        flagged_names.push(method_name);
+    }
+}
+
+fn lower_syntax_tree_node(
+    syntax_tree_node: SyntaxTreeNode,
+    class_members: &ClassMembers,
+) -> Result<SyntaxTreeNode, String> {
+    let syntax_tree_node: SyntaxTreeNode = match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Method => {
+            let syntax_tree_node: SyntaxTreeNode =
+                match thread_record_updates(syntax_tree_node, class_members) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                };
+
+            let mut local_record_types: HashMap<String, String> = HashMap::new();
+            collect_local_record_types(&syntax_tree_node, class_members, &mut local_record_types);
+
+            let mut local_names: Vec<String> = vec![];
+            collect_local_names(&syntax_tree_node, &mut local_names);
+            let syntax_tree_node: SyntaxTreeNode =
+                resolve_constant_patterns(syntax_tree_node, &local_names, class_members);
+
+            match resolve_record_deconstructions(
+                syntax_tree_node,
+                class_members,
+                &local_record_types,
+            ) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            }
+        }
+        _ => syntax_tree_node,
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+
+    for child in syntax_tree_node.children {
+        match child.type_ {
+            SyntaxTreeNodeType::Class => match lower_class(child, class_members) {
+                Ok(valid_nodes) => children.extend(valid_nodes),
+                Err(error) => return Err(error),
+            },
+            SyntaxTreeNodeType::Interface => children.push(lower_interface(child)),
+            _ => match lower_syntax_tree_node(child, class_members) {
+                Ok(valid_node) => children.push(valid_node),
+                Err(error) => return Err(error),
+            },
+        }
+    }
+
+    let syntax_tree_node: SyntaxTreeNode = match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Namespace => hold_mutable_static_fields(SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children,
+        }),
+        _ => SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children,
+        },
+    };
+
+    Ok(
+        match (
+            &syntax_tree_node.type_,
+            syntax_tree_node.value.as_deref(),
+            syntax_tree_node.children.first(),
+        ) {
+            (SyntaxTreeNodeType::MemberAccess, Some(member_name), Some(target_node))
+                if is_class_reference(target_node, class_members)
+                    && class_members
+                        .static_member_names
+                        .contains(&member_name.to_string()) =>
+            {
+                create_literal_node(member_name)
+            }
+            (SyntaxTreeNodeType::MemberAccess, Some(property_name), Some(_))
+                if class_members
+                    .instance_method_names
+                    .contains(&property_name.to_string()) =>
+            {
+                create_call_node(property_name, syntax_tree_node.children)
+            }
+            (SyntaxTreeNodeType::Invocation, Some(method_name), Some(target_node))
+                if is_class_reference(target_node, class_members)
+                    && class_members
+                        .static_member_names
+                        .contains(&method_name.to_string()) =>
+            {
+                let method_name: String = method_name.to_string();
+                let mut children: Vec<SyntaxTreeNode> = syntax_tree_node.children;
+                children.remove(0);
+                create_call_node(&method_name, children)
+            }
+            (SyntaxTreeNodeType::ObjectCreation, _, Some(type_node))
+                if matches!(type_node.type_, SyntaxTreeNodeType::Type) =>
+            {
+                match type_node
+                    .value
+                    .as_deref()
+                    .and_then(|type_name| find_record(type_name, class_members))
+                {
+                    Some(record) => create_record_construction(record, syntax_tree_node.children),
+                    None => syntax_tree_node,
+                }
+            }
+            (SyntaxTreeNodeType::Pattern, Some("positional"), _) => {
+                resolve_positional_record_pattern(syntax_tree_node, class_members)
+            }
+            _ => syntax_tree_node,
+        },
+    )
+}
+
+fn resolve_positional_record_pattern(
+    pattern_node: SyntaxTreeNode,
+    class_members: &ClassMembers,
+) -> SyntaxTreeNode {
+    let record: Option<&RecordShape> = pattern_node
+        .children
+        .iter()
+        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        .and_then(|type_node| type_node.value.as_deref())
+        .and_then(|type_name| find_record(type_name, class_members));
+
+    let record: &RecordShape = match record {
+        Some(record)
+            if pattern_node
+                .children
+                .iter()
+                .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Pattern))
+                .count()
+                == record.field_names.len() =>
+        {
+            record
+        }
+        _ => return pattern_node,
+    };
+
+    let mut field_names: std::slice::Iter<String> = record.field_names.iter();
+
+    SyntaxTreeNode {
+        value: Some(String::from("property")),
+        type_: SyntaxTreeNodeType::Pattern,
+        children: pattern_node
+            .children
+            .into_iter()
+            .map(|child| match child.type_ {
+                SyntaxTreeNodeType::Pattern => SyntaxTreeNode {
+                    value: field_names.next().cloned(),
+                    type_: SyntaxTreeNodeType::PatternProperty,
+                    children: Vec::from([child]),
+                },
+                _ => child,
+            })
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+/// Pattern labels that name a constant parse as type patterns, since a bare name could be either.
+/// Names of locals and `const` fields are turned back into constant patterns, and constants
+/// qualified by a class of this file are referenced by their def name.
+fn resolve_constant_patterns(
+    syntax_tree_node: SyntaxTreeNode,
+    local_names: &[String],
+    class_members: &ClassMembers,
+) -> SyntaxTreeNode {
+    let constant_name: Option<String> = match (
+        &syntax_tree_node.type_,
+        syntax_tree_node.value.as_deref(),
+        syntax_tree_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Pattern, Some("type"), [type_node])
+            if type_node.value.as_ref().is_some_and(|type_name| {
+                local_names.contains(type_name) || class_members.constant_names.contains(type_name)
+            }) =>
+        {
+            type_node.value.clone()
+        }
+        (SyntaxTreeNodeType::Pattern, Some("constant"), [constant_node]) => constant_node
+            .value
+            .as_deref()
+            .and_then(|value| value.split_once('.'))
+            .filter(|(class_name, member_name)| {
+                class_members.class_names.contains(&class_name.to_string())
+                    && class_members
+                        .constant_names
+                        .contains(&member_name.to_string())
+            })
+            .map(|(_, member_name)| member_name.to_string()),
+        _ => None,
+    };
+
+    if let Some(constant_name) = constant_name {
+        return SyntaxTreeNode {
+            value: Some(String::from("constant")),
+            type_: SyntaxTreeNodeType::Pattern,
+            children: Vec::from([create_literal_node(&constant_name)]),
+        };
+    }
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| resolve_constant_patterns(child, local_names, class_members))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn lower_class(
+    class_node: SyntaxTreeNode,
+    class_members: &ClassMembers,
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let class_name: String = class_node.value.clone().unwrap();
+    let implemented_interfaces: Vec<&InterfaceShape> =
+        find_implemented_interfaces(&class_node, class_members);
+
+    let factory_node: Option<SyntaxTreeNode> = match find_constructor_node(&class_node) {
+        Ok(constructor_node) => constructor_node.and_then(|constructor_node| {
+            create_factory_method(
+                &class_name,
+                &find_instance_field_nodes(&class_node),
+                constructor_node,
+            )
+        }),
+        Err(error) => return Err(error),
+    };
+
+    let mut record_children: Vec<SyntaxTreeNode> = vec![];
+    let mut static_field_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut method_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    for member_node in class_node.children {
+        match member_node.type_ {
+            SyntaxTreeNodeType::Field if is_static_field(&member_node) => {
+                match lower_syntax_tree_node(member_node, class_members) {
+                    Ok(valid_node) => static_field_nodes.push(valid_node),
+                    Err(error) => return Err(error),
+                }
+            }
+            SyntaxTreeNodeType::Method => method_nodes.push(member_node),
+            SyntaxTreeNodeType::Constructor | SyntaxTreeNodeType::Type => {}
+            _ => record_children.push(member_node),
+        }
+    }
+
+    let instance_field_names: Vec<String> = record_children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Field))
+        .filter_map(|child| child.value.clone())
+        .collect::<Vec<String>>();
+
+    let explicit_implementations: Vec<(String, String)> = method_nodes
+        .iter()
+        .filter_map(|method_node| method_node.value.as_deref())
+        .map(split_explicit_name)
+        .filter_map(|(method_name, interface_name)| Some((interface_name?, method_name)))
+        .collect::<Vec<(String, String)>>();
+
+    let mut implementation_nodes: Vec<(String, SyntaxTreeNode)> = vec![];
+    let mut standalone_method_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    for method_node in method_nodes {
+        let (method_name, explicit_interface_name): (String, Option<String>) =
+            split_explicit_name(method_node.value.as_deref().unwrap());
+        let interface_name: Option<String> = explicit_interface_name
+            .filter(|interface_name| {
+                implemented_interfaces
+                    .iter()
+                    .any(|interface| &interface.interface_name == interface_name)
+            })
+            .or_else(|| {
+                implemented_interfaces
+                    .iter()
+                    .find(|interface| {
+                        interface.method_names.contains(&method_name)
+                            && !explicit_implementations
+                                .contains(&(interface.interface_name.clone(), method_name.clone()))
+                    })
+                    .map(|interface| interface.interface_name.clone())
+            });
+
+        let method_node: SyntaxTreeNode = match lower_class_method(
+            SyntaxTreeNode {
+                value: Some(method_name),
+                type_: method_node.type_,
+                children: method_node.children,
+            },
+            &class_name,
+            &instance_field_names,
+            class_members,
+        ) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        };
+
+        match interface_name {
+            Some(interface_name) => implementation_nodes.push((interface_name, method_node)),
+            None => standalone_method_nodes.push(method_node),
+        }
+    }
+
+    for interface in &implemented_interfaces {
+        for property_name in &interface.method_names {
+            let is_implemented: bool =
+                implementation_nodes
+                    .iter()
+                    .any(|(interface_name, method_node)| {
+                        *interface_name == interface.interface_name
+                            && method_node.value.as_ref() == Some(property_name)
+                    });
+
+            if !is_implemented && instance_field_names.contains(property_name) {
+                implementation_nodes.push((
+                    interface.interface_name.clone(),
+                    create_field_accessor(property_name, &class_name),
+                ));
+            }
+        }
+    }
+
+    for interface in &implemented_interfaces {
+        for default_method_node in &interface.default_method_nodes {
+            let is_implemented: bool =
+                implementation_nodes
+                    .iter()
+                    .any(|(interface_name, method_node)| {
+                        *interface_name == interface.interface_name
+                            && method_node.value == default_method_node.value
+                    });
+
+            if !is_implemented {
+                match lower_class_method(
+                    default_method_node.clone(),
+                    &class_name,
+                    &instance_field_names,
+                    class_members,
+                ) {
+                    Ok(valid_node) => {
+                        implementation_nodes.push((interface.interface_name.clone(), valid_node))
+                    }
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+    }
+
+    for interface in &implemented_interfaces {
+        record_children.push(SyntaxTreeNode {
+            value: Some(interface.interface_name.clone()),
+            type_: SyntaxTreeNodeType::Type,
+            children: vec![],
+        });
+        record_children.extend(
+            implementation_nodes
+                .iter()
+                .filter(|(interface_name, _)| *interface_name == interface.interface_name)
+                .map(|(_, method_node)| method_node.clone()),
+        );
+    }
+
+    let mut lowered_nodes: Vec<SyntaxTreeNode> =
+        match order_static_fields(static_field_nodes, &class_name) {
+            Ok(ordered_nodes) => ordered_nodes,
+            Err(error) => return Err(error),
+        };
+
+    if !instance_field_names.is_empty() || !implemented_interfaces.is_empty() {
+        lowered_nodes.push(SyntaxTreeNode {
+            value: Some(class_name),
+            type_: SyntaxTreeNodeType::Class,
+            children: record_children,
+        });
+    }
+
+    if let Some(factory_node) = factory_node {
+        match lower_syntax_tree_node(factory_node, class_members) {
+            Ok(valid_node) => lowered_nodes.push(valid_node),
+            Err(error) => return Err(error),
+        }
+    }
+
+    lowered_nodes.extend(standalone_method_nodes);
+
+    Ok(lowered_nodes)
+}
+
+/// Orders static field defs so that every field is defined after the fields its initializer
+/// reads, keeping the declaration order otherwise.
+fn order_static_fields(
+    field_nodes: Vec<SyntaxTreeNode>,
+    class_name: &str,
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let field_names: Vec<String> = field_nodes
+        .iter()
+        .filter_map(|field_node| field_node.value.clone())
+        .collect::<Vec<String>>();
+
+    let mut pending_nodes: Vec<SyntaxTreeNode> = field_nodes;
+    let mut ordered_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    while !pending_nodes.is_empty() {
+        let ready_index: Option<usize> = pending_nodes.iter().position(|field_node| {
+            field_names.iter().all(|field_name| {
+                Some(field_name) == field_node.value.as_ref()
+                    || !find_field_initializer(field_node)
+                        .is_some_and(|value_node| references_literal(value_node, field_name))
+                    || ordered_nodes
+                        .iter()
+                        .any(|ordered_node| ordered_node.value.as_ref() == Some(field_name))
+            })
+        });
+
+        match ready_index {
+            Some(ready_index) => ordered_nodes.push(pending_nodes.remove(ready_index)),
+            None => {
+                return Err(format!(
+                    "static fields {} of class {} depend on each other",
+                    pending_nodes
+                        .iter()
+                        .filter_map(|field_node| field_node.value.clone())
+                        .collect::<Vec<String>>()
+                        .join(", "),
+                    class_name
+                ))
+            }
+        }
+    }
+
+    Ok(ordered_nodes)
+}
+
+/// A def cannot be rebound by the `let` that local assignments lower to, so static fields that
+/// methods assign are held in atoms instead.
+fn hold_mutable_static_fields(namespace_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let mutable_field_names: Vec<String> = namespace_node
+        .children
+        .iter()
+        .filter(|child| {
+            matches!(child.type_, SyntaxTreeNodeType::Field)
+                && !has_modifier(child, "const")
+                && !has_modifier(child, "readonly")
+        })
+        .filter_map(|child| child.value.clone())
+        .filter(|field_name| {
+            namespace_node
+                .children
+                .iter()
+                .any(|child| assigns_static_field(child, field_name))
+        })
+        .collect::<Vec<String>>();
+
+    if mutable_field_names.is_empty() {
+        return namespace_node;
+    }
+
+    SyntaxTreeNode {
+        value: namespace_node.value,
+        type_: namespace_node.type_,
+        children: namespace_node
+            .children
+            .into_iter()
+            .map(|child| match &child.value {
+                Some(field_name)
+                    if matches!(child.type_, SyntaxTreeNodeType::Field)
+                        && mutable_field_names.contains(field_name) =>
+                {
+                    create_atom_field(child)
+                }
+                _ => hold_static_fields_in_atoms(child, &mutable_field_names),
+            })
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn assigns_static_field(syntax_tree_node: &SyntaxTreeNode, field_name: &str) -> bool {
+    match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Method, _) => {
+            let mut local_names: Vec<String> = vec![];
+            collect_local_names(syntax_tree_node, &mut local_names);
+            if local_names
+                .iter()
+                .any(|local_name| local_name == field_name)
+            {
+                return false;
+            }
+        }
+        (SyntaxTreeNodeType::Assignment, Some(assigned_name)) if assigned_name == field_name => {
+            return true;
+        }
+        _ => {}
+    }
+
+    syntax_tree_node
+        .children
+        .iter()
+        .any(|child| assigns_static_field(child, field_name))
+}
+
+fn hold_static_fields_in_atoms(
+    syntax_tree_node: SyntaxTreeNode,
+    field_names: &[String],
+) -> SyntaxTreeNode {
+    if !matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Method) {
+        return SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children: syntax_tree_node
+                .children
+                .into_iter()
+                .map(|child| hold_static_fields_in_atoms(child, field_names))
+                .collect::<Vec<SyntaxTreeNode>>(),
+        };
+    }
+
+    let mut local_names: Vec<String> = vec![];
+    collect_local_names(&syntax_tree_node, &mut local_names);
+    let held_names: Vec<String> = field_names
+        .iter()
+        .filter(|field_name| !local_names.contains(field_name))
+        .cloned()
+        .collect::<Vec<String>>();
+
+    match held_names.is_empty() {
+        true => syntax_tree_node,
+        false => hold_names_in_atoms(&syntax_tree_node, &held_names),
+    }
+}
+
+fn create_atom_field(field_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let type_name: String = field_node
+        .children
+        .iter()
+        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        .and_then(|type_node| type_node.value.clone())
+        .unwrap_or_default();
+    let (mut children, initializer_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+        field_node.children.into_iter().partition(|child| {
+            matches!(
+                child.type_,
+                SyntaxTreeNodeType::Type | SyntaxTreeNodeType::Modifier
+            )
+        });
+
+    children.push(create_call_node(
+        "atom",
+        match initializer_nodes.is_empty() {
+            true => Vec::from([create_literal_node(find_default_value(&type_name))]),
+            false => initializer_nodes,
+        },
+    ));
+
+    SyntaxTreeNode {
+        value: field_node.value,
+        type_: SyntaxTreeNodeType::Field,
+        children,
+    }
+}
+
+fn references_literal(syntax_tree_node: &SyntaxTreeNode, name: &str) -> bool {
+    (matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Literal)
+        && syntax_tree_node.value.as_deref() == Some(name))
+        || syntax_tree_node
+            .children
+            .iter()
+            .any(|child| references_literal(child, name))
+}
+
+/// An interface property satisfied by a field or auto-property reads the field by keyword.
+fn create_field_accessor(field_name: &str, class_name: &str) -> SyntaxTreeNode {
+    let instance_name: String = to_instance_name(class_name);
+
+    SyntaxTreeNode {
+        value: Some(field_name.to_string()),
+        type_: SyntaxTreeNodeType::Method,
+        children: Vec::from([
+            SyntaxTreeNode {
+                value: Some(instance_name.clone()),
+                type_: SyntaxTreeNodeType::MethodArgument,
+                children: vec![],
+            },
+            SyntaxTreeNode {
+                value: Some(field_name.to_string()),
+                type_: SyntaxTreeNodeType::MemberAccess,
+                children: Vec::from([create_literal_node(&instance_name)]),
+            },
+        ]),
+    }
+}
+
+fn lower_class_method(
+    method_node: SyntaxTreeNode,
+    class_name: &str,
+    instance_field_names: &[String],
+    class_members: &ClassMembers,
+) -> Result<SyntaxTreeNode, String> {
+    let method_node: SyntaxTreeNode = match has_instance_argument(&method_node) {
+        true => {
+            let instance_name: String = to_instance_name(class_name);
+            let mut local_names: Vec<String> = vec![];
+            collect_local_names(&method_node, &mut local_names);
+
+            let field_names: Vec<String> = instance_field_names
+                .iter()
+                .filter(|field_name| !local_names.contains(field_name))
+                .cloned()
+                .collect::<Vec<String>>();
+            let method_names: Vec<String> = class_members
+                .instance_method_names
+                .iter()
+                .filter(|method_name| !local_names.contains(method_name))
+                .cloned()
+                .collect::<Vec<String>>();
+
+            let method_node: SyntaxTreeNode =
+                bind_instance_members(method_node, &instance_name, &field_names, &method_names);
+            let method_node: SyntaxTreeNode =
+                match thread_record_updates(method_node, class_members) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => return Err(error),
+                };
+
+            match rebind_field_assignments(method_node, class_name, &instance_name, &field_names) {
+                Ok(valid_node) => valid_node,
+                Err(error) => return Err(error),
+            }
+        }
+        false => method_node,
+    };
+
+    lower_syntax_tree_node(method_node, class_members)
+}
+
+fn rebind_field_assignments(
+    method_node: SyntaxTreeNode,
+    class_name: &str,
+    instance_name: &str,
+    field_names: &[String],
+) -> Result<SyntaxTreeNode, String> {
+    let update_description: String = match (
+        find_assigned_field(&method_node, field_names),
+        find_assigned_field(&method_node, &[instance_name.to_string()]),
+    ) {
+        (Some(field_name), _) => format!("assigns field {}", field_name),
+        (None, Some(_)) => String::from("calls a method that updates the record"),
+        (None, None) => return Ok(method_node),
+    };
+    let return_type_name: &str = method_node
+        .children
+        .first()
+        .and_then(|type_node| type_node.value.as_deref())
+        .unwrap_or("void");
+
+    if return_type_name != "void" && return_type_name != class_name {
+        return Err(format!(
+            "instance method {} of class {} {} but returns {}; records are immutable, so only methods returning void or {} can hand back the updated record",
+            method_node.value.clone().unwrap(),
+            class_name,
+            update_description,
+            return_type_name,
+            class_name
+        ));
+    }
+
+    let returns_instance: bool = return_type_name == "void";
+    let mut children: Vec<SyntaxTreeNode> = method_node
+        .children
+        .into_iter()
+        .map(|child| replace_field_assignments(child, instance_name, field_names, returns_instance))
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    if returns_instance {
+        children.push(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Return,
+            children: Vec::from([create_literal_node(instance_name)]),
+        });
+    }
+
+    Ok(SyntaxTreeNode {
+        value: method_node.value,
+        type_: method_node.type_,
+        children,
+    })
+}
+
+/// Finds the instance methods of a record class that update it, either by assigning one of its
+/// fields or by calling another such method on the same instance.
+fn collect_updating_methods(
+    class_node: &SyntaxTreeNode,
+    instance_field_nodes: &[&SyntaxTreeNode],
+    class_members: &mut ClassMembers,
+) {
+    let class_name: String = class_node.value.clone().unwrap();
+    let field_names: Vec<String> = instance_field_nodes
+        .iter()
+        .filter_map(|field_node| field_node.value.clone())
+        .collect::<Vec<String>>();
+    let method_nodes: Vec<&SyntaxTreeNode> = class_node
+        .children
+        .iter()
+        .filter(|child| {
+            matches!(child.type_, SyntaxTreeNodeType::Method)
+                && has_instance_argument(child)
+                && child
+                    .children
+                    .first()
+                    .and_then(|type_node| type_node.value.as_deref())
+                    .is_some_and(|type_name| type_name == "void" || type_name == class_name)
+        })
+        .collect::<Vec<&SyntaxTreeNode>>();
+
+    let mut updating_method_names: Vec<String> = vec![];
+    loop {
+        let found_method_names: Vec<String> = method_nodes
+            .iter()
+            .filter(|method_node| {
+                let mut local_names: Vec<String> = vec![];
+                collect_local_names(method_node, &mut local_names);
+                let assignable_field_names: Vec<String> = field_names
+                    .iter()
+                    .filter(|field_name| !local_names.contains(field_name))
+                    .cloned()
+                    .collect::<Vec<String>>();
+
+                find_assigned_field(method_node, &assignable_field_names).is_some()
+                    || calls_instance_method(method_node, &updating_method_names)
+            })
+            .map(|method_node| split_explicit_name(method_node.value.as_deref().unwrap()).0)
+            .filter(|method_name| !updating_method_names.contains(method_name))
+            .collect::<Vec<String>>();
+
+        if found_method_names.is_empty() {
+            break;
+        }
+        updating_method_names.extend(found_method_names);
+    }
+
+    for method_name in updating_method_names {
+        class_members
+            .updating_method_names
+            .insert(method_name, class_name.clone());
+    }
+}
+
+fn calls_instance_method(syntax_tree_node: &SyntaxTreeNode, method_names: &[String]) -> bool {
+    syntax_tree_node.children.iter().any(|child| {
+        let is_instance_call: bool = match (&child.type_, &child.value) {
+            (SyntaxTreeNodeType::Expression, Some(method_name)) => {
+                method_names.contains(method_name)
+            }
+            (SyntaxTreeNodeType::Invocation, Some(method_name)) => {
+                method_names.contains(method_name)
+                    && child.children.first().is_some_and(|target_node| {
+                        matches!(target_node.type_, SyntaxTreeNodeType::Literal)
+                            && target_node.value.as_deref() == Some(INSTANCE_ARGUMENT_NAME)
+                    })
+            }
+            _ => false,
+        };
+
+        is_instance_call || calls_instance_method(child, method_names)
+    })
+}
+
+/// Records are immutable, so a method that updates one hands back the updated record. A call made
+/// as a statement is rebound to its receiver, which later lowers to `(let [c (increment c)] ...)`.
+fn thread_record_updates(
+    syntax_tree_node: SyntaxTreeNode,
+    class_members: &ClassMembers,
+) -> Result<SyntaxTreeNode, String> {
+    let accepts_statements: bool = matches!(
+        syntax_tree_node.type_,
+        SyntaxTreeNodeType::Method
+            | SyntaxTreeNodeType::Branch
+            | SyntaxTreeNodeType::Loop
+            | SyntaxTreeNodeType::SwitchSection
+            | SyntaxTreeNodeType::Block
+            | SyntaxTreeNodeType::Catch
+            | SyntaxTreeNodeType::Using
+    );
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    for child in syntax_tree_node.children {
+        let child: SyntaxTreeNode = match thread_record_updates(child, class_members) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        };
+
+        let updated_class_name: Option<&String> = match (&child.type_, &child.value) {
+            (
+                SyntaxTreeNodeType::Expression | SyntaxTreeNodeType::Invocation,
+                Some(method_name),
+            ) if accepts_statements => class_members.updating_method_names.get(method_name),
+            _ => None,
+        };
+
+        children.push(match (updated_class_name, child.children.first()) {
+            (None, _) => child,
+            (Some(_), Some(receiver_node))
+                if matches!(receiver_node.type_, SyntaxTreeNodeType::Literal)
+                    && receiver_node.children.is_empty() =>
+            {
+                SyntaxTreeNode {
+                    value: receiver_node.value.clone(),
+                    type_: SyntaxTreeNodeType::Assignment,
+                    children: Vec::from([child]),
+                }
+            }
+            (Some(class_name), _) => {
+                return Err(format!(
+                    "call to {} updates a record of class {} but its receiver is not a local variable; records are immutable, so the updated record has to be rebound to a local",
+                    child.value.clone().unwrap(),
+                    class_name
+                ))
+            }
+        });
+    }
+
+    Ok(SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    })
+}
+
+fn find_assigned_field<'a>(
+    syntax_tree_node: &'a SyntaxTreeNode,
+    field_names: &[String],
+) -> Option<&'a String> {
+    syntax_tree_node.children.iter().find_map(|child| {
+        match (&child.type_, &child.value, child.children.as_slice()) {
+            (SyntaxTreeNodeType::Assignment, Some(field_name), [_])
+                if field_names.contains(field_name) =>
+            {
+                Some(field_name)
+            }
+            _ => find_assigned_field(child, field_names),
+        }
+    })
+}
+
+fn replace_field_assignments(
+    syntax_tree_node: SyntaxTreeNode,
+    instance_name: &str,
+    field_names: &[String],
+    returns_instance: bool,
+) -> SyntaxTreeNode {
+    let children: Vec<SyntaxTreeNode> = syntax_tree_node
+        .children
+        .into_iter()
+        .map(|child| replace_field_assignments(child, instance_name, field_names, returns_instance))
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    match (
+        &syntax_tree_node.type_,
+        &syntax_tree_node.value,
+        children.len(),
+    ) {
+        (SyntaxTreeNodeType::Assignment, Some(field_name), 1)
+            if field_names.contains(field_name) =>
+        {
+            let mut children: Vec<SyntaxTreeNode> = children;
+            SyntaxTreeNode {
+                value: Some(instance_name.to_string()),
+                type_: SyntaxTreeNodeType::Assignment,
+                children: Vec::from([create_call_node(
+                    "assoc",
+                    Vec::from([
+                        create_literal_node(instance_name),
+                        SyntaxTreeNode {
+                            value: Some(field_name.clone()),
+                            type_: SyntaxTreeNodeType::MemberAccess,
+                            children: vec![],
+                        },
+                        children.remove(0),
+                    ]),
+                )]),
+            }
+        }
+        (SyntaxTreeNodeType::Return, None, 0) if returns_instance => SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Return,
+            children: Vec::from([create_literal_node(instance_name)]),
+        },
+        _ => SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children,
+        },
+    }
+}
+
+fn lower_interface(interface_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: interface_node.value,
+        type_: SyntaxTreeNodeType::Interface,
+        children: interface_node
+            .children
+            .into_iter()
+            .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Method))
+            .map(|method_node| SyntaxTreeNode {
+                value: method_node.value,
+                type_: SyntaxTreeNodeType::Method,
+                children: method_node
+                    .children
+                    .into_iter()
+                    .filter(|child| {
+                        matches!(
+                            child.type_,
+                            SyntaxTreeNodeType::Type | SyntaxTreeNodeType::MethodArgument
+                        )
+                    })
+                    .collect::<Vec<SyntaxTreeNode>>(),
+            })
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn find_implemented_interfaces<'a>(
+    class_node: &SyntaxTreeNode,
+    class_members: &'a ClassMembers,
+) -> Vec<&'a InterfaceShape> {
+    let mut pending_names: Vec<String> = class_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        .filter_map(|type_node| type_node.value.clone())
+        .collect::<Vec<String>>();
+    let mut implemented_interfaces: Vec<&InterfaceShape> = vec![];
+
+    while let Some(interface_name) = pending_names.pop() {
+        if let Some(interface) = class_members
+            .interfaces
+            .iter()
+            .find(|interface| interface.interface_name == interface_name)
+        {
+            if !implemented_interfaces
+                .iter()
+                .any(|implemented| implemented.interface_name == interface_name)
+            {
+                pending_names.extend(interface.base_names.iter().cloned());
+                implemented_interfaces.push(interface);
+            }
+        }
+    }
+
+    implemented_interfaces.sort_by_key(|interface| {
+        class_members
+            .interfaces
+            .iter()
+            .position(|other| other.interface_name == interface.interface_name)
+    });
+
+    implemented_interfaces
+}
+
+fn split_explicit_name(method_name: &str) -> (String, Option<String>) {
+    match method_name.rsplit_once('.') {
+        Some((interface_name, method_name)) => {
+            (method_name.to_string(), Some(interface_name.to_string()))
+        }
+        None => (method_name.to_string(), None),
+    }
+}
+
+fn create_factory_method(
+    class_name: &str,
+    field_nodes: &[&SyntaxTreeNode],
+    constructor_node: &SyntaxTreeNode,
+) -> Option<SyntaxTreeNode> {
+    let argument_nodes: Vec<&SyntaxTreeNode> = constructor_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::MethodArgument))
+        .collect::<Vec<&SyntaxTreeNode>>();
+    let argument_names: Vec<String> = argument_nodes
+        .iter()
+        .filter_map(|argument_node| argument_node.value.clone())
+        .collect::<Vec<String>>();
+
+    let mut field_values: HashMap<String, SyntaxTreeNode> = HashMap::new();
+    for field_node in field_nodes {
+        if let Some(initializer_node) = find_field_initializer(field_node) {
+            field_values.insert(field_node.value.clone().unwrap(), initializer_node.clone());
+        }
+    }
+
+    let mut body_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    for statement_node in constructor_node
+        .children
+        .iter()
+        .filter(|child| !matches!(child.type_, SyntaxTreeNodeType::MethodArgument))
+        .flat_map(|child| match (&child.type_, &child.value) {
+            (SyntaxTreeNodeType::Assignment, None) => child.children.iter().collect(),
+            _ => Vec::from([child]),
+        })
+    {
+        let statement_node: SyntaxTreeNode =
+            substitute_field_values(statement_node.clone(), &field_values);
+
+        match (
+            &statement_node.type_,
+            &statement_node.value,
+            statement_node.children.as_slice(),
+        ) {
+            (SyntaxTreeNodeType::Assignment, Some(field_name), [value_node])
+                if field_nodes
+                    .iter()
+                    .any(|field_node| field_node.value.as_ref() == Some(field_name)) =>
+            {
+                match (&value_node.type_, &value_node.value) {
+                    (SyntaxTreeNodeType::Literal, Some(argument_name))
+                        if argument_names.contains(argument_name) =>
+                    {
+                        field_values.insert(field_name.clone(), value_node.clone());
+                    }
+                    _ => {
+                        body_nodes.push(SyntaxTreeNode {
+                            value: Some(field_name.clone()),
+                            type_: SyntaxTreeNodeType::Assignment,
+                            children: Vec::from([
+                                SyntaxTreeNode {
+                                    value: Some(String::from("var")),
+                                    type_: SyntaxTreeNodeType::Type,
+                                    children: vec![],
+                                },
+                                value_node.clone(),
+                            ]),
+                        });
+                        field_values.insert(field_name.clone(), create_literal_node(field_name));
+                    }
+                }
+            }
+            _ => body_nodes.push(statement_node),
+        }
+    }
+
+    let record_argument_nodes: Vec<SyntaxTreeNode> = field_nodes
+        .iter()
+        .map(
+            |field_node| match field_values.get(field_node.value.as_ref().unwrap()) {
+                Some(value_node) => value_node.clone(),
+                None => create_literal_node(find_default_value(
+                    field_node
+                        .children
+                        .iter()
+                        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+                        .and_then(|type_node| type_node.value.as_deref())
+                        .unwrap_or_default(),
+                )),
+            },
+        )
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    let is_positional: bool = body_nodes.is_empty()
+        && record_argument_nodes.len() == argument_names.len()
+        && record_argument_nodes
+            .iter()
+            .zip(&argument_names)
+            .all(|(value_node, argument_name)| value_node.value.as_ref() == Some(argument_name));
+
+    if is_positional {
+        return None;
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+        value: Some(class_name.to_string()),
+        type_: SyntaxTreeNodeType::Type,
+        children: vec![],
+    }]);
+    children.extend(argument_nodes.into_iter().cloned());
+    children.extend(group_consecutive_assignments(body_nodes));
+    children.push(create_call_node(
+        &format!("->{}", class_name),
+        record_argument_nodes,
+    ));
+
+    Some(SyntaxTreeNode {
+        value: Some(format!("Make{}", class_name)),
+        type_: SyntaxTreeNodeType::Method,
+        children,
+    })
+}
+
+fn substitute_field_values(
+    syntax_tree_node: SyntaxTreeNode,
+    field_values: &HashMap<String, SyntaxTreeNode>,
+) -> SyntaxTreeNode {
+    match (
+        &syntax_tree_node.type_,
+        &syntax_tree_node.value,
+        syntax_tree_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Literal, Some(field_name), [])
+            if field_values.contains_key(field_name) =>
+        {
+            return field_values[field_name].clone()
+        }
+        (SyntaxTreeNodeType::MemberAccess, Some(field_name), [target_node])
+            if field_values.contains_key(field_name)
+                && target_node.value.as_deref() == Some(INSTANCE_ARGUMENT_NAME) =>
+        {
+            return field_values[field_name].clone()
+        }
+        _ => {}
+    }
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| substitute_field_values(child, field_values))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn collect_local_record_types(
+    syntax_tree_node: &SyntaxTreeNode,
+    class_members: &ClassMembers,
+    local_record_types: &mut HashMap<String, String>,
+) {
+    for child in &syntax_tree_node.children {
+        let record_name: Option<&String> = match (&child.type_, child.children.as_slice()) {
+            (SyntaxTreeNodeType::MethodArgument, [type_node, ..]) => type_node.value.as_ref(),
+            (SyntaxTreeNodeType::Assignment, [type_node, value_node])
+                if matches!(type_node.type_, SyntaxTreeNodeType::Type) =>
+            {
+                match type_node.value.as_deref() {
+                    Some("var") => find_record_type(value_node, class_members, local_record_types),
+                    _ => type_node.value.as_ref(),
+                }
+            }
+            _ => None,
+        };
+
+        if let (Some(name), Some(record_name)) = (&child.value, record_name) {
+            if find_record(record_name, class_members).is_some() {
+                local_record_types.insert(name.clone(), record_name.clone());
+            }
+        }
+
+        collect_local_record_types(child, class_members, local_record_types);
+    }
+}
+
+fn find_record_type<'a>(
+    value_node: &'a SyntaxTreeNode,
+    class_members: &ClassMembers,
+    local_record_types: &'a HashMap<String, String>,
+) -> Option<&'a String> {
+    match (
+        &value_node.type_,
+        &value_node.value,
+        value_node.children.first(),
+    ) {
+        (SyntaxTreeNodeType::Literal, Some(name), None) => local_record_types.get(name),
+        (SyntaxTreeNodeType::Expression, Some(function_name), Some(target_node))
+            if function_name == "assoc" =>
+        {
+            find_record_type(target_node, class_members, local_record_types)
+        }
+        (SyntaxTreeNodeType::ObjectCreation, _, Some(type_node))
+            if matches!(type_node.type_, SyntaxTreeNodeType::Type) =>
+        {
+            type_node
+                .value
+                .as_ref()
+                .filter(|type_name| find_record(type_name, class_members).is_some())
+        }
+        _ => None,
+    }
+}
+
+fn find_record<'a>(class_name: &str, class_members: &'a ClassMembers) -> Option<&'a RecordShape> {
+    class_members
+        .records
+        .iter()
+        .find(|record| record.class_name == class_name)
+}
+
+fn resolve_record_deconstructions(
+    syntax_tree_node: SyntaxTreeNode,
+    class_members: &ClassMembers,
+    local_record_types: &HashMap<String, String>,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    for child in syntax_tree_node.children {
+        match resolve_record_deconstructions(child, class_members, local_record_types) {
+            Ok(valid_node) => children.push(valid_node),
+            Err(error) => return Err(error),
+        }
+    }
+
+    let record: Option<&RecordShape> = match (
+        &syntax_tree_node.type_,
+        &syntax_tree_node.value,
+        children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Assignment, None, [.., target_node, value_node])
+            if matches!(target_node.type_, SyntaxTreeNodeType::Collection) =>
+        {
+            find_record_type(value_node, class_members, local_record_types)
+                .and_then(|record_name| find_record(record_name, class_members))
+        }
+        _ => None,
+    };
+
+    let children: Vec<SyntaxTreeNode> = match record {
+        Some(record) => {
+            let mut children: Vec<SyntaxTreeNode> = children;
+            let target_index: usize = children.len() - 2;
+            let name_nodes: Vec<SyntaxTreeNode> =
+                std::mem::take(&mut children[target_index].children);
+
+            if name_nodes.len() > record.field_names.len() {
+                return Err(format!(
+                    "Unable to deconstruct record {} into {} values: it only has {} fields",
+                    record.class_name,
+                    name_nodes.len(),
+                    record.field_names.len()
+                ));
+            }
+
+            children[target_index] = create_map_node(
+                name_nodes
+                    .into_iter()
+                    .zip(&record.field_names)
+                    .filter(|(name_node, _)| name_node.value.as_deref() != Some("_"))
+                    .map(|(name_node, field_name)| SyntaxTreeNode {
+                        value: None,
+                        type_: SyntaxTreeNodeType::MemberInitializer,
+                        children: Vec::from([
+                            name_node,
+                            SyntaxTreeNode {
+                                value: Some(field_name.clone()),
+                                type_: SyntaxTreeNodeType::MemberAccess,
+                                children: vec![],
+                            },
+                        ]),
+                    })
+                    .collect::<Vec<SyntaxTreeNode>>(),
+            );
+
+            children
+        }
+        None => children,
+    };
+
+    Ok(SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    })
+}
+
+fn create_record_construction(
+    record: &RecordShape,
+    creation_nodes: Vec<SyntaxTreeNode>,
+) -> SyntaxTreeNode {
+    let (argument_nodes, initializer_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+        creation_nodes
+            .into_iter()
+            .skip(1)
+            .partition(|node| !matches!(node.type_, SyntaxTreeNodeType::MemberInitializer));
+
+    if argument_nodes.is_empty() && initializer_nodes.is_empty() && record.field_names.is_empty() {
+        return create_call_node(&format!("->{}", record.class_name), vec![]);
+    }
+
+    if argument_nodes.is_empty() && !record.has_factory {
+        let initialized_names: Vec<Option<String>> = initializer_nodes
+            .iter()
+            .map(|initializer_node| initializer_node.value.clone())
+            .collect::<Vec<Option<String>>>();
+
+        let mut map_children: Vec<SyntaxTreeNode> = record
+            .field_initializers
+            .iter()
+            .filter(|default_node| !initialized_names.contains(&default_node.value))
+            .cloned()
+            .collect::<Vec<SyntaxTreeNode>>();
+        map_children.extend(initializer_nodes);
+
+        return create_call_node(
+            &format!("map->{}", record.class_name),
+            Vec::from([create_map_node(map_children)]),
+        );
+    }
+
+    let construction_node: SyntaxTreeNode = match record.has_factory {
+        true => create_call_node(&format!("Make{}", record.class_name), argument_nodes),
+        false => create_call_node(&format!("->{}", record.class_name), argument_nodes),
+    };
+
+    match initializer_nodes.is_empty() {
+        true => construction_node,
+        false => create_call_node(
+            "merge",
+            Vec::from([construction_node, create_map_node(initializer_nodes)]),
+        ),
+    }
+}
+
+fn create_map_node(member_initializer_nodes: Vec<SyntaxTreeNode>) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::ObjectCreation,
+        children: member_initializer_nodes,
+    }
+}
+
+fn bind_instance_members(
+    syntax_tree_node: SyntaxTreeNode,
+    instance_name: &str,
+    field_names: &[String],
+    method_names: &[String],
+) -> SyntaxTreeNode {
+    match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::MethodArgument, Some(INSTANCE_ARGUMENT_NAME)) => {
+            return SyntaxTreeNode {
+                value: Some(instance_name.to_string()),
+                type_: SyntaxTreeNodeType::MethodArgument,
+                children: syntax_tree_node.children,
+            }
+        }
+        (SyntaxTreeNodeType::Literal, Some(INSTANCE_ARGUMENT_NAME)) => {
+            return create_literal_node(instance_name)
+        }
+        (SyntaxTreeNodeType::Literal, Some(name))
+            if field_names.contains(&name.to_string()) && syntax_tree_node.children.is_empty() =>
+        {
+            return SyntaxTreeNode {
+                value: Some(name.to_string()),
+                type_: SyntaxTreeNodeType::MemberAccess,
+                children: Vec::from([create_literal_node(instance_name)]),
+            }
+        }
+        (SyntaxTreeNodeType::Literal, Some(name))
+            if method_names.contains(&name.to_string()) && syntax_tree_node.children.is_empty() =>
+        {
+            return create_call_node(name, Vec::from([create_literal_node(instance_name)]))
+        }
+        _ => {}
+    }
+
+    let is_instance_method_call: bool =
+        matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Expression)
+            && syntax_tree_node
+                .value
+                .as_ref()
+                .is_some_and(|name| method_names.contains(name));
+
+    let mut children: Vec<SyntaxTreeNode> = match is_instance_method_call {
+        true => Vec::from([create_literal_node(instance_name)]),
+        false => vec![],
+    };
+    children.extend(
+        syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| bind_instance_members(child, instance_name, field_names, method_names)),
+    );
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    }
+}
+
+fn collect_local_names(syntax_tree_node: &SyntaxTreeNode, local_names: &mut Vec<String>) {
+    for child in &syntax_tree_node.children {
+        match (&child.type_, &child.value) {
+            (SyntaxTreeNodeType::MethodArgument, Some(name)) => local_names.push(name.clone()),
+            (SyntaxTreeNodeType::Assignment, Some(name))
+                if child.children.first().is_some_and(|type_node| {
+                    matches!(type_node.type_, SyntaxTreeNodeType::Type)
+                }) =>
+            {
+                local_names.push(name.clone())
+            }
+            _ => {}
+        }
+
+        collect_local_names(child, local_names);
+    }
+}
+
+fn find_instance_field_nodes(class_node: &SyntaxTreeNode) -> Vec<&SyntaxTreeNode> {
+    class_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Field) && !is_static_field(child))
+        .collect::<Vec<&SyntaxTreeNode>>()
+}
+
+fn find_constructor_node(class_node: &SyntaxTreeNode) -> Result<Option<&SyntaxTreeNode>, String> {
+    let constructor_nodes: Vec<&SyntaxTreeNode> = class_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Constructor))
+        .collect::<Vec<&SyntaxTreeNode>>();
+
+    match constructor_nodes.as_slice() {
+        [] => Ok(None),
+        [constructor_node] => Ok(Some(constructor_node)),
+        _ => Err(format!(
+            "Unable to lower class {}: overloaded constructors are not supported",
+            class_node.value.clone().unwrap()
+        )),
+    }
+}
+
+fn find_field_initializer(field_node: &SyntaxTreeNode) -> Option<&SyntaxTreeNode> {
+    field_node.children.iter().find(|child| {
+        !matches!(
+            child.type_,
+            SyntaxTreeNodeType::Type | SyntaxTreeNodeType::Modifier
+        )
+    })
+}
+
+fn is_class_reference(target_node: &SyntaxTreeNode, class_members: &ClassMembers) -> bool {
+    matches!(target_node.type_, SyntaxTreeNodeType::Literal)
+        && target_node
+            .value
+            .as_ref()
+            .is_some_and(|name| class_members.class_names.contains(name))
+}
+
+fn has_modifier(field_node: &SyntaxTreeNode, modifier: &str) -> bool {
+    field_node.children.iter().any(|child| {
+        matches!(child.type_, SyntaxTreeNodeType::Modifier)
+            && child.value.as_deref() == Some(modifier)
+    })
+}
+
+fn is_static_field(field_node: &SyntaxTreeNode) -> bool {
+    field_node.children.iter().any(|child| {
+        matches!(child.type_, SyntaxTreeNodeType::Modifier)
+            && matches!(child.value.as_deref(), Some("static" | "const"))
+    })
+}
+
+fn has_instance_argument(method_node: &SyntaxTreeNode) -> bool {
+    method_node.children.iter().any(|child| {
+        matches!(child.type_, SyntaxTreeNodeType::MethodArgument)
+            && child.value.as_deref() == Some(INSTANCE_ARGUMENT_NAME)
+    })
+}
+
+fn to_instance_name(class_name: &str) -> String {
+    let mut characters: std::str::Chars = class_name.chars();
+
+    match characters.next() {
+        Some(first_character) => {
+            format!("{}{}", first_character.to_lowercase(), characters.as_str())
+        }
+        None => String::new(),
+    }
+}
+
+pub(crate) fn convert_class_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let mut output: String = format!(
+        "(defrecord {} [{}]",
+        syntax_tree_node.value.clone().unwrap(),
+        syntax_tree_node
+            .children
+            .iter()
+            .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Field))
+            .map(|child| child.value.clone().unwrap())
+            .collect::<Vec<String>>()
+            .join(" ")
+    );
+
+    for child in &syntax_tree_node.children {
+        match child.type_ {
+            SyntaxTreeNodeType::Type => {
+                output.push('\n');
+                output.push_str(child.value.as_deref().unwrap());
+            }
+            SyntaxTreeNodeType::Method => {
+                output.push('\n');
+                output.push_str(&convert_protocol_method_to_code(child));
+            }
+            _ => {}
+        }
+    }
+
+    output.push_str(")\n\n");
+
+    output
+}
+
+pub(crate) fn convert_interface_syntax_tree_node_to_code(
+    syntax_tree_node: &SyntaxTreeNode,
+) -> String {
+    let mut output: String = format!("(defprotocol {}", syntax_tree_node.value.clone().unwrap());
+
+    for method_node in &syntax_tree_node.children {
+        output.push_str(&format!(
+            "\n({} [{}])",
+            method_node.value.clone().unwrap(),
+            find_argument_names(method_node).join(" ")
+        ));
+    }
+
+    output.push_str(")\n\n");
+
+    output
+}
+
+fn convert_protocol_method_to_code(method_node: &SyntaxTreeNode) -> String {
+    close_open_parentheses(format!(
+        "({} [{}]\n{}",
+        method_node.value.clone().unwrap(),
+        find_argument_names(method_node).join(" "),
+        method_node
+            .children
+            .iter()
+            .filter(|child| {
+                !matches!(
+                    child.type_,
+                    SyntaxTreeNodeType::MethodArgument | SyntaxTreeNodeType::Type
+                )
+            })
+            .map(convert_abstract_syntax_tree_to_code)
+            .collect::<Vec<String>>()
+            .join("\n")
+    ))
+}
+
+fn find_argument_names(method_node: &SyntaxTreeNode) -> Vec<String> {
+    method_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::MethodArgument))
+        .map(|child| child.value.clone().unwrap())
+        .collect::<Vec<String>>()
+}
+
+pub(crate) fn convert_field_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let field_name: String = syntax_tree_node.value.clone().unwrap();
+    let type_name: &str = syntax_tree_node
+        .children
+        .iter()
+        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        .and_then(|type_node| type_node.value.as_deref())
+        .unwrap_or_default();
+    let is_constant: bool = syntax_tree_node.children.iter().any(|child| {
+        matches!(child.type_, SyntaxTreeNodeType::Modifier)
+            && child.value.as_deref() == Some("const")
+    });
+
+    match find_field_initializer(syntax_tree_node) {
+        Some(value_node) if is_constant && is_constant_literal(value_node) => format!(
+            "(def ^:const {} {})\n\n",
+            field_name,
+            convert_abstract_syntax_tree_to_code(value_node)
+        ),
+        Some(value_node) => format!(
+            "(def {} {})\n\n",
+            field_name,
+            convert_abstract_syntax_tree_to_code(value_node)
+        ),
+        None => format!("(def {} {})\n\n", field_name, find_default_value(type_name)),
+    }
+}
@@ -0,0 +1,987 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::naming::{convert_namespace_name, to_kebab_case};
+use std::collections::{HashMap, HashSet};
+
+const CLOJURE_SPECIAL_FORMS: &[&str] = &[
+    "case",
+    "catch",
+    "def",
+    "do",
+    "finally",
+    "fn",
+    "if",
+    "let",
+    "letfn",
+    "loop",
+    "monitor-enter",
+    "monitor-exit",
+    "new",
+    "quote",
+    "recur",
+    "set!",
+    "throw",
+    "try",
+    "var",
+];
+
+const CLOJURE_CORE_PUBLIC_VARS: &[&str] = &[
+    "*",
+    "*'",
+    "+",
+    "+'",
+    "-",
+    "-'",
+    "->",
+    "->>",
+    "->ArrayChunk",
+    "->Eduction",
+    "->Vec",
+    "->VecNode",
+    "->VecSeq",
+    "<",
+    "<=",
+    "=",
+    "==",
+    ">",
+    ">=",
+    "accessor",
+    "aclone",
+    "add-classpath",
+    "add-tap",
+    "add-watch",
+    "agent",
+    "agent-error",
+    "agent-errors",
+    "aget",
+    "alength",
+    "alias",
+    "all-ns",
+    "alter",
+    "alter-meta!",
+    "alter-var-root",
+    "amap",
+    "ancestors",
+    "and",
+    "any?",
+    "apply",
+    "areduce",
+    "array-map",
+    "as->",
+    "aset",
+    "aset-boolean",
+    "aset-byte",
+    "aset-char",
+    "aset-double",
+    "aset-float",
+    "aset-int",
+    "aset-long",
+    "aset-short",
+    "assert",
+    "assoc",
+    "assoc!",
+    "assoc-in",
+    "associative?",
+    "atom",
+    "await",
+    "await-for",
+    "await1",
+    "bases",
+    "bean",
+    "bigdec",
+    "bigint",
+    "biginteger",
+    "binding",
+    "bit-and",
+    "bit-and-not",
+    "bit-clear",
+    "bit-flip",
+    "bit-not",
+    "bit-or",
+    "bit-set",
+    "bit-shift-left",
+    "bit-shift-right",
+    "bit-test",
+    "bit-xor",
+    "boolean",
+    "boolean-array",
+    "boolean?",
+    "booleans",
+    "bound-fn",
+    "bound-fn*",
+    "bound?",
+    "bounded-count",
+    "butlast",
+    "byte",
+    "byte-array",
+    "bytes",
+    "bytes?",
+    "case",
+    "cast",
+    "cat",
+    "char",
+    "char-array",
+    "char?",
+    "chars",
+    "chunk",
+    "chunk-append",
+    "chunk-buffer",
+    "chunk-cons",
+    "chunk-first",
+    "chunk-next",
+    "chunk-rest",
+    "chunked-seq?",
+    "class",
+    "class?",
+    "clear-agent-errors",
+    "clojure-version",
+    "coll?",
+    "comment",
+    "commute",
+    "comp",
+    "comparator",
+    "compare",
+    "compare-and-set!",
+    "compile",
+    "complement",
+    "completing",
+    "concat",
+    "cond",
+    "cond->",
+    "cond->>",
+    "condp",
+    "conj",
+    "conj!",
+    "cons",
+    "constantly",
+    "construct-proxy",
+    "contains?",
+    "count",
+    "counted?",
+    "create-ns",
+    "create-struct",
+    "cycle",
+    "dec",
+    "dec'",
+    "decimal?",
+    "declare",
+    "dedupe",
+    "default-data-readers",
+    "definline",
+    "definterface",
+    "defmacro",
+    "defmethod",
+    "defmulti",
+    "defn",
+    "defn-",
+    "defonce",
+    "defprotocol",
+    "defrecord",
+    "defstruct",
+    "deftype",
+    "delay",
+    "delay?",
+    "deliver",
+    "denominator",
+    "deref",
+    "derive",
+    "descendants",
+    "disj",
+    "disj!",
+    "dissoc",
+    "dissoc!",
+    "distinct",
+    "distinct?",
+    "doall",
+    "dorun",
+    "doseq",
+    "dosync",
+    "dotimes",
+    "doto",
+    "double",
+    "double-array",
+    "double?",
+    "doubles",
+    "drop",
+    "drop-last",
+    "drop-while",
+    "eduction",
+    "empty",
+    "empty?",
+    "ensure",
+    "ensure-reduced",
+    "enumeration-seq",
+    "error-handler",
+    "error-mode",
+    "eval",
+    "even?",
+    "every-pred",
+    "every?",
+    "ex-cause",
+    "ex-data",
+    "ex-info",
+    "ex-message",
+    "extend",
+    "extend-protocol",
+    "extend-type",
+    "extenders",
+    "extends?",
+    "false?",
+    "ffirst",
+    "file-seq",
+    "filter",
+    "filterv",
+    "find",
+    "find-keyword",
+    "find-ns",
+    "find-var",
+    "first",
+    "flatten",
+    "float",
+    "float-array",
+    "float?",
+    "floats",
+    "flush",
+    "fn",
+    "fn?",
+    "fnext",
+    "fnil",
+    "for",
+    "force",
+    "format",
+    "frequencies",
+    "future",
+    "future-call",
+    "future-cancel",
+    "future-cancelled?",
+    "future-done?",
+    "future?",
+    "gen-class",
+    "gen-interface",
+    "gensym",
+    "get",
+    "get-in",
+    "get-method",
+    "get-proxy-class",
+    "get-thread-bindings",
+    "get-validator",
+    "group-by",
+    "halt-when",
+    "hash",
+    "hash-combine",
+    "hash-map",
+    "hash-ordered-coll",
+    "hash-set",
+    "hash-unordered-coll",
+    "ident?",
+    "identical?",
+    "identity",
+    "if-let",
+    "if-not",
+    "if-some",
+    "ifn?",
+    "import",
+    "in-ns",
+    "inc",
+    "inc'",
+    "indexed?",
+    "inst-ms",
+    "inst?",
+    "instance?",
+    "int",
+    "int-array",
+    "int?",
+    "integer?",
+    "interleave",
+    "intern",
+    "interpose",
+    "into",
+    "into-array",
+    "ints",
+    "io!",
+    "isa?",
+    "iterate",
+    "iteration",
+    "iterator-seq",
+    "juxt",
+    "keep",
+    "keep-indexed",
+    "key",
+    "keys",
+    "keyword",
+    "keyword?",
+    "last",
+    "lazy-cat",
+    "lazy-seq",
+    "let",
+    "letfn",
+    "line-seq",
+    "list",
+    "list*",
+    "list?",
+    "load",
+    "load-file",
+    "load-reader",
+    "load-string",
+    "loaded-libs",
+    "locking",
+    "long",
+    "long-array",
+    "longs",
+    "loop",
+    "macroexpand",
+    "macroexpand-1",
+    "make-array",
+    "make-hierarchy",
+    "map",
+    "map-entry?",
+    "map-indexed",
+    "map?",
+    "mapcat",
+    "mapv",
+    "max",
+    "max-key",
+    "memfn",
+    "memoize",
+    "merge",
+    "merge-with",
+    "meta",
+    "methods",
+    "min",
+    "min-key",
+    "mix-collection-hash",
+    "mod",
+    "munge",
+    "name",
+    "namespace",
+    "namespace-munge",
+    "nat-int?",
+    "neg-int?",
+    "neg?",
+    "newline",
+    "next",
+    "nfirst",
+    "nil?",
+    "nnext",
+    "not",
+    "not-any?",
+    "not-empty",
+    "not-every?",
+    "not=",
+    "ns",
+    "ns-aliases",
+    "ns-imports",
+    "ns-interns",
+    "ns-map",
+    "ns-name",
+    "ns-publics",
+    "ns-refers",
+    "ns-resolve",
+    "ns-unalias",
+    "ns-unmap",
+    "nth",
+    "nthnext",
+    "nthrest",
+    "num",
+    "number?",
+    "numerator",
+    "object-array",
+    "odd?",
+    "or",
+    "parents",
+    "parse-boolean",
+    "parse-double",
+    "parse-long",
+    "parse-uuid",
+    "partial",
+    "partition",
+    "partition-all",
+    "partition-by",
+    "pcalls",
+    "peek",
+    "persistent!",
+    "pmap",
+    "pop",
+    "pop!",
+    "pop-thread-bindings",
+    "pos-int?",
+    "pos?",
+    "pr",
+    "pr-str",
+    "prefer-method",
+    "prefers",
+    "print",
+    "print-str",
+    "printf",
+    "println",
+    "println-str",
+    "prn",
+    "prn-str",
+    "promise",
+    "proxy",
+    "proxy-mappings",
+    "proxy-super",
+    "push-thread-bindings",
+    "pvalues",
+    "qualified-ident?",
+    "qualified-keyword?",
+    "qualified-symbol?",
+    "quot",
+    "rand",
+    "rand-int",
+    "rand-nth",
+    "random-sample",
+    "random-uuid",
+    "range",
+    "ratio?",
+    "rational?",
+    "rationalize",
+    "re-find",
+    "re-groups",
+    "re-matcher",
+    "re-matches",
+    "re-pattern",
+    "re-seq",
+    "read",
+    "read-line",
+    "read-string",
+    "reader-conditional",
+    "reader-conditional?",
+    "realized?",
+    "record?",
+    "reduce",
+    "reduce-kv",
+    "reduced",
+    "reduced?",
+    "reductions",
+    "ref",
+    "ref-history-count",
+    "ref-max-history",
+    "ref-min-history",
+    "ref-set",
+    "refer",
+    "refer-clojure",
+    "reify",
+    "release-pending-sends",
+    "rem",
+    "remove",
+    "remove-all-methods",
+    "remove-method",
+    "remove-ns",
+    "remove-tap",
+    "remove-watch",
+    "repeat",
+    "repeatedly",
+    "replace",
+    "replicate",
+    "require",
+    "requiring-resolve",
+    "reset!",
+    "reset-meta!",
+    "reset-vals!",
+    "resolve",
+    "rest",
+    "restart-agent",
+    "resultset-seq",
+    "reverse",
+    "reversible?",
+    "rseq",
+    "rsubseq",
+    "run!",
+    "satisfies?",
+    "second",
+    "select-keys",
+    "send",
+    "send-off",
+    "send-via",
+    "seq",
+    "seq?",
+    "seqable?",
+    "seque",
+    "sequence",
+    "sequential?",
+    "set",
+    "set-agent-send-executor!",
+    "set-agent-send-off-executor!",
+    "set-error-handler!",
+    "set-error-mode!",
+    "set-validator!",
+    "set?",
+    "short",
+    "short-array",
+    "shorts",
+    "shuffle",
+    "shutdown-agents",
+    "simple-ident?",
+    "simple-keyword?",
+    "simple-symbol?",
+    "slurp",
+    "some",
+    "some->",
+    "some->>",
+    "some-fn",
+    "some?",
+    "sort",
+    "sort-by",
+    "sorted-map",
+    "sorted-map-by",
+    "sorted-set",
+    "sorted-set-by",
+    "sorted?",
+    "special-symbol?",
+    "spit",
+    "split-at",
+    "split-with",
+    "str",
+    "string?",
+    "struct",
+    "struct-map",
+    "subs",
+    "subseq",
+    "subvec",
+    "supers",
+    "swap!",
+    "swap-vals!",
+    "symbol",
+    "symbol?",
+    "sync",
+    "tagged-literal",
+    "tagged-literal?",
+    "take",
+    "take-last",
+    "take-nth",
+    "take-while",
+    "tap>",
+    "test",
+    "the-ns",
+    "thread-bound?",
+    "time",
+    "to-array",
+    "to-array-2d",
+    "trampoline",
+    "transduce",
+    "transient",
+    "tree-seq",
+    "true?",
+    "type",
+    "unchecked-add",
+    "unchecked-add-int",
+    "unchecked-byte",
+    "unchecked-char",
+    "unchecked-dec",
+    "unchecked-dec-int",
+    "unchecked-divide-int",
+    "unchecked-double",
+    "unchecked-float",
+    "unchecked-inc",
+    "unchecked-inc-int",
+    "unchecked-int",
+    "unchecked-long",
+    "unchecked-multiply",
+    "unchecked-multiply-int",
+    "unchecked-negate",
+    "unchecked-negate-int",
+    "unchecked-remainder-int",
+    "unchecked-short",
+    "unchecked-subtract",
+    "unchecked-subtract-int",
+    "underive",
+    "unreduced",
+    "unsigned-bit-shift-right",
+    "update",
+    "update-in",
+    "update-keys",
+    "update-proxy",
+    "update-vals",
+    "uri?",
+    "use",
+    "uuid?",
+    "val",
+    "vals",
+    "var-get",
+    "var-set",
+    "var?",
+    "vary-meta",
+    "vec",
+    "vector",
+    "vector-of",
+    "vector?",
+    "volatile!",
+    "volatile?",
+    "vreset!",
+    "vswap!",
+    "when",
+    "when-first",
+    "when-let",
+    "when-not",
+    "when-some",
+    "while",
+    "with-bindings",
+    "with-bindings*",
+    "with-in-str",
+    "with-loading-context",
+    "with-local-vars",
+    "with-meta",
+    "with-open",
+    "with-out-str",
+    "with-precision",
+    "with-redefs",
+    "with-redefs-fn",
+    "xml-seq",
+    "zero?",
+    "zipmap",
+];
+
+pub fn resolve_core_collisions(
+    abstract_syntax_tree: SyntaxTreeNode,
+) -> (SyntaxTreeNode, Vec<String>) {
+    let mut warnings: Vec<String> = vec![];
+
+    let children: Vec<SyntaxTreeNode> = abstract_syntax_tree
+        .children
+        .into_iter()
+        .map(|child| match child.type_ {
+            SyntaxTreeNodeType::Namespace => resolve_namespace_collisions(child, &mut warnings),
+            _ => child,
+        })
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    (
+        SyntaxTreeNode {
+            value: abstract_syntax_tree.value,
+            type_: abstract_syntax_tree.type_,
+            children,
+        },
+        warnings,
+    )
+}
+
+/// Warns about calls to methods that are not declared in their namespace, such as external
+/// helpers, whose converted name resolves to a clojure.core var or special form. This runs on the
+/// parsed tree so that core calls emitted by later passes are not mistaken for user calls.
+pub fn flag_undeclared_core_calls(
+    abstract_syntax_tree: &SyntaxTreeNode,
+    naming_overrides: &HashMap<String, String>,
+) -> Vec<String> {
+    let mut warnings: Vec<String> = vec![];
+
+    for namespace_node in abstract_syntax_tree
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Namespace))
+    {
+        let mut declared_method_names: HashSet<String> = HashSet::new();
+        collect_declared_method_names(namespace_node, &mut declared_method_names);
+
+        let namespace_name: String = convert_namespace_name(
+            namespace_node.value.as_deref().unwrap_or_default(),
+            naming_overrides,
+        );
+
+        let mut flagged_names: HashSet<String> = HashSet::new();
+        flag_core_calls(
+            namespace_node,
+            &namespace_name,
+            &declared_method_names,
+            naming_overrides,
+            &mut flagged_names,
+            &mut warnings,
+        );
+    }
+
+    warnings
+}
+
+fn collect_declared_method_names(
+    syntax_tree_node: &SyntaxTreeNode,
+    declared_method_names: &mut HashSet<String>,
+) {
+    for child in &syntax_tree_node.children {
+        if let (SyntaxTreeNodeType::Method, Some(name)) = (&child.type_, &child.value) {
+            declared_method_names.insert(name.clone());
+        }
+
+        collect_declared_method_names(child, declared_method_names);
+    }
+}
+
+fn flag_core_calls(
+    syntax_tree_node: &SyntaxTreeNode,
+    namespace_name: &str,
+    declared_method_names: &HashSet<String>,
+    naming_overrides: &HashMap<String, String>,
+    flagged_names: &mut HashSet<String>,
+    warnings: &mut Vec<String>,
+) {
+    if let (SyntaxTreeNodeType::Expression, Some(name)) =
+        (&syntax_tree_node.type_, &syntax_tree_node.value)
+    {
+        if name.starts_with(char::is_uppercase)
+            && !declared_method_names.contains(name)
+            && !flagged_names.contains(name)
+        {
+            if let Some(collision) = describe_core_collision(name, naming_overrides) {
+                warnings.push(format!(
+                    "call to undeclared method `{}` in namespace {} {}",
+                    name, namespace_name, collision
+                ));
+                flagged_names.insert(name.clone());
+            }
+        }
+    }
+
+    for child in &syntax_tree_node.children {
+        flag_core_calls(
+            child,
+            namespace_name,
+            declared_method_names,
+            naming_overrides,
+            flagged_names,
+            warnings,
+        );
+    }
+}
+
+/// Describes what a call to the undeclared method `name` turns into when its converted name is a
+/// clojure.core var or special form, e.g. "resolves to clojure.core/find".
+pub(crate) fn describe_core_collision(
+    name: &str,
+    naming_overrides: &HashMap<String, String>,
+) -> Option<String> {
+    let converted_name: String = match naming_overrides.get(name) {
+        Some(overridden_name) if !overridden_name.is_empty() => overridden_name.clone(),
+        Some(_) => name.to_string(),
+        None => to_kebab_case(name),
+    };
+
+    if is_special_form(&converted_name) {
+        Some(format!("becomes the special form `{}`", converted_name))
+    } else if is_clojure_core_var(&converted_name) {
+        Some(format!("resolves to clojure.core/{}", converted_name))
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_special_form(name: &str) -> bool {
+    CLOJURE_SPECIAL_FORMS.contains(&name)
+}
+
+pub(crate) fn is_clojure_core_var(name: &str) -> bool {
+    CLOJURE_CORE_PUBLIC_VARS.contains(&name)
+}
+
+fn mangle_name(name: &str) -> String {
+    format!("{}'", name)
+}
+
+fn resolve_namespace_collisions(
+    namespace_node: SyntaxTreeNode,
+    warnings: &mut Vec<String>,
+) -> SyntaxTreeNode {
+    let namespace_name: String = namespace_node.value.clone().unwrap();
+
+    let mut excluded_names: Vec<String> = vec![];
+    let mut mangled_method_names: HashSet<String> = HashSet::new();
+
+    for (member_kind, method_name) in namespace_node
+        .children
+        .iter()
+        .flat_map(|child| match child.type_ {
+            SyntaxTreeNodeType::Interface => child.children.iter().collect(),
+            _ => Vec::from([child]),
+        })
+        .filter_map(|child| match (&child.type_, &child.value) {
+            (SyntaxTreeNodeType::Method, Some(name)) => Some(("method", name.clone())),
+            (SyntaxTreeNodeType::Field, Some(name)) => Some(("field", name.clone())),
+            _ => None,
+        })
+    {
+        if is_special_form(&method_name) {
+            warnings.push(format!(
+                "{} `{}` in namespace {} collides with the special form `{}` and was renamed to `{}`",
+                member_kind,
+                method_name,
+                namespace_name,
+                method_name,
+                mangle_name(&method_name)
+            ));
+            mangled_method_names.insert(method_name);
+        } else if is_clojure_core_var(&method_name) && !excluded_names.contains(&method_name) {
+            warnings.push(format!(
+                "{} `{}` in namespace {} shadows clojure.core/{} and was added to :refer-clojure :exclude",
+                member_kind, method_name, namespace_name, method_name
+            ));
+            excluded_names.push(method_name);
+        }
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+
+    if !excluded_names.is_empty() {
+        children.push(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::ReferClojureExclude,
+            children: excluded_names
+                .into_iter()
+                .map(|name| SyntaxTreeNode {
+                    value: Some(name),
+                    type_: SyntaxTreeNodeType::Literal,
+                    children: vec![],
+                })
+                .collect::<Vec<SyntaxTreeNode>>(),
+        });
+    }
+
+    for child in namespace_node.children {
+        let child: SyntaxTreeNode = rename_calls(child, &mangled_method_names);
+
+        children.push(match child.type_ {
+            SyntaxTreeNodeType::Method => resolve_method_collisions(child, warnings),
+            SyntaxTreeNodeType::Class => SyntaxTreeNode {
+                value: child.value,
+                type_: child.type_,
+                children: child
+                    .children
+                    .into_iter()
+                    .map(|member_node| match member_node.type_ {
+                        SyntaxTreeNodeType::Method => {
+                            resolve_method_collisions(member_node, warnings)
+                        }
+                        _ => member_node,
+                    })
+                    .collect::<Vec<SyntaxTreeNode>>(),
+            },
+            _ => child,
+        });
+    }
+
+    SyntaxTreeNode {
+        value: namespace_node.value,
+        type_: namespace_node.type_,
+        children,
+    }
+}
+
+fn rename_calls(
+    syntax_tree_node: SyntaxTreeNode,
+    mangled_method_names: &HashSet<String>,
+) -> SyntaxTreeNode {
+    let value: Option<String> = match (&syntax_tree_node.type_, syntax_tree_node.value) {
+        (SyntaxTreeNodeType::Method | SyntaxTreeNodeType::Expression, Some(name))
+            if mangled_method_names.contains(&name) =>
+        {
+            Some(mangle_name(&name))
+        }
+        (_, value) => value,
+    };
+
+    SyntaxTreeNode {
+        value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| rename_calls(child, mangled_method_names))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn resolve_method_collisions(
+    method_node: SyntaxTreeNode,
+    warnings: &mut Vec<String>,
+) -> SyntaxTreeNode {
+    let mut local_names: Vec<String> = vec![];
+    collect_local_names(&method_node, &mut local_names);
+
+    let mut colliding_local_names: HashSet<String> = HashSet::new();
+    for local_name in local_names {
+        if !is_special_form(&local_name) && !is_clojure_core_var(&local_name) {
+            continue;
+        }
+
+        if colliding_local_names.insert(local_name.clone()) {
+            warnings.push(format!(
+                "local `{}` in method {} collides with clojure.core and was renamed to `{}`",
+                local_name,
+                method_node.value.clone().unwrap(),
+                mangle_name(&local_name)
+            ));
+        }
+    }
+
+    rename_locals(method_node, &colliding_local_names)
+}
+
+fn collect_local_names(syntax_tree_node: &SyntaxTreeNode, local_names: &mut Vec<String>) {
+    for child in &syntax_tree_node.children {
+        if let (
+            SyntaxTreeNodeType::MethodArgument
+            | SyntaxTreeNodeType::Assignment
+            | SyntaxTreeNodeType::Catch,
+            Some(name),
+        ) = (&child.type_, &child.value)
+        {
+            local_names.push(name.clone());
+        }
+
+        if matches!(child.type_, SyntaxTreeNodeType::Loop)
+            && child.value.as_deref() == Some("foreach")
+        {
+            collect_iteration_variable_names(&child.children[0], local_names);
+        }
+
+        if matches!(child.type_, SyntaxTreeNodeType::Pattern)
+            && !matches!(
+                child.value.as_deref(),
+                Some("constant" | "<" | "<=" | ">" | ">=")
+            )
+        {
+            local_names.extend(
+                child
+                    .children
+                    .iter()
+                    .filter(|capture| matches!(capture.type_, SyntaxTreeNodeType::Literal))
+                    .filter_map(|capture| capture.value.clone()),
+            );
+        }
+
+        collect_local_names(child, local_names);
+    }
+}
+
+fn collect_iteration_variable_names(variable_node: &SyntaxTreeNode, local_names: &mut Vec<String>) {
+    match (&variable_node.type_, &variable_node.value) {
+        (SyntaxTreeNodeType::Literal, Some(name)) => local_names.push(name.clone()),
+        _ => {
+            for child in &variable_node.children {
+                collect_iteration_variable_names(child, local_names);
+            }
+        }
+    }
+}
+
+fn rename_locals(
+    syntax_tree_node: SyntaxTreeNode,
+    colliding_local_names: &HashSet<String>,
+) -> SyntaxTreeNode {
+    let value: Option<String> = match (&syntax_tree_node.type_, syntax_tree_node.value) {
+        (
+            SyntaxTreeNodeType::MethodArgument
+            | SyntaxTreeNodeType::Assignment
+            | SyntaxTreeNodeType::Catch
+            | SyntaxTreeNodeType::Literal,
+            Some(name),
+        ) if colliding_local_names.contains(&name) => Some(mangle_name(&name)),
+        (_, value) => value,
+    };
+
+    SyntaxTreeNode {
+        value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| rename_locals(child, colliding_local_names))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
@@ -0,0 +1,382 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::mutation::create_call_node;
+use crate::transpiler::naming::to_kebab_case;
+use crate::transpiler::transpiler::{
+    convert_abstract_syntax_tree_to_code, convert_body_to_code, convert_body_to_forms_code,
+};
+use std::collections::HashMap;
+
+const EXCEPTION_INFO_CLASS: &str = "clojure.lang.ExceptionInfo";
+const EXCEPTION_INFO_NAME: &str = "exception-info";
+
+const HOST_EXCEPTIONS: [(&str, &str); 12] = [
+    ("Exception", "Exception"),
+    ("SystemException", "RuntimeException"),
+    ("IOException", "java.io.IOException"),
+    ("FileNotFoundException", "java.io.FileNotFoundException"),
+    ("InvalidOperationException", "IllegalStateException"),
+    ("NotSupportedException", "UnsupportedOperationException"),
+    ("NotImplementedException", "UnsupportedOperationException"),
+    ("NullReferenceException", "NullPointerException"),
+    ("IndexOutOfRangeException", "IndexOutOfBoundsException"),
+    ("DivideByZeroException", "ArithmeticException"),
+    ("FormatException", "NumberFormatException"),
+    ("TimeoutException", "java.util.concurrent.TimeoutException"),
+];
+
+struct CatchClause<'a> {
+    type_name: Option<&'a str>,
+    exception_name: Option<&'a str>,
+    filter_node: Option<&'a SyntaxTreeNode>,
+    body_nodes: &'a [SyntaxTreeNode],
+}
+
+pub fn resolve_exception_types(
+    syntax_tree_node: SyntaxTreeNode,
+    exception_mappings: &HashMap<String, String>,
+) -> SyntaxTreeNode {
+    let resolves_first_type: bool = match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Catch => true,
+        SyntaxTreeNodeType::ObjectCreation => syntax_tree_node
+            .children
+            .first()
+            .and_then(|type_node| type_node.value.as_deref())
+            .is_some_and(|type_name| {
+                type_name.ends_with("Exception") || exception_mappings.contains_key(type_name)
+            }),
+        _ => false,
+    };
+
+    let children: Vec<SyntaxTreeNode> = syntax_tree_node
+        .children
+        .into_iter()
+        .enumerate()
+        .map(|(index, child)| match (&child.type_, &child.value) {
+            (SyntaxTreeNodeType::Type, Some(type_name)) if index == 0 && resolves_first_type => {
+                SyntaxTreeNode {
+                    value: Some(resolve_exception_type(type_name, exception_mappings)),
+                    type_: SyntaxTreeNodeType::Type,
+                    children: vec![],
+                }
+            }
+            _ => resolve_exception_types(child, exception_mappings),
+        })
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    let children: Vec<SyntaxTreeNode> = match (&syntax_tree_node.type_, &syntax_tree_node.value) {
+        (SyntaxTreeNodeType::Catch, Some(exception_name)) => {
+            let is_exception_info: bool = children.first().is_some_and(|type_node| {
+                matches!(type_node.type_, SyntaxTreeNodeType::Type)
+                    && type_node
+                        .value
+                        .as_deref()
+                        .is_some_and(|type_name| type_name.starts_with(':'))
+            });
+
+            children
+                .into_iter()
+                .map(|child| resolve_exception_properties(child, exception_name, is_exception_info))
+                .collect::<Vec<SyntaxTreeNode>>()
+        }
+        _ => children,
+    };
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    }
+}
+
+fn resolve_exception_properties(
+    syntax_tree_node: SyntaxTreeNode,
+    exception_name: &str,
+    is_exception_info: bool,
+) -> SyntaxTreeNode {
+    let syntax_tree_node: SyntaxTreeNode = SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| resolve_exception_properties(child, exception_name, is_exception_info))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    };
+
+    let property_name: String = match (
+        &syntax_tree_node.type_,
+        &syntax_tree_node.value,
+        syntax_tree_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::MemberAccess, Some(property_name), [target_node])
+            if matches!(target_node.type_, SyntaxTreeNodeType::Literal)
+                && target_node.value.as_deref() == Some(exception_name) =>
+        {
+            property_name.clone()
+        }
+        _ => return syntax_tree_node,
+    };
+
+    match (property_name.as_str(), is_exception_info) {
+        ("message", _) => create_call_node("ex-message", syntax_tree_node.children),
+        (_, true) => SyntaxTreeNode {
+            value: Some(property_name),
+            type_: SyntaxTreeNodeType::MemberAccess,
+            children: Vec::from([create_call_node("ex-data", syntax_tree_node.children)]),
+        },
+        (_, false) => create_call_node(
+            &format!(
+                ".get{}",
+                property_name
+                    .split('-')
+                    .map(capitalize)
+                    .collect::<Vec<String>>()
+                    .join("")
+            ),
+            syntax_tree_node.children,
+        ),
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut characters: std::str::Chars = word.chars();
+
+    match characters.next() {
+        Some(first_character) => first_character.to_uppercase().chain(characters).collect(),
+        None => String::new(),
+    }
+}
+
+fn resolve_exception_type(type_name: &str, exception_mappings: &HashMap<String, String>) -> String {
+    match exception_mappings.get(type_name).map(String::as_str) {
+        Some("ex-info") => create_exception_keyword(type_name),
+        Some(mapped_type_name) => mapped_type_name.to_string(),
+        None => match HOST_EXCEPTIONS
+            .iter()
+            .find(|(exception_name, _)| *exception_name == type_name)
+        {
+            Some((_, host_type_name)) => host_type_name.to_string(),
+            None => create_exception_keyword(type_name),
+        },
+    }
+}
+
+fn create_exception_keyword(type_name: &str) -> String {
+    format!(
+        ":{}",
+        to_kebab_case(type_name.strip_suffix("Exception").unwrap_or(type_name))
+    )
+}
+
+pub(crate) fn convert_try_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let mut clause_codes: Vec<String> = Vec::from([convert_body_to_forms_code(
+        &syntax_tree_node.children[0].children,
+    )]);
+
+    let catch_clauses: Vec<CatchClause<'_>> = syntax_tree_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Catch))
+        .map(split_catch_clause)
+        .collect::<Vec<CatchClause<'_>>>();
+
+    let mut has_exception_info_catch: bool = false;
+    for (index, catch_clause) in catch_clauses.iter().enumerate() {
+        match (
+            is_exception_info_catch(catch_clause),
+            has_exception_info_catch,
+        ) {
+            (true, false) => {
+                clause_codes.push(convert_exception_info_catches_to_code(
+                    &catch_clauses[index..],
+                ));
+                has_exception_info_catch = true;
+            }
+            (true, true) => {}
+            (false, _) => clause_codes.push(convert_host_catch_to_code(catch_clause)),
+        }
+    }
+
+    if let Some(finally_node) = syntax_tree_node.children.iter().find(|child| {
+        matches!(child.type_, SyntaxTreeNodeType::Block)
+            && child.value.as_deref() == Some("finally")
+    }) {
+        clause_codes.push(format!(
+            "(finally\n{})",
+            convert_body_to_forms_code(&finally_node.children)
+        ));
+    }
+
+    format!("(try\n{})", clause_codes.join("\n"))
+}
+
+fn split_catch_clause(catch_node: &SyntaxTreeNode) -> CatchClause<'_> {
+    let mut body_start_index: usize = 0;
+
+    let type_name: Option<&str> = match catch_node.children.first() {
+        Some(type_node) if matches!(type_node.type_, SyntaxTreeNodeType::Type) => {
+            body_start_index += 1;
+            type_node.value.as_deref()
+        }
+        _ => None,
+    };
+
+    let filter_node: Option<&SyntaxTreeNode> = match catch_node.children.get(body_start_index) {
+        Some(filter_block_node)
+            if matches!(filter_block_node.type_, SyntaxTreeNodeType::Block)
+                && filter_block_node.value.as_deref() == Some("when") =>
+        {
+            body_start_index += 1;
+            Some(&filter_block_node.children[0])
+        }
+        _ => None,
+    };
+
+    CatchClause {
+        type_name,
+        exception_name: catch_node.value.as_deref(),
+        filter_node,
+        body_nodes: &catch_node.children[body_start_index..],
+    }
+}
+
+fn is_exception_info_catch(catch_clause: &CatchClause) -> bool {
+    catch_clause
+        .type_name
+        .is_some_and(|type_name| type_name.starts_with(':'))
+}
+
+fn is_catch_all(catch_clause: &CatchClause) -> bool {
+    catch_clause.filter_node.is_none()
+        && matches!(
+            catch_clause.type_name,
+            None | Some("Exception") | Some("Throwable")
+        )
+}
+
+fn convert_host_catch_to_code(catch_clause: &CatchClause) -> String {
+    let type_name: &str = catch_clause.type_name.unwrap_or("Throwable");
+
+    match catch_clause.filter_node {
+        Some(filter_node) => {
+            let exception_name: &str = catch_clause.exception_name.unwrap_or("ex");
+
+            format!(
+                "(catch {} {}\n(if {}\n{}\n(throw {})))",
+                type_name,
+                exception_name,
+                convert_abstract_syntax_tree_to_code(filter_node),
+                convert_body_to_code(catch_clause.body_nodes),
+                exception_name
+            )
+        }
+        None => format!(
+            "(catch {} {}\n{})",
+            type_name,
+            catch_clause.exception_name.unwrap_or("_"),
+            convert_body_to_forms_code(catch_clause.body_nodes)
+        ),
+    }
+}
+
+fn convert_exception_info_catches_to_code(catch_clauses: &[CatchClause]) -> String {
+    let mut clauses_code: Vec<(String, String)> = vec![];
+
+    for catch_clause in catch_clauses
+        .iter()
+        .filter(|catch_clause| is_exception_info_catch(catch_clause))
+    {
+        let type_check_code: String = format!(
+            "(= (:type (ex-data {})) {})",
+            EXCEPTION_INFO_NAME,
+            catch_clause.type_name.unwrap()
+        );
+
+        clauses_code.push((
+            match catch_clause.filter_node {
+                Some(filter_node) => format!(
+                    "(and {} {})",
+                    type_check_code,
+                    bind_caught_exception(
+                        catch_clause,
+                        convert_abstract_syntax_tree_to_code(filter_node)
+                    )
+                ),
+                None => type_check_code,
+            },
+            bind_caught_exception(catch_clause, convert_body_to_code(catch_clause.body_nodes)),
+        ));
+    }
+
+    let fallback_code: String = match catch_clauses
+        .iter()
+        .find(|catch_clause| is_catch_all(catch_clause))
+    {
+        Some(catch_all_clause) => bind_caught_exception(
+            catch_all_clause,
+            convert_body_to_code(catch_all_clause.body_nodes),
+        ),
+        None => format!("(throw {})", EXCEPTION_INFO_NAME),
+    };
+
+    let dispatch_code: String = match clauses_code.as_slice() {
+        [(condition_code, body_code)] => {
+            format!("(if {}\n{}\n{})", condition_code, body_code, fallback_code)
+        }
+        _ => format!(
+            "(cond\n{}\n:else {})",
+            clauses_code
+                .iter()
+                .map(|(condition_code, body_code)| format!("{} {}", condition_code, body_code))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            fallback_code
+        ),
+    };
+
+    format!(
+        "(catch {} {}\n{})",
+        EXCEPTION_INFO_CLASS, EXCEPTION_INFO_NAME, dispatch_code
+    )
+}
+
+fn bind_caught_exception(catch_clause: &CatchClause, code: String) -> String {
+    match catch_clause.exception_name {
+        Some(exception_name) => format!(
+            "(let [{} {}]\n{})",
+            exception_name, EXCEPTION_INFO_NAME, code
+        ),
+        None => code,
+    }
+}
+
+pub(crate) fn convert_throw_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    match syntax_tree_node.children.first() {
+        Some(exception_node) => format!(
+            "(throw {})",
+            convert_abstract_syntax_tree_to_code(exception_node)
+        ),
+        None => unreachable!("the parser binds `throw;` to the caught exception or rejects it"),
+    }
+}
+
+pub(crate) fn convert_exception_info_creation_to_code(
+    exception_keyword: &str,
+    argument_codes: &[String],
+) -> String {
+    let message_code: String = match argument_codes.first() {
+        Some(message_code) => message_code.clone(),
+        None => format!("\"{}\"", &exception_keyword[1..]),
+    };
+
+    let data_code: String = match argument_codes.get(1..) {
+        Some(extra_argument_codes) if !extra_argument_codes.is_empty() => format!(
+            "{{:type {} :args [{}]}}",
+            exception_keyword,
+            extra_argument_codes.join(" ")
+        ),
+        _ => format!("{{:type {}}}", exception_keyword),
+    };
+
+    format!("(ex-info {} {})", message_code, data_code)
+}
@@ -0,0 +1,261 @@
+use crate::syntax_tree::syntax_tree_builder::split_branch_bodies;
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::patterns;
+use crate::transpiler::patterns::PatternCode;
+use crate::transpiler::transpiler::{
+    convert_abstract_syntax_tree_to_code, convert_body_to_code, convert_body_to_forms_code,
+    find_assigned_value,
+};
+
+pub fn apply_binding_idioms(syntax_tree_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let children: Vec<SyntaxTreeNode> = syntax_tree_node
+        .children
+        .into_iter()
+        .map(apply_binding_idioms)
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: merge_null_checked_bindings(children),
+    }
+}
+
+fn merge_null_checked_bindings(body_nodes: Vec<SyntaxTreeNode>) -> Vec<SyntaxTreeNode> {
+    let mut merged_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    let mut index: usize = 0;
+    while index < body_nodes.len() {
+        if index + 1 < body_nodes.len() {
+            if let Some(binding_branch_node) = create_binding_branch(
+                &body_nodes[index],
+                &body_nodes[index + 1],
+                &body_nodes[index + 2..],
+            ) {
+                merged_nodes.push(binding_branch_node);
+                index += 2;
+                continue;
+            }
+        }
+
+        merged_nodes.push(body_nodes[index].clone());
+        index += 1;
+    }
+
+    merged_nodes
+}
+
+fn create_binding_branch(
+    assignment_node: &SyntaxTreeNode,
+    branch_node: &SyntaxTreeNode,
+    remaining_nodes: &[SyntaxTreeNode],
+) -> Option<SyntaxTreeNode> {
+    if !matches!(assignment_node.type_, SyntaxTreeNodeType::Assignment)
+        || !assignment_node
+            .children
+            .iter()
+            .any(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        || !matches!(branch_node.type_, SyntaxTreeNodeType::Branch)
+        || branch_node.value.as_deref() != Some("if")
+    {
+        return None;
+    }
+
+    let bound_name: &str = assignment_node.value.as_deref()?;
+    let checks_presence: bool = find_null_check_polarity(&branch_node.children[0], bound_name)?;
+
+    let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+        split_branch_bodies(branch_node);
+    let (present_nodes, absent_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+        match checks_presence {
+            true => (then_nodes, else_nodes),
+            false => (else_nodes, then_nodes),
+        };
+
+    if present_nodes.is_empty()
+        || absent_nodes
+            .iter()
+            .any(|node| references_name(node, bound_name))
+        || remaining_nodes
+            .iter()
+            .any(|node| references_name(node, bound_name))
+    {
+        return None;
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([assignment_node.clone()]);
+    children.extend_from_slice(present_nodes);
+    if !absent_nodes.is_empty() {
+        children.push(SyntaxTreeNode {
+            value: Some(String::from("else")),
+            type_: SyntaxTreeNodeType::Branch,
+            children: absent_nodes.to_vec(),
+        });
+    }
+
+    Some(SyntaxTreeNode {
+        value: Some(String::from("if")),
+        type_: SyntaxTreeNodeType::Branch,
+        children,
+    })
+}
+
+fn find_null_check_polarity(condition_node: &SyntaxTreeNode, name: &str) -> Option<bool> {
+    if !matches!(condition_node.type_, SyntaxTreeNodeType::EqualityCheck) {
+        return None;
+    }
+
+    let checked_node: &SyntaxTreeNode = find_null_checked_operand(condition_node)?;
+    if !matches!(checked_node.type_, SyntaxTreeNodeType::Literal)
+        || checked_node.value.as_deref() != Some(name)
+    {
+        return None;
+    }
+
+    Some(condition_node.value.as_deref() == Some("not="))
+}
+
+fn find_null_checked_operand(condition_node: &SyntaxTreeNode) -> Option<&SyntaxTreeNode> {
+    match condition_node.children.as_slice() {
+        [left_node, right_node] if is_null_literal(right_node) => Some(left_node),
+        [left_node, right_node] if is_null_literal(left_node) => Some(right_node),
+        _ => None,
+    }
+}
+
+fn is_null_literal(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Literal)
+        && syntax_tree_node.value.as_deref() == Some("null")
+}
+
+fn references_name(syntax_tree_node: &SyntaxTreeNode, name: &str) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Comment | SyntaxTreeNodeType::Type => false,
+        _ => {
+            syntax_tree_node.value.as_deref() == Some(name)
+                || syntax_tree_node
+                    .children
+                    .iter()
+                    .any(|child| references_name(child, name))
+        }
+    }
+}
+
+pub(crate) fn convert_null_check_to_code(syntax_tree_node: &SyntaxTreeNode) -> Option<String> {
+    let checked_node: &SyntaxTreeNode = find_null_checked_operand(syntax_tree_node)?;
+
+    Some(format!(
+        "({} {})",
+        match syntax_tree_node.value.as_deref() {
+            Some("not=") => "some?",
+            _ => "nil?",
+        },
+        convert_abstract_syntax_tree_to_code(checked_node)
+    ))
+}
+
+pub(crate) fn convert_idiomatic_branch_to_code(
+    syntax_tree_node: &SyntaxTreeNode,
+) -> Option<String> {
+    let condition_node: &SyntaxTreeNode = &syntax_tree_node.children[0];
+    let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+        split_branch_bodies(syntax_tree_node);
+
+    if matches!(condition_node.type_, SyntaxTreeNodeType::Assignment) {
+        let binding_code: String = format!(
+            "[{} {}]",
+            condition_node.value.clone().unwrap(),
+            convert_abstract_syntax_tree_to_code(find_assigned_value(condition_node))
+        );
+
+        return Some(match else_nodes {
+            [] => format!(
+                "(when-let {}\n{})",
+                binding_code,
+                convert_body_to_forms_code(then_nodes)
+            ),
+            _ => format!(
+                "(if-let {}\n{}\n{})",
+                binding_code,
+                convert_body_to_code(then_nodes),
+                convert_body_to_code(else_nodes)
+            ),
+        });
+    }
+
+    let negated_condition_node: Option<&SyntaxTreeNode> =
+        match (&condition_node.type_, condition_node.value.as_deref()) {
+            (SyntaxTreeNodeType::Expression, Some("not")) if condition_node.children.len() == 1 => {
+                Some(&condition_node.children[0])
+            }
+            _ => None,
+        };
+
+    match (negated_condition_node, else_nodes) {
+        (Some(inner_condition_node), []) => Some(format!(
+            "(when-not {}\n{})",
+            convert_abstract_syntax_tree_to_code(inner_condition_node),
+            convert_body_to_forms_code(then_nodes)
+        )),
+        (Some(_), [else_if_node])
+            if matches!(else_if_node.type_, SyntaxTreeNodeType::Branch)
+                && else_if_node.value.as_deref() == Some("if") =>
+        {
+            None
+        }
+        (Some(inner_condition_node), _) => Some(format!(
+            "(if-not {}\n{}\n{})",
+            convert_abstract_syntax_tree_to_code(inner_condition_node),
+            convert_body_to_code(then_nodes),
+            convert_body_to_code(else_nodes)
+        )),
+        (None, []) => {
+            let condition_code: PatternCode = patterns::convert_condition_to_code(condition_node);
+            let when_code: String = format!(
+                "(when {}\n{})",
+                condition_code.condition_code,
+                patterns::wrap_in_bindings(
+                    &condition_code.bindings,
+                    convert_body_to_forms_code(then_nodes)
+                )
+            );
+
+            Some(match condition_code.hoisted_bindings.is_empty() {
+                true => when_code,
+                false => format!(
+                    "(let [{}]\n{}",
+                    patterns::create_bindings_code(&condition_code.hoisted_bindings),
+                    when_code
+                ),
+            })
+        }
+        (None, _) => None,
+    }
+}
+
+pub(crate) fn convert_some_thread_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let mut step_codes: Vec<String> = Vec::from([convert_abstract_syntax_tree_to_code(
+        &syntax_tree_node.children[0],
+    )]);
+
+    for step_node in &syntax_tree_node.children[1..] {
+        let step_name: String = step_node.value.clone().unwrap();
+
+        step_codes.push(match (&step_node.type_, step_node.children.is_empty()) {
+            (SyntaxTreeNodeType::MemberAccess, _) => format!(":{}", step_name),
+            (_, true) => step_name,
+            (_, false) => format!(
+                "({} {})",
+                step_name,
+                step_node
+                    .children
+                    .iter()
+                    .map(convert_abstract_syntax_tree_to_code)
+                    .collect::<Vec<String>>()
+                    .join(" ")
+            ),
+        });
+    }
+
+    format!("(some-> {})", step_codes.join(" "))
+}
@@ -0,0 +1,149 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::transpiler::{
+    convert_abstract_syntax_tree_to_code, convert_body_to_forms_code,
+};
+
+const SHORTHAND_ARGUMENT: &str = "%";
+
+pub fn apply_anonymous_function_style(
+    syntax_tree_node: SyntaxTreeNode,
+    anonymous_function_shorthand: bool,
+) -> SyntaxTreeNode {
+    let children: Vec<SyntaxTreeNode> = syntax_tree_node
+        .children
+        .into_iter()
+        .map(|child| apply_anonymous_function_style(child, anonymous_function_shorthand))
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    let syntax_tree_node: SyntaxTreeNode = SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    };
+
+    match anonymous_function_shorthand && is_shorthand_candidate(&syntax_tree_node) {
+        true => {
+            let parameter_name: String = syntax_tree_node.children[0].value.clone().unwrap();
+
+            SyntaxTreeNode {
+                value: Some(String::from("#")),
+                type_: SyntaxTreeNodeType::Lambda,
+                children: Vec::from([replace_parameter_references(
+                    syntax_tree_node.children[1].clone(),
+                    &parameter_name,
+                )]),
+            }
+        }
+        false => syntax_tree_node,
+    }
+}
+
+fn is_shorthand_candidate(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    if !matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Lambda) {
+        return false;
+    }
+
+    match syntax_tree_node.children.as_slice() {
+        [parameter_node, body_node] => {
+            matches!(parameter_node.type_, SyntaxTreeNodeType::MethodArgument)
+                && matches!(
+                    body_node.type_,
+                    SyntaxTreeNodeType::Expression
+                        | SyntaxTreeNodeType::EqualityCheck
+                        | SyntaxTreeNodeType::MemberAccess
+                )
+                && !contains_nested_function(body_node)
+                && references_parameter(body_node, parameter_node.value.as_deref().unwrap())
+        }
+        _ => false,
+    }
+}
+
+fn contains_nested_function(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Lambda | SyntaxTreeNodeType::Assignment => true,
+        _ => syntax_tree_node
+            .children
+            .iter()
+            .any(contains_nested_function),
+    }
+}
+
+fn references_parameter(syntax_tree_node: &SyntaxTreeNode, parameter_name: &str) -> bool {
+    match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Literal, Some(name)) => name == parameter_name,
+        _ => syntax_tree_node
+            .children
+            .iter()
+            .any(|child| references_parameter(child, parameter_name)),
+    }
+}
+
+fn replace_parameter_references(
+    syntax_tree_node: SyntaxTreeNode,
+    parameter_name: &str,
+) -> SyntaxTreeNode {
+    let value: Option<String> = match (&syntax_tree_node.type_, syntax_tree_node.value) {
+        (SyntaxTreeNodeType::Literal, Some(name)) if name == parameter_name => {
+            Some(String::from(SHORTHAND_ARGUMENT))
+        }
+        (_, value) => value,
+    };
+
+    SyntaxTreeNode {
+        value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| replace_parameter_references(child, parameter_name))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+pub(crate) fn convert_lambda_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    if syntax_tree_node.value.as_deref() == Some("#") {
+        return format!(
+            "#{}",
+            convert_abstract_syntax_tree_to_code(&syntax_tree_node.children[0])
+        );
+    }
+
+    let (parameter_nodes, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+        syntax_tree_node
+            .children
+            .iter()
+            .cloned()
+            .partition(|child| matches!(child.type_, SyntaxTreeNodeType::MethodArgument));
+
+    let parameter_names: Vec<String> = parameter_nodes
+        .into_iter()
+        .filter_map(|parameter_node| parameter_node.value)
+        .collect::<Vec<String>>();
+
+    match body_nodes.as_slice() {
+        [] => format!("(fn [{}])", parameter_names.join(" ")),
+        [body_node]
+            if matches!(
+                body_node.type_,
+                SyntaxTreeNodeType::Literal
+                    | SyntaxTreeNodeType::Expression
+                    | SyntaxTreeNodeType::EqualityCheck
+                    | SyntaxTreeNodeType::MemberAccess
+                    | SyntaxTreeNodeType::Collection
+                    | SyntaxTreeNodeType::ObjectCreation
+            ) =>
+        {
+            format!(
+                "(fn [{}] {})",
+                parameter_names.join(" "),
+                convert_abstract_syntax_tree_to_code(body_node)
+            )
+        }
+        _ => format!(
+            "(fn [{}]\n{})",
+            parameter_names.join(" "),
+            convert_body_to_forms_code(&body_nodes)
+        ),
+    }
+}
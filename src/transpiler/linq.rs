@@ -0,0 +1,415 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::mutation::{create_call_node, create_literal_node};
+use crate::transpiler::queries::replace_group_keys;
+use crate::transpiler::transpiler::convert_abstract_syntax_tree_to_code;
+
+pub fn translate_query_operators(abstract_syntax_tree: SyntaxTreeNode) -> SyntaxTreeNode {
+    let mut method_names: Vec<String> = vec![];
+    collect_method_names(&abstract_syntax_tree, &mut method_names);
+
+    rewrite_query_chains(abstract_syntax_tree, &method_names)
+}
+
+pub(crate) fn collect_method_names(
+    syntax_tree_node: &SyntaxTreeNode,
+    method_names: &mut Vec<String>,
+) {
+    if let (SyntaxTreeNodeType::Method, Some(method_name)) =
+        (&syntax_tree_node.type_, &syntax_tree_node.value)
+    {
+        method_names.push(method_name.clone());
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_method_names(child, method_names);
+    }
+}
+
+fn rewrite_query_chains(
+    syntax_tree_node: SyntaxTreeNode,
+    method_names: &[String],
+) -> SyntaxTreeNode {
+    if is_query_operator_call(&syntax_tree_node, method_names) {
+        return create_query_pipeline(syntax_tree_node, method_names);
+    }
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| rewrite_query_chains(child, method_names))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn is_query_operator_call(syntax_tree_node: &SyntaxTreeNode, method_names: &[String]) -> bool {
+    let operator_name: &str = match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Invocation, Some(operator_name)) => operator_name,
+        _ => return false,
+    };
+
+    if syntax_tree_node.children.is_empty()
+        || method_names
+            .iter()
+            .any(|method_name| method_name == operator_name)
+    {
+        return false;
+    }
+
+    matches!(
+        (operator_name, syntax_tree_node.children.len() - 1),
+        (
+            "Where"
+                | "Select"
+                | "SelectMany"
+                | "OrderBy"
+                | "OrderByDescending"
+                | "ThenBy"
+                | "ThenByDescending"
+                | "GroupBy"
+                | "All"
+                | "Take"
+                | "Skip",
+            1
+        ) | (
+            "Any"
+                | "First"
+                | "FirstOrDefault"
+                | "Last"
+                | "LastOrDefault"
+                | "Count"
+                | "Sum"
+                | "Min"
+                | "Max",
+            0..=1
+        ) | ("Aggregate" | "Zip" | "ToDictionary", 1..=2)
+            | ("Distinct" | "ToList" | "ToArray" | "ToHashSet", 0)
+    )
+}
+
+fn create_query_pipeline(call_node: SyntaxTreeNode, method_names: &[String]) -> SyntaxTreeNode {
+    let mut operator_calls: Vec<(String, Vec<SyntaxTreeNode>)> = vec![];
+    let mut current_node: SyntaxTreeNode = call_node;
+
+    while is_query_operator_call(&current_node, method_names) {
+        let mut children: Vec<SyntaxTreeNode> = current_node.children;
+        let arguments: Vec<SyntaxTreeNode> = children
+            .split_off(1)
+            .into_iter()
+            .map(|argument| rewrite_query_chains(argument, method_names))
+            .collect::<Vec<SyntaxTreeNode>>();
+
+        operator_calls.push((current_node.value.unwrap(), arguments));
+        current_node = children.remove(0);
+    }
+    operator_calls.reverse();
+
+    let mut source_node: SyntaxTreeNode = rewrite_query_chains(current_node, method_names);
+    let mut step_nodes: Vec<SyntaxTreeNode> = vec![];
+    let mut sort_keys: Vec<(SyntaxTreeNode, bool)> = vec![];
+    let mut is_grouped: bool = false;
+
+    for (operator_name, arguments) in operator_calls {
+        let mut arguments: Vec<SyntaxTreeNode> = arguments
+            .into_iter()
+            .map(|argument| match is_grouped {
+                true => destructure_group_entry(argument),
+                false => simplify_key_selector(argument),
+            })
+            .collect::<Vec<SyntaxTreeNode>>();
+        is_grouped = operator_name == "GroupBy"
+            || (is_grouped
+                && matches!(
+                    operator_name.as_str(),
+                    "Where"
+                        | "OrderBy"
+                        | "OrderByDescending"
+                        | "ThenBy"
+                        | "ThenByDescending"
+                        | "Take"
+                        | "Skip"
+                        | "Distinct"
+                ));
+
+        if !operator_name.starts_with("ThenBy") {
+            step_nodes.extend(create_sort_steps(&mut sort_keys));
+        }
+
+        match (operator_name.as_str(), arguments.len()) {
+            ("OrderBy" | "ThenBy", _) => sort_keys.push((arguments.remove(0), false)),
+            ("OrderByDescending" | "ThenByDescending", _) => {
+                sort_keys.push((arguments.remove(0), true))
+            }
+            ("Zip", _) => {
+                let other_node: SyntaxTreeNode = arguments.remove(0);
+                let combine_node: SyntaxTreeNode = match arguments.pop() {
+                    Some(selector_node) => selector_node,
+                    None => create_literal_node("vector"),
+                };
+
+                source_node = create_call_node(
+                    "map",
+                    Vec::from([
+                        combine_node,
+                        close_pipeline(source_node, std::mem::take(&mut step_nodes)),
+                        other_node,
+                    ]),
+                );
+            }
+            _ => step_nodes.extend(create_operator_steps(&operator_name, arguments)),
+        }
+    }
+    step_nodes.extend(create_sort_steps(&mut sort_keys));
+
+    close_pipeline(source_node, step_nodes)
+}
+
+fn create_operator_steps(
+    operator_name: &str,
+    mut arguments: Vec<SyntaxTreeNode>,
+) -> Vec<SyntaxTreeNode> {
+    let predicate_steps = |arguments: Vec<SyntaxTreeNode>| -> Vec<SyntaxTreeNode> {
+        match arguments.is_empty() {
+            true => vec![],
+            false => Vec::from([create_call_node("filter", arguments)]),
+        }
+    };
+    let selector_steps = |mut arguments: Vec<SyntaxTreeNode>| -> Vec<SyntaxTreeNode> {
+        match arguments.pop() {
+            Some(selector_node) => Vec::from([create_call_node("map", Vec::from([selector_node]))]),
+            None => vec![],
+        }
+    };
+
+    let mut step_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    match operator_name {
+        "Where" => step_nodes.push(create_call_node("filter", arguments)),
+        "Select" => {
+            let selector_node: SyntaxTreeNode = arguments.remove(0);
+            step_nodes.push(match count_lambda_parameters(&selector_node) {
+                2 => create_call_node(
+                    "map-indexed",
+                    Vec::from([swap_lambda_parameters(selector_node)]),
+                ),
+                _ => create_call_node("map", Vec::from([selector_node])),
+            });
+        }
+        "SelectMany" => step_nodes.push(create_call_node("mapcat", arguments)),
+        "GroupBy" => step_nodes.push(create_call_node(
+            "group-by",
+            Vec::from([arguments.remove(0)]),
+        )),
+        "All" => step_nodes.push(create_call_node("every?", arguments)),
+        "Any" => {
+            step_nodes.push(match arguments.is_empty() {
+                true => create_call_node("seq", vec![]),
+                false => create_call_node("some", arguments),
+            });
+            step_nodes.push(create_call_node("boolean", vec![]));
+        }
+        "First" | "FirstOrDefault" => {
+            step_nodes.extend(predicate_steps(arguments));
+            step_nodes.push(create_call_node("first", vec![]));
+        }
+        "Last" | "LastOrDefault" => {
+            step_nodes.extend(predicate_steps(arguments));
+            step_nodes.push(create_call_node("last", vec![]));
+        }
+        "Count" => {
+            step_nodes.extend(predicate_steps(arguments));
+            step_nodes.push(create_call_node("count", vec![]));
+        }
+        "Sum" => {
+            step_nodes.extend(selector_steps(arguments));
+            step_nodes.push(create_call_node(
+                "reduce",
+                Vec::from([create_literal_node("+")]),
+            ));
+        }
+        "Min" | "Max" => {
+            step_nodes.extend(selector_steps(arguments));
+            step_nodes.push(create_call_node(
+                "apply",
+                Vec::from([create_literal_node(&operator_name.to_lowercase())]),
+            ));
+        }
+        "Aggregate" => {
+            arguments.reverse();
+            step_nodes.push(create_call_node("reduce", arguments));
+        }
+        "Distinct" => step_nodes.push(create_call_node("distinct", vec![])),
+        "Take" => step_nodes.push(create_call_node("take", arguments)),
+        "Skip" => step_nodes.push(create_call_node("drop", arguments)),
+        "ToDictionary" => {
+            let key_selector_node: SyntaxTreeNode = arguments.remove(0);
+            let value_selector_node: SyntaxTreeNode = match arguments.pop() {
+                Some(value_selector_node) => value_selector_node,
+                None => create_literal_node("identity"),
+            };
+
+            step_nodes.push(create_call_node(
+                "map",
+                Vec::from([create_call_node(
+                    "juxt",
+                    Vec::from([key_selector_node, value_selector_node]),
+                )]),
+            ));
+            step_nodes.push(create_call_node(
+                "into",
+                Vec::from([create_literal_node("{}")]),
+            ));
+        }
+        "ToList" | "ToArray" => step_nodes.push(create_call_node("vec", vec![])),
+        "ToHashSet" => step_nodes.push(create_call_node("set", vec![])),
+        _ => panic!("Unsupported query operator {}", operator_name),
+    }
+
+    step_nodes
+}
+
+fn create_sort_steps(sort_keys: &mut Vec<(SyntaxTreeNode, bool)>) -> Vec<SyntaxTreeNode> {
+    sort_keys
+        .drain(..)
+        .rev()
+        .map(|(key_selector_node, is_descending)| {
+            let mut arguments: Vec<SyntaxTreeNode> = Vec::from([key_selector_node]);
+            if is_descending {
+                arguments.push(create_descending_comparator());
+            }
+            create_call_node("sort-by", arguments)
+        })
+        .collect::<Vec<SyntaxTreeNode>>()
+}
+
+fn create_descending_comparator() -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: None,
+        type_: SyntaxTreeNodeType::Lambda,
+        children: Vec::from([
+            SyntaxTreeNode {
+                value: Some(String::from("a")),
+                type_: SyntaxTreeNodeType::MethodArgument,
+                children: vec![],
+            },
+            SyntaxTreeNode {
+                value: Some(String::from("b")),
+                type_: SyntaxTreeNodeType::MethodArgument,
+                children: vec![],
+            },
+            create_call_node(
+                "compare",
+                Vec::from([create_literal_node("b"), create_literal_node("a")]),
+            ),
+        ]),
+    }
+}
+
+fn close_pipeline(source_node: SyntaxTreeNode, step_nodes: Vec<SyntaxTreeNode>) -> SyntaxTreeNode {
+    match step_nodes.len() {
+        0 => source_node,
+        1 => {
+            let step_node: SyntaxTreeNode = step_nodes.into_iter().next().unwrap();
+            let mut arguments: Vec<SyntaxTreeNode> = step_node.children;
+            arguments.push(source_node);
+            create_call_node(step_node.value.as_deref().unwrap(), arguments)
+        }
+        _ => {
+            let mut children: Vec<SyntaxTreeNode> = Vec::from([source_node]);
+            children.extend(step_nodes);
+            create_call_node("->>", children)
+        }
+    }
+}
+
+fn simplify_key_selector(selector_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    match (&selector_node.type_, selector_node.children.as_slice()) {
+        (SyntaxTreeNodeType::Lambda, [parameter_node, body_node])
+            if matches!(parameter_node.type_, SyntaxTreeNodeType::MethodArgument)
+                && matches!(body_node.type_, SyntaxTreeNodeType::MemberAccess)
+                && matches!(
+                    body_node.children.as_slice(),
+                    [target_node] if matches!(target_node.type_, SyntaxTreeNodeType::Literal)
+                        && target_node.value == parameter_node.value
+                ) =>
+        {
+            SyntaxTreeNode {
+                value: body_node.value.clone(),
+                type_: SyntaxTreeNodeType::MemberAccess,
+                children: vec![],
+            }
+        }
+        _ => selector_node,
+    }
+}
+
+fn destructure_group_entry(selector_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let group_name: String = match (&selector_node.type_, selector_node.children.as_slice()) {
+        (SyntaxTreeNodeType::Lambda, [parameter_node, _])
+            if matches!(parameter_node.type_, SyntaxTreeNodeType::MethodArgument) =>
+        {
+            parameter_node.value.clone().unwrap()
+        }
+        _ => return selector_node,
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = selector_node.children;
+    let body_node: SyntaxTreeNode =
+        replace_group_keys(children.pop().unwrap(), std::slice::from_ref(&group_name));
+
+    if body_node.value == Some(format!("{}-key", group_name)) {
+        return create_literal_node("key");
+    }
+
+    SyntaxTreeNode {
+        value: selector_node.value,
+        type_: SyntaxTreeNodeType::Lambda,
+        children: Vec::from([
+            SyntaxTreeNode {
+                value: Some(format!("[{}-key {}]", group_name, group_name)),
+                type_: SyntaxTreeNodeType::MethodArgument,
+                children: vec![],
+            },
+            body_node,
+        ]),
+    }
+}
+
+fn count_lambda_parameters(selector_node: &SyntaxTreeNode) -> usize {
+    match selector_node.type_ {
+        SyntaxTreeNodeType::Lambda => selector_node
+            .children
+            .iter()
+            .filter(|child| matches!(child.type_, SyntaxTreeNodeType::MethodArgument))
+            .count(),
+        _ => 0,
+    }
+}
+
+fn swap_lambda_parameters(lambda_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let mut children: Vec<SyntaxTreeNode> = lambda_node.children;
+    children.swap(0, 1);
+
+    SyntaxTreeNode {
+        value: lambda_node.value,
+        type_: SyntaxTreeNodeType::Lambda,
+        children,
+    }
+}
+
+pub(crate) fn convert_thread_last_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let mut form_codes: Vec<String> = Vec::from([convert_abstract_syntax_tree_to_code(
+        &syntax_tree_node.children[0],
+    )]);
+
+    for step_node in &syntax_tree_node.children[1..] {
+        form_codes.push(match (&step_node.type_, step_node.children.is_empty()) {
+            (SyntaxTreeNodeType::Expression, true) => step_node.value.clone().unwrap(),
+            _ => convert_abstract_syntax_tree_to_code(step_node),
+        });
+    }
+
+    format!("(->> {})", form_codes.join("\n"))
+}
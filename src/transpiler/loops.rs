@@ -0,0 +1,749 @@
+use crate::syntax_tree::syntax_tree_builder::{contains_loop_control, split_branch_bodies};
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::patterns;
+use crate::transpiler::patterns::PatternCode;
+use crate::transpiler::transpiler::{
+    close_open_parentheses, convert_abstract_syntax_tree_to_code, find_assigned_value,
+};
+
+pub(crate) fn convert_loop_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    match syntax_tree_node.value.as_deref() {
+        Some("for") => return convert_for_loop_to_code(syntax_tree_node),
+        Some("foreach") => return convert_foreach_loop_to_code(syntax_tree_node),
+        _ => {}
+    }
+
+    let condition_code: String =
+        convert_abstract_syntax_tree_to_code(&syntax_tree_node.children[0]);
+    let body_nodes: &[SyntaxTreeNode] = &syntax_tree_node.children[1..];
+
+    let mutated_names: Vec<String> = collect_mutated_names(body_nodes);
+    let recur_code: String = create_recur_code(&mutated_names);
+    let loop_result_code: String = create_loop_result_code(&mutated_names);
+
+    let test_code: String = match mutated_names.is_empty() {
+        true => format!("(when {}\n{})", condition_code, recur_code),
+        false => format!(
+            "(if {}\n{}\n{})",
+            condition_code, recur_code, loop_result_code
+        ),
+    };
+
+    let iteration_code: String = match (syntax_tree_node.value.as_deref(), mutated_names.is_empty())
+    {
+        (Some("do"), _) => convert_loop_body_to_code(
+            body_nodes,
+            test_code.clone(),
+            false,
+            &LoopExits {
+                break_code: loop_result_code,
+                continue_code: test_code,
+            },
+        ),
+        (_, true) => format!(
+            "(when {}\n{})",
+            condition_code,
+            convert_loop_body_to_code(
+                body_nodes,
+                recur_code.clone(),
+                false,
+                &LoopExits {
+                    break_code: loop_result_code,
+                    continue_code: recur_code,
+                }
+            )
+        ),
+        (_, false) => format!(
+            "(if {}\n{}\n{})",
+            condition_code,
+            convert_loop_body_to_code(
+                body_nodes,
+                recur_code.clone(),
+                true,
+                &LoopExits {
+                    break_code: loop_result_code.clone(),
+                    continue_code: recur_code,
+                }
+            ),
+            loop_result_code
+        ),
+    };
+
+    wrap_loop_in_rebinding(
+        &mutated_names,
+        create_loop_bindings_code(&mutated_names),
+        iteration_code,
+    )
+}
+
+pub(crate) fn wrap_loop_in_rebinding(
+    mutated_names: &[String],
+    loop_bindings_code: String,
+    iteration_code: String,
+) -> String {
+    wrap_in_rebinding(
+        mutated_names,
+        format!("(loop [{}]\n{})", loop_bindings_code, iteration_code),
+    )
+}
+
+pub(crate) fn wrap_in_rebinding(mutated_names: &[String], code: String) -> String {
+    match mutated_names.len() {
+        0 => code,
+        1 => format!("(let [{} {}]", mutated_names[0], code),
+        _ => format!("(let [[{}] {}]", mutated_names.join(" "), code),
+    }
+}
+
+pub(crate) fn create_loop_bindings_code(mutated_names: &[String]) -> String {
+    mutated_names
+        .iter()
+        .map(|name| format!("{} {}", name, name))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub(crate) fn create_recur_code(mutated_names: &[String]) -> String {
+    match mutated_names.is_empty() {
+        true => String::from("(recur)"),
+        false => format!("(recur {})", mutated_names.join(" ")),
+    }
+}
+
+pub(crate) fn create_loop_result_code(mutated_names: &[String]) -> String {
+    match mutated_names.len() {
+        0 => String::from("nil"),
+        1 => mutated_names[0].clone(),
+        _ => format!("[{}]", mutated_names.join(" ")),
+    }
+}
+
+/// Rewrites `items.Add(item)` statements on locals inside loop bodies into
+/// `items = conj(items, item)`, or `assoc` for the two argument dictionary form, so that
+/// collections built by a loop body of any shape are threaded through `reduce` or `loop` like
+/// every other mutated local.
+pub fn thread_collection_additions(syntax_tree_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    if matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Loop) {
+        return rewrite_collection_addition(&syntax_tree_node);
+    }
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(thread_collection_additions)
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn rewrite_collection_additions(body_nodes: &[SyntaxTreeNode]) -> Vec<SyntaxTreeNode> {
+    body_nodes
+        .iter()
+        .map(rewrite_collection_addition)
+        .collect::<Vec<SyntaxTreeNode>>()
+}
+
+fn rewrite_collection_addition(statement_node: &SyntaxTreeNode) -> SyntaxTreeNode {
+    let first_body_index: usize = match (
+        &statement_node.type_,
+        statement_node.value.as_deref(),
+        statement_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Expression, Some(method_name), [target, added_nodes @ ..])
+            if method_name.eq_ignore_ascii_case("add")
+                && matches!(target.type_, SyntaxTreeNodeType::Literal)
+                && matches!(added_nodes.len(), 1 | 2) =>
+        {
+            return SyntaxTreeNode {
+                value: target.value.clone(),
+                type_: SyntaxTreeNodeType::Assignment,
+                children: Vec::from([SyntaxTreeNode {
+                    value: Some(String::from(match added_nodes.len() {
+                        1 => "conj",
+                        _ => "assoc",
+                    })),
+                    type_: SyntaxTreeNodeType::Expression,
+                    children: statement_node.children.clone(),
+                }]),
+            };
+        }
+        (SyntaxTreeNodeType::Branch, Some("if"), _) => 1,
+        (SyntaxTreeNodeType::Branch, Some("else"), _) => 0,
+        (SyntaxTreeNodeType::Loop, Some("for"), _) => 3,
+        (SyntaxTreeNodeType::Loop, Some("foreach"), _) => 2,
+        (SyntaxTreeNodeType::Loop, _, _) => 1,
+        _ => return statement_node.clone(),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = statement_node.children[..first_body_index].to_vec();
+    children.extend(rewrite_collection_additions(
+        &statement_node.children[first_body_index..],
+    ));
+
+    SyntaxTreeNode {
+        value: statement_node.value.clone(),
+        type_: statement_node.type_.clone(),
+        children,
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct LoopExits {
+    pub break_code: String,
+    pub continue_code: String,
+}
+
+pub(crate) fn convert_loop_body_to_code(
+    body_nodes: &[SyntaxTreeNode],
+    final_form_code: String,
+    wrap_in_do: bool,
+    loop_exits: &LoopExits,
+) -> String {
+    let mut body_code: Vec<String> = vec![];
+
+    for (index, body_node) in body_nodes.iter().enumerate() {
+        match (&body_node.type_, body_node.value.as_deref()) {
+            (SyntaxTreeNodeType::LoopControl, Some("break")) => {
+                body_code.push(loop_exits.break_code.clone());
+                return join_loop_body_code(body_nodes, body_code, wrap_in_do);
+            }
+            (SyntaxTreeNodeType::LoopControl, _) => {
+                body_code.push(loop_exits.continue_code.clone());
+                return join_loop_body_code(body_nodes, body_code, wrap_in_do);
+            }
+            (SyntaxTreeNodeType::Branch, Some("if")) if contains_loop_control(body_node) => {
+                let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+                    split_branch_bodies(body_node);
+                let condition_code: PatternCode =
+                    patterns::convert_condition_to_code(&body_node.children[0]);
+
+                let mut then_continuation_nodes: Vec<SyntaxTreeNode> = then_nodes.to_vec();
+                then_continuation_nodes.extend_from_slice(&body_nodes[index + 1..]);
+
+                let mut else_continuation_nodes: Vec<SyntaxTreeNode> = else_nodes.to_vec();
+                else_continuation_nodes.extend_from_slice(&body_nodes[index + 1..]);
+
+                let branch_code: String = format!(
+                    "(if {}\n{}\n{})",
+                    condition_code.condition_code,
+                    patterns::wrap_in_bindings(
+                        &condition_code.bindings,
+                        convert_loop_body_to_code(
+                            &then_continuation_nodes,
+                            final_form_code.clone(),
+                            true,
+                            loop_exits
+                        )
+                    ),
+                    convert_loop_body_to_code(
+                        &else_continuation_nodes,
+                        final_form_code,
+                        true,
+                        loop_exits
+                    )
+                );
+
+                body_code.push(patterns::wrap_in_bindings(
+                    &condition_code.hoisted_bindings,
+                    branch_code,
+                ));
+                return join_loop_body_code(body_nodes, body_code, wrap_in_do);
+            }
+            _ => body_code.push(convert_abstract_syntax_tree_to_code(body_node)),
+        }
+    }
+
+    if !final_form_code.is_empty() {
+        body_code.push(final_form_code);
+    }
+
+    join_loop_body_code(body_nodes, body_code, wrap_in_do)
+}
+
+fn join_loop_body_code(
+    body_nodes: &[SyntaxTreeNode],
+    body_code: Vec<String>,
+    wrap_in_do: bool,
+) -> String {
+    let output: String = match wrap_in_do
+        && body_code.len() > 1
+        && body_nodes
+            .first()
+            .is_some_and(|node| !matches!(node.type_, SyntaxTreeNodeType::Assignment))
+    {
+        true => format!("(do\n{}", body_code.join("\n")),
+        false => body_code.join("\n"),
+    };
+
+    close_open_parentheses(output)
+}
+
+pub(crate) fn collect_mutated_names(body_nodes: &[SyntaxTreeNode]) -> Vec<String> {
+    let mut assigned_names: Vec<String> = vec![];
+    let mut declared_names: Vec<String> = vec![];
+
+    for body_node in body_nodes {
+        collect_assigned_names(body_node, &mut assigned_names, &mut declared_names);
+    }
+
+    assigned_names
+        .into_iter()
+        .filter(|name| !declared_names.contains(name))
+        .collect::<Vec<String>>()
+}
+
+fn collect_assigned_names(
+    syntax_tree_node: &SyntaxTreeNode,
+    assigned_names: &mut Vec<String>,
+    declared_names: &mut Vec<String>,
+) {
+    if matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Assignment) {
+        let is_declaration: bool = syntax_tree_node
+            .children
+            .iter()
+            .any(|child| matches!(child.type_, SyntaxTreeNodeType::Type));
+
+        for name in find_assigned_names(syntax_tree_node) {
+            if is_declaration {
+                declared_names.push(name);
+            } else if !assigned_names.contains(&name) {
+                assigned_names.push(name);
+            }
+        }
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_assigned_names(child, assigned_names, declared_names);
+    }
+}
+
+fn find_assigned_names(assignment_node: &SyntaxTreeNode) -> Vec<String> {
+    match (
+        &assignment_node.value,
+        assignment_node
+            .children
+            .iter()
+            .find(|child| !matches!(child.type_, SyntaxTreeNodeType::Type)),
+    ) {
+        (Some(name), _) => Vec::from([name.clone()]),
+        (None, Some(target_node)) if matches!(target_node.type_, SyntaxTreeNodeType::Collection) => {
+            target_node
+                .children
+                .iter()
+                .filter_map(|name_node| name_node.value.clone())
+                .collect::<Vec<String>>()
+        }
+        (None, _) => vec![],
+    }
+}
+
+struct CountingLoop {
+    variable_name: String,
+    start_code: String,
+    end_code: String,
+    comparison: String,
+    step: i64,
+}
+
+fn convert_for_loop_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let initializer_nodes: &[SyntaxTreeNode] = &syntax_tree_node.children[0].children;
+    let condition_node: &SyntaxTreeNode = &syntax_tree_node.children[1];
+    let iterator_nodes: &[SyntaxTreeNode] = &syntax_tree_node.children[2].children;
+    let body_nodes: &[SyntaxTreeNode] = &syntax_tree_node.children[3..];
+
+    let mutated_names: Vec<String> = collect_mutated_names(body_nodes);
+
+    let has_loop_exits: bool = body_nodes.iter().any(contains_loop_control);
+
+    if let Some(counting_loop) = recognise_counting_loop(
+        initializer_nodes,
+        condition_node,
+        iterator_nodes,
+        &mutated_names,
+    )
+    .filter(|_| !has_loop_exits)
+    {
+        return convert_counting_loop_to_code(&counting_loop, body_nodes, &mutated_names);
+    }
+
+    let initializer_names: Vec<String> = initializer_nodes
+        .iter()
+        .filter_map(|node| node.value.clone())
+        .collect::<Vec<String>>();
+
+    let mut loop_body_nodes: Vec<SyntaxTreeNode> = body_nodes.to_vec();
+    loop_body_nodes.extend_from_slice(iterator_nodes);
+
+    let outer_mutated_names: Vec<String> = collect_mutated_names(&loop_body_nodes)
+        .into_iter()
+        .filter(|name| !initializer_names.contains(name))
+        .collect::<Vec<String>>();
+
+    let mut loop_names: Vec<String> = initializer_names.clone();
+    loop_names.extend_from_slice(&outer_mutated_names);
+
+    let mut loop_bindings_code: Vec<String> = initializer_nodes
+        .iter()
+        .map(|node| {
+            format!(
+                "{} {}",
+                node.value.clone().unwrap(),
+                convert_abstract_syntax_tree_to_code(find_assigned_value(node))
+            )
+        })
+        .collect::<Vec<String>>();
+    loop_bindings_code.push(create_loop_bindings_code(&outer_mutated_names));
+
+    let recur_code: String = create_recur_code(&loop_names);
+    let loop_exits: LoopExits = LoopExits {
+        break_code: create_loop_result_code(&outer_mutated_names),
+        continue_code: convert_loop_body_to_code(
+            iterator_nodes,
+            recur_code.clone(),
+            true,
+            &LoopExits::default(),
+        ),
+    };
+
+    let iteration_code: String = match loop_names.is_empty() {
+        true => format!(
+            "(when {}\n{})",
+            convert_abstract_syntax_tree_to_code(condition_node),
+            convert_loop_body_to_code(&loop_body_nodes, recur_code, false, &loop_exits)
+        ),
+        false => format!(
+            "(if {}\n{}\n{})",
+            convert_abstract_syntax_tree_to_code(condition_node),
+            convert_loop_body_to_code(&loop_body_nodes, recur_code, true, &loop_exits),
+            create_loop_result_code(&outer_mutated_names)
+        ),
+    };
+
+    wrap_loop_in_rebinding(
+        &outer_mutated_names,
+        loop_bindings_code
+            .into_iter()
+            .filter(|binding| !binding.is_empty())
+            .collect::<Vec<String>>()
+            .join(" "),
+        iteration_code,
+    )
+}
+
+fn recognise_counting_loop(
+    initializer_nodes: &[SyntaxTreeNode],
+    condition_node: &SyntaxTreeNode,
+    iterator_nodes: &[SyntaxTreeNode],
+    mutated_names: &[String],
+) -> Option<CountingLoop> {
+    let [initializer_node] = initializer_nodes else {
+        return None;
+    };
+    let [iterator_node] = iterator_nodes else {
+        return None;
+    };
+
+    let variable_name: String = initializer_node.value.clone()?;
+
+    if !initializer_node
+        .children
+        .iter()
+        .any(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        || iterator_node.value.as_deref() != Some(variable_name.as_str())
+    {
+        return None;
+    }
+
+    let comparison: String = match (&condition_node.type_, condition_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Expression, Some(operator @ ("<" | "<=" | ">" | ">=")))
+            if condition_node.children[0].value.as_deref() == Some(variable_name.as_str()) =>
+        {
+            operator.to_string()
+        }
+        _ => return None,
+    };
+
+    let mut end_names: Vec<String> = vec![];
+    collect_literal_values(&condition_node.children[1], &mut end_names);
+    if mutated_names
+        .iter()
+        .any(|name| *name == variable_name || end_names.contains(name))
+    {
+        return None;
+    }
+
+    let step_node: &SyntaxTreeNode = find_assigned_value(iterator_node);
+    let step: i64 = match (step_node.value.as_deref(), step_node.children.as_slice()) {
+        (Some("inc"), _) => 1,
+        (Some("dec"), _) => -1,
+        (Some(operator @ ("+" | "-")), [target, amount])
+            if target.value.as_deref() == Some(variable_name.as_str()) =>
+        {
+            let amount: i64 = amount.value.as_deref()?.parse::<i64>().ok()?;
+            match operator {
+                "+" => amount,
+                _ => -amount,
+            }
+        }
+        _ => return None,
+    };
+
+    if step == 0 || (step > 0) != comparison.starts_with('<') {
+        return None;
+    }
+
+    Some(CountingLoop {
+        variable_name,
+        start_code: convert_abstract_syntax_tree_to_code(find_assigned_value(initializer_node)),
+        end_code: convert_abstract_syntax_tree_to_code(&condition_node.children[1]),
+        comparison,
+        step,
+    })
+}
+
+fn convert_counting_loop_to_code(
+    counting_loop: &CountingLoop,
+    body_nodes: &[SyntaxTreeNode],
+    mutated_names: &[String],
+) -> String {
+    if mutated_names.is_empty()
+        && counting_loop.start_code == "0"
+        && counting_loop.step == 1
+        && counting_loop.comparison == "<"
+    {
+        return close_open_parentheses(format!(
+            "(dotimes [{} {}]\n{}",
+            counting_loop.variable_name,
+            counting_loop.end_code,
+            convert_loop_body_to_code(body_nodes, String::new(), false, &LoopExits::default())
+        ));
+    }
+
+    let range_code: String = create_range_code(counting_loop);
+
+    if mutated_names.is_empty() {
+        return close_open_parentheses(format!(
+            "(doseq [{} {}]\n{}",
+            counting_loop.variable_name,
+            range_code,
+            convert_loop_body_to_code(body_nodes, String::new(), false, &LoopExits::default())
+        ));
+    }
+
+    let accumulator_code: String = create_loop_result_code(mutated_names);
+
+    wrap_in_rebinding(
+        mutated_names,
+        format!(
+            "(reduce (fn [{} {}]\n{})\n{}\n{})",
+            accumulator_code,
+            counting_loop.variable_name,
+            convert_loop_body_to_code(
+                body_nodes,
+                accumulator_code.clone(),
+                false,
+                &LoopExits::default()
+            ),
+            accumulator_code,
+            range_code
+        ),
+    )
+}
+
+fn create_range_code(counting_loop: &CountingLoop) -> String {
+    let end_code: String = match counting_loop.comparison.as_str() {
+        "<=" => format!("(inc {})", counting_loop.end_code),
+        ">=" => format!("(dec {})", counting_loop.end_code),
+        _ => counting_loop.end_code.clone(),
+    };
+
+    match (counting_loop.start_code.as_str(), counting_loop.step) {
+        ("0", 1) => format!("(range {})", end_code),
+        (_, 1) => format!("(range {} {})", counting_loop.start_code, end_code),
+        (_, step) => format!("(range {} {} {})", counting_loop.start_code, end_code, step),
+    }
+}
+
+fn collect_literal_values(syntax_tree_node: &SyntaxTreeNode, literal_values: &mut Vec<String>) {
+    if let (SyntaxTreeNodeType::Literal, Some(value)) =
+        (&syntax_tree_node.type_, &syntax_tree_node.value)
+    {
+        literal_values.push(value.clone());
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_literal_values(child, literal_values);
+    }
+}
+
+fn convert_foreach_loop_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let binding_code: String = convert_abstract_syntax_tree_to_code(&syntax_tree_node.children[0]);
+    let source_code: String = convert_abstract_syntax_tree_to_code(&syntax_tree_node.children[1]);
+    let body_nodes: &[SyntaxTreeNode] = &syntax_tree_node.children[2..];
+
+    if let Some(collection_building_code) =
+        convert_collection_building_loop_to_code(&binding_code, &source_code, body_nodes)
+    {
+        return collection_building_code;
+    }
+
+    let mutated_names: Vec<String> = collect_mutated_names(body_nodes);
+    let has_loop_exits: bool = body_nodes.iter().any(contains_loop_control);
+
+    if mutated_names.is_empty() && !has_loop_exits {
+        return close_open_parentheses(format!(
+            "(doseq [{} {}]\n{}",
+            binding_code,
+            source_code,
+            convert_loop_body_to_code(body_nodes, String::new(), false, &LoopExits::default())
+        ));
+    }
+
+    let (accumulator_binding_code, accumulator_code): (String, String) =
+        match mutated_names.is_empty() {
+            true => (String::from("_"), String::from("nil")),
+            false => (
+                create_loop_result_code(&mutated_names),
+                create_loop_result_code(&mutated_names),
+            ),
+        };
+
+    wrap_in_rebinding(
+        &mutated_names,
+        format!(
+            "(reduce (fn [{} {}]\n{})\n{}\n{})",
+            accumulator_binding_code,
+            binding_code,
+            convert_loop_body_to_code(
+                body_nodes,
+                accumulator_code.clone(),
+                false,
+                &LoopExits {
+                    break_code: format!("(reduced {})", accumulator_code),
+                    continue_code: accumulator_code.clone(),
+                }
+            ),
+            accumulator_code,
+            source_code
+        ),
+    )
+}
+
+fn convert_collection_building_loop_to_code(
+    binding_code: &str,
+    source_code: &str,
+    body_nodes: &[SyntaxTreeNode],
+) -> Option<String> {
+    let (declaration_nodes, last_node): (&[SyntaxTreeNode], &SyntaxTreeNode) = match body_nodes
+        .iter()
+        .rposition(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment))
+    {
+        Some(index) => (&body_nodes[..index], &body_nodes[index]),
+        None => return None,
+    };
+
+    if declaration_nodes.iter().any(|node| {
+        !matches!(
+            node.type_,
+            SyntaxTreeNodeType::Assignment | SyntaxTreeNodeType::Comment
+        )
+    }) || !collect_mutated_names(declaration_nodes).is_empty()
+    {
+        return None;
+    }
+
+    let (target_name, assigned_value_node): (&str, &SyntaxTreeNode) = match (
+        &last_node.type_,
+        last_node.value.as_deref(),
+        last_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Assignment, Some(target_name), [assigned_value_node]) => {
+            (target_name, assigned_value_node)
+        }
+        _ => return None,
+    };
+
+    let (guard_node, conj_node): (Option<&SyntaxTreeNode>, &SyntaxTreeNode) = match (
+        &assigned_value_node.type_,
+        assigned_value_node.value.as_deref(),
+        assigned_value_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Branch, Some("if"), [guard_node, conj_node, else_node])
+            if matches!(
+                else_node.children.as_slice(),
+                [unchanged_node] if unchanged_node.value.as_deref() == Some(target_name)
+            ) =>
+        {
+            (Some(guard_node), conj_node)
+        }
+        _ => (None, assigned_value_node),
+    };
+
+    let added_value_node: &SyntaxTreeNode = match (
+        &conj_node.type_,
+        conj_node.value.as_deref(),
+        conj_node.children.as_slice(),
+    ) {
+        (SyntaxTreeNodeType::Expression, Some("conj"), [target, added_value_node])
+            if target.value.as_deref() == Some(target_name) =>
+        {
+            added_value_node
+        }
+        _ => return None,
+    };
+
+    let added_value_code: String = convert_abstract_syntax_tree_to_code(added_value_node);
+
+    let declaration_bindings_code: Vec<String> = declaration_nodes
+        .iter()
+        .filter(|node| matches!(node.type_, SyntaxTreeNodeType::Assignment))
+        .flat_map(|node| match node.value {
+            Some(_) => vec![node],
+            None => node.children.iter().collect::<Vec<&SyntaxTreeNode>>(),
+        })
+        .map(|node| {
+            format!(
+                "{} {}",
+                node.value.clone().unwrap(),
+                convert_abstract_syntax_tree_to_code(find_assigned_value(node))
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let elements_code: String = match (guard_node, declaration_bindings_code.is_empty()) {
+        (None, true) => format!(
+            "(mapv (fn [{}] {}) {})",
+            binding_code, added_value_code, source_code
+        ),
+        _ => {
+            let mut comprehension_bindings_code: String =
+                format!("{} {}", binding_code, source_code);
+
+            if !declaration_bindings_code.is_empty() {
+                comprehension_bindings_code
+                    .push_str(format!("\n:let [{}]", declaration_bindings_code.join(" ")).as_str());
+            }
+            if let Some(guard_node) = guard_node {
+                comprehension_bindings_code.push_str(
+                    format!(
+                        "\n:when {}",
+                        convert_abstract_syntax_tree_to_code(guard_node)
+                    )
+                    .as_str(),
+                );
+            }
+
+            format!(
+                "(for [{}]\n{})",
+                comprehension_bindings_code, added_value_code
+            )
+        }
+    };
+
+    Some(format!(
+        "(let [{} (into {} {})]",
+        target_name, target_name, elements_code
+    ))
+}
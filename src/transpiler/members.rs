@@ -0,0 +1,472 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::clojure_core::describe_core_collision;
+use crate::transpiler::linq::collect_method_names;
+use crate::transpiler::mutation::{create_call_node, create_literal_node};
+use crate::transpiler::naming::convert_namespace_name;
+use std::collections::{HashMap, HashSet};
+use std::vec::IntoIter;
+
+const UNKNOWN_TYPE: &str = "var";
+
+const LIST_TYPE_PREFIXES: [&str; 5] = [
+    "List<",
+    "IList<",
+    "IReadOnlyList<",
+    "ImmutableList<",
+    "ImmutableArray<",
+];
+
+const DICTIONARY_TYPE_PREFIXES: [&str; 5] = [
+    "Dictionary<",
+    "IDictionary<",
+    "IReadOnlyDictionary<",
+    "ImmutableDictionary<",
+    "SortedDictionary<",
+];
+
+const SET_TYPE_PREFIXES: [&str; 4] = ["HashSet<", "ISet<", "SortedSet<", "ImmutableHashSet<"];
+
+const HOST_TYPES: [&str; 9] = [
+    "string",
+    "String",
+    "StringBuilder",
+    "DateTime",
+    "TimeSpan",
+    "Guid",
+    "Random",
+    "Regex",
+    "Uri",
+];
+
+struct MemberScope<'a> {
+    method_names: Vec<String>,
+    type_names: Vec<String>,
+    naming_overrides: &'a HashMap<String, String>,
+    namespace_name: String,
+    flagged_names: HashSet<String>,
+    warnings: Vec<String>,
+}
+
+pub fn resolve_member_access(
+    abstract_syntax_tree: SyntaxTreeNode,
+    naming_overrides: &HashMap<String, String>,
+) -> (SyntaxTreeNode, Vec<String>) {
+    let mut member_scope: MemberScope = MemberScope {
+        method_names: vec![],
+        type_names: vec![],
+        naming_overrides,
+        namespace_name: String::new(),
+        flagged_names: HashSet::new(),
+        warnings: vec![],
+    };
+    collect_method_names(&abstract_syntax_tree, &mut member_scope.method_names);
+    collect_type_names(&abstract_syntax_tree, &mut member_scope.type_names);
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    for child in abstract_syntax_tree.children {
+        if let (SyntaxTreeNodeType::Namespace, Some(namespace_name)) = (&child.type_, &child.value)
+        {
+            member_scope.namespace_name = convert_namespace_name(namespace_name, naming_overrides);
+            member_scope.flagged_names.clear();
+        }
+
+        children.push(resolve_syntax_tree_node(
+            child,
+            &HashMap::new(),
+            &mut member_scope,
+        ));
+    }
+
+    (
+        SyntaxTreeNode {
+            value: abstract_syntax_tree.value,
+            type_: abstract_syntax_tree.type_,
+            children,
+        },
+        member_scope.warnings,
+    )
+}
+
+fn collect_type_names(syntax_tree_node: &SyntaxTreeNode, type_names: &mut Vec<String>) {
+    if let (SyntaxTreeNodeType::Class | SyntaxTreeNodeType::Interface, Some(type_name)) =
+        (&syntax_tree_node.type_, &syntax_tree_node.value)
+    {
+        type_names.push(type_name.clone());
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_type_names(child, type_names);
+    }
+}
+
+fn resolve_syntax_tree_node(
+    syntax_tree_node: SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+    member_scope: &mut MemberScope,
+) -> SyntaxTreeNode {
+    let mut local_types: HashMap<String, String> = local_types.clone();
+
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Method => collect_local_types(&syntax_tree_node, &mut local_types),
+        SyntaxTreeNodeType::Lambda => {
+            for parameter_node in &syntax_tree_node.children {
+                if let (SyntaxTreeNodeType::MethodArgument, Some(parameter_name)) =
+                    (&parameter_node.type_, &parameter_node.value)
+                {
+                    local_types.insert(parameter_name.clone(), String::from(UNKNOWN_TYPE));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if syntax_tree_node.value.as_deref() == Some("some->") {
+        return resolve_null_conditional_chain(syntax_tree_node, &local_types, member_scope);
+    }
+
+    let syntax_tree_node: SyntaxTreeNode = SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| resolve_syntax_tree_node(child, &local_types, member_scope))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    };
+
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::ElementAccess => resolve_element_access(syntax_tree_node, &local_types),
+        SyntaxTreeNodeType::MemberAccess if syntax_tree_node.children.len() == 1 => {
+            resolve_property_access(syntax_tree_node, &local_types)
+        }
+        SyntaxTreeNodeType::Invocation => {
+            resolve_method_call(syntax_tree_node, &local_types, member_scope)
+        }
+        SyntaxTreeNodeType::ObjectCreation => resolve_host_initializers(syntax_tree_node),
+        _ => syntax_tree_node,
+    }
+}
+
+fn resolve_null_conditional_chain(
+    chain_node: SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+    member_scope: &mut MemberScope,
+) -> SyntaxTreeNode {
+    let mut children: IntoIter<SyntaxTreeNode> = chain_node.children.into_iter();
+    let mut resolved_children: Vec<SyntaxTreeNode> = vec![];
+
+    if let Some(subject_node) = children.next() {
+        resolved_children.push(resolve_syntax_tree_node(
+            subject_node,
+            local_types,
+            member_scope,
+        ));
+    }
+
+    resolved_children.extend(children.map(|step_node| {
+        SyntaxTreeNode {
+            value: step_node.value,
+            type_: step_node.type_,
+            children: step_node
+                .children
+                .into_iter()
+                .map(|argument_node| {
+                    resolve_syntax_tree_node(argument_node, local_types, member_scope)
+                })
+                .collect::<Vec<SyntaxTreeNode>>(),
+        }
+    }));
+
+    SyntaxTreeNode {
+        value: chain_node.value,
+        type_: chain_node.type_,
+        children: resolved_children,
+    }
+}
+
+fn collect_local_types(
+    syntax_tree_node: &SyntaxTreeNode,
+    local_types: &mut HashMap<String, String>,
+) {
+    for child in &syntax_tree_node.children {
+        match (&child.type_, &child.value, child.children.as_slice()) {
+            (SyntaxTreeNodeType::MethodArgument, Some(name), children) => {
+                local_types.insert(
+                    name.clone(),
+                    match children.first() {
+                        Some(type_node) => type_node.value.clone().unwrap(),
+                        None => String::from(UNKNOWN_TYPE),
+                    },
+                );
+            }
+            (SyntaxTreeNodeType::Assignment, Some(name), [type_node, value_node, ..])
+                if matches!(type_node.type_, SyntaxTreeNodeType::Type) =>
+            {
+                local_types.insert(
+                    name.clone(),
+                    match type_node.value.as_deref() {
+                        Some(UNKNOWN_TYPE) | None => infer_type(value_node, local_types)
+                            .unwrap_or_else(|| String::from(UNKNOWN_TYPE)),
+                        Some(type_name) => type_name.to_string(),
+                    },
+                );
+            }
+            _ => {}
+        }
+
+        if !matches!(child.type_, SyntaxTreeNodeType::Lambda) {
+            collect_local_types(child, local_types);
+        }
+    }
+}
+
+fn infer_type(
+    syntax_tree_node: &SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+) -> Option<String> {
+    match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Literal, Some(value)) if value.starts_with('"') => {
+            Some(String::from("string"))
+        }
+        (SyntaxTreeNodeType::Literal, Some(name)) => local_types
+            .get(name)
+            .filter(|type_name| *type_name != UNKNOWN_TYPE)
+            .cloned(),
+        (SyntaxTreeNodeType::ObjectCreation, _) => syntax_tree_node
+            .children
+            .first()
+            .filter(|type_node| matches!(type_node.type_, SyntaxTreeNodeType::Type))
+            .and_then(|type_node| type_node.value.clone()),
+        (SyntaxTreeNodeType::ElementAccess, _) => {
+            find_element_type(&infer_type(&syntax_tree_node.children[0], local_types)?)
+        }
+        _ => None,
+    }
+}
+
+fn find_element_type(collection_type_name: &str) -> Option<String> {
+    if let Some(element_type_name) = collection_type_name.strip_suffix("[]") {
+        return Some(element_type_name.to_string());
+    }
+
+    let type_arguments: &str = collection_type_name.split_once('<')?.1.strip_suffix('>')?;
+
+    match is_dictionary_type(collection_type_name) {
+        true => type_arguments
+            .split_once(',')
+            .map(|(_, value_type_name)| value_type_name.to_string()),
+        false => Some(type_arguments.to_string()),
+    }
+}
+
+fn resolve_element_access(
+    syntax_tree_node: SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+) -> SyntaxTreeNode {
+    let accessor_name: &str = match infer_type(&syntax_tree_node.children[0], local_types) {
+        Some(type_name) if is_list_type(&type_name) || type_name == "string" => "nth",
+        _ => "get",
+    };
+
+    SyntaxTreeNode {
+        value: Some(accessor_name.to_string()),
+        type_: SyntaxTreeNodeType::ElementAccess,
+        children: syntax_tree_node.children,
+    }
+}
+
+fn resolve_property_access(
+    syntax_tree_node: SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+) -> SyntaxTreeNode {
+    let property_name: String = syntax_tree_node.value.clone().unwrap();
+    let target_node: &SyntaxTreeNode = &syntax_tree_node.children[0];
+
+    if let Some(type_name) = find_type_reference(target_node, local_types) {
+        return create_literal_node(&format!("{}/{}", type_name, property_name));
+    }
+
+    let target_type: Option<String> = infer_type(target_node, local_types);
+
+    match (target_type.as_deref(), property_name.as_str()) {
+        (Some(type_name), "Count" | "Length")
+            if is_collection_type(type_name) || is_host_type(type_name) =>
+        {
+            create_call_node("count", syntax_tree_node.children)
+        }
+        (Some(type_name), "Keys") if is_dictionary_type(type_name) => {
+            create_call_node("keys", syntax_tree_node.children)
+        }
+        (Some(type_name), "Values") if is_dictionary_type(type_name) => {
+            create_call_node("vals", syntax_tree_node.children)
+        }
+        (Some(type_name), _) if is_host_type(type_name) => {
+            create_call_node(&format!(".{}", property_name), syntax_tree_node.children)
+        }
+        _ => syntax_tree_node,
+    }
+}
+
+fn resolve_method_call(
+    syntax_tree_node: SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+    member_scope: &mut MemberScope,
+) -> SyntaxTreeNode {
+    let method_name: String = syntax_tree_node.value.clone().unwrap();
+    let mut children: Vec<SyntaxTreeNode> = syntax_tree_node.children;
+
+    if member_scope.method_names.contains(&method_name) {
+        return create_call_node(&method_name, children);
+    }
+
+    if let Some(type_name) = find_type_reference(&children[0], local_types) {
+        let static_method_name: String = format!("{}/{}", type_name, method_name);
+        children.remove(0);
+        return create_call_node(&static_method_name, children);
+    }
+
+    let target_type: Option<String> = infer_type(&children[0], local_types);
+
+    match (target_type.as_deref(), method_name.as_str()) {
+        (Some(type_name), "ContainsKey") if is_dictionary_type(type_name) => {
+            create_call_node("contains?", children)
+        }
+        (Some(type_name), _)
+            if is_host_type(type_name)
+                || !(is_collection_type(type_name)
+                    || is_declared_type(type_name, &member_scope.type_names)) =>
+        {
+            create_call_node(&format!(".{}", method_name), children)
+        }
+        (None, _) if !matches!(children[0].type_, SyntaxTreeNodeType::Literal) => {
+            create_call_node(&format!(".{}", method_name), children)
+        }
+        _ => {
+            if !member_scope.flagged_names.contains(&method_name) {
+                if let Some(collision) =
+                    describe_core_collision(&method_name, member_scope.naming_overrides)
+                {
+                    member_scope.warnings.push(format!(
+                        "call to undeclared member method `{}` in namespace {} {}",
+                        method_name, member_scope.namespace_name, collision
+                    ));
+                    member_scope.flagged_names.insert(method_name.clone());
+                }
+            }
+
+            create_call_node(&method_name, children)
+        }
+    }
+}
+
+fn is_declared_type(type_name: &str, declared_type_names: &[String]) -> bool {
+    let base_type_name: &str = type_name
+        .trim_end_matches('?')
+        .split('<')
+        .next()
+        .unwrap_or_default();
+
+    declared_type_names
+        .iter()
+        .any(|declared_type_name| declared_type_name == base_type_name)
+}
+
+/// Host objects built with constructor arguments cannot be merged with a map of their
+/// initialized properties, so the properties are set through their setters instead.
+fn resolve_host_initializers(syntax_tree_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let is_host_construction: bool = match syntax_tree_node.children.split_first() {
+        Some((type_node, creation_nodes)) => {
+            matches!(type_node.type_, SyntaxTreeNodeType::Type)
+                && type_node
+                    .value
+                    .as_deref()
+                    .is_some_and(|type_name| !is_collection_type(type_name))
+                && creation_nodes.iter().any(|node| {
+                    !matches!(
+                        node.type_,
+                        SyntaxTreeNodeType::MemberInitializer | SyntaxTreeNodeType::Collection
+                    )
+                })
+                && creation_nodes
+                    .iter()
+                    .any(|node| matches!(node.type_, SyntaxTreeNodeType::MemberInitializer))
+                && creation_nodes.iter().all(|node| {
+                    !matches!(node.type_, SyntaxTreeNodeType::MemberInitializer)
+                        || node.value.is_some()
+                })
+        }
+        None => false,
+    };
+
+    if !is_host_construction {
+        return syntax_tree_node;
+    }
+
+    let (initializer_nodes, construction_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+        syntax_tree_node
+            .children
+            .into_iter()
+            .partition(|node| matches!(node.type_, SyntaxTreeNodeType::MemberInitializer));
+
+    let mut doto_children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: SyntaxTreeNodeType::ObjectCreation,
+        children: construction_nodes,
+    }]);
+    doto_children.extend(initializer_nodes.into_iter().map(|initializer_node| {
+        create_call_node(
+            &format!(".set{}", initializer_node.value.unwrap()),
+            initializer_node.children,
+        )
+    }));
+
+    create_call_node("doto", doto_children)
+}
+
+fn find_type_reference<'a>(
+    target_node: &'a SyntaxTreeNode,
+    local_types: &HashMap<String, String>,
+) -> Option<&'a str> {
+    match (&target_node.type_, target_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Literal, Some(name))
+            if is_type_name(name) && !local_types.contains_key(name) =>
+        {
+            Some(name)
+        }
+        _ => None,
+    }
+}
+
+fn is_type_name(name: &str) -> bool {
+    name.chars().next().is_some_and(char::is_uppercase)
+        && name
+            .chars()
+            .all(|c: char| char::is_alphanumeric(c) || c == '_')
+}
+
+pub(crate) fn is_list_type(type_name: &str) -> bool {
+    type_name.ends_with("[]")
+        || LIST_TYPE_PREFIXES
+            .iter()
+            .any(|prefix| type_name.starts_with(prefix))
+}
+
+pub(crate) fn is_dictionary_type(type_name: &str) -> bool {
+    DICTIONARY_TYPE_PREFIXES
+        .iter()
+        .any(|prefix| type_name.starts_with(prefix))
+}
+
+fn is_collection_type(type_name: &str) -> bool {
+    is_list_type(type_name) || is_dictionary_type(type_name) || is_set_type(type_name)
+}
+
+pub(crate) fn is_set_type(type_name: &str) -> bool {
+    SET_TYPE_PREFIXES
+        .iter()
+        .any(|prefix| type_name.starts_with(prefix))
+}
+
+fn is_host_type(type_name: &str) -> bool {
+    HOST_TYPES.contains(&type_name)
+}
@@ -1,2 +1,19 @@
+pub mod classes;
+pub mod clojure_core;
+pub mod exceptions;
+pub mod idioms;
+pub mod lambdas;
+pub mod linq;
+pub mod loops;
+pub mod members;
+pub mod mutation;
+pub mod naming;
+pub mod objects;
+pub mod patterns;
 pub mod prettifier;
+pub mod queries;
+pub mod rebinding;
+pub mod returns;
+pub mod switches;
+#[allow(clippy::module_inception)]
 pub mod transpiler;
@@ -0,0 +1,432 @@
+use crate::config::project_config::MutableStateFallback;
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::loops::collect_mutated_names;
+use crate::transpiler::transpiler::{convert_abstract_syntax_tree_to_code, find_assigned_value};
+
+const SWAPPABLE_OPERATORS: [&str; 8] = ["+", "-", "*", "/", "rem", "quot", "inc", "dec"];
+
+struct MutableStateForms {
+    constructor: &'static str,
+    reset: &'static str,
+    swap: &'static str,
+}
+
+pub fn apply_mutable_state_fallback(
+    abstract_syntax_tree: SyntaxTreeNode,
+    mutable_state_fallback: MutableStateFallback,
+) -> (SyntaxTreeNode, Vec<String>) {
+    let mut warnings: Vec<String> = vec![];
+    let abstract_syntax_tree: SyntaxTreeNode = rewrite_methods(
+        abstract_syntax_tree,
+        &choose_mutable_state_forms(mutable_state_fallback),
+        &mut warnings,
+    );
+
+    (abstract_syntax_tree, warnings)
+}
+
+fn choose_mutable_state_forms(mutable_state_fallback: MutableStateFallback) -> MutableStateForms {
+    match mutable_state_fallback {
+        MutableStateFallback::Volatile => MutableStateForms {
+            constructor: "volatile!",
+            reset: "vreset!",
+            swap: "vswap!",
+        },
+        MutableStateFallback::Atom => MutableStateForms {
+            constructor: "atom",
+            reset: "reset!",
+            swap: "swap!",
+        },
+    }
+}
+
+fn rewrite_methods(
+    syntax_tree_node: SyntaxTreeNode,
+    mutable_state_forms: &MutableStateForms,
+    warnings: &mut Vec<String>,
+) -> SyntaxTreeNode {
+    if !matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Method) {
+        return SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children: syntax_tree_node
+                .children
+                .into_iter()
+                .map(|child| rewrite_methods(child, mutable_state_forms, warnings))
+                .collect::<Vec<SyntaxTreeNode>>(),
+        };
+    }
+
+    let method_name: String = syntax_tree_node.value.clone().unwrap();
+
+    let mut declared_names: Vec<String> = vec![];
+    collect_declared_names(&syntax_tree_node, &mut declared_names);
+
+    let mut mutated_names: Vec<(String, &str)> = vec![];
+    for child in &syntax_tree_node.children {
+        collect_non_linear_mutations(child, false, &mut mutated_names);
+    }
+    mutated_names.retain(|(name, _)| declared_names.contains(name));
+
+    if mutated_names.is_empty() {
+        return syntax_tree_node;
+    }
+
+    for (name, reason) in &mutated_names {
+        warnings.push(format!(
+            "local `{}` in method {} is {} and was moved into {}",
+            name, method_name, reason, mutable_state_forms.constructor
+        ));
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+    for child in &syntax_tree_node.children {
+        for mutated_name in mutated_names
+            .iter()
+            .filter(|(name, _)| declares_name(child, name))
+        {
+            children.push(create_warning_comment(mutated_name, mutable_state_forms));
+        }
+
+        children.push(rewrite_mutable_locals(
+            child,
+            &mutated_names,
+            mutable_state_forms,
+        ));
+
+        if let (SyntaxTreeNodeType::MethodArgument, Some(argument_name)) =
+            (&child.type_, &child.value)
+        {
+            if let Some(mutated_name) = mutated_names.iter().find(|(name, _)| name == argument_name)
+            {
+                children.push(create_warning_comment(mutated_name, mutable_state_forms));
+                children.push(SyntaxTreeNode {
+                    value: Some(argument_name.clone()),
+                    type_: SyntaxTreeNodeType::Assignment,
+                    children: Vec::from([
+                        SyntaxTreeNode {
+                            value: Some(String::from("var")),
+                            type_: SyntaxTreeNodeType::Type,
+                            children: vec![],
+                        },
+                        create_call_node(
+                            mutable_state_forms.constructor,
+                            Vec::from([create_literal_node(argument_name)]),
+                        ),
+                    ]),
+                });
+            }
+        }
+    }
+
+    let (mut signature_nodes, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+        children.into_iter().partition(|child| {
+            matches!(
+                child.type_,
+                SyntaxTreeNodeType::MethodArgument | SyntaxTreeNodeType::Type
+            )
+        });
+    signature_nodes.extend(body_nodes);
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: signature_nodes,
+    }
+}
+
+fn collect_declared_names(syntax_tree_node: &SyntaxTreeNode, declared_names: &mut Vec<String>) {
+    match (&syntax_tree_node.type_, &syntax_tree_node.value) {
+        (SyntaxTreeNodeType::MethodArgument, Some(name)) => declared_names.push(name.clone()),
+        (SyntaxTreeNodeType::Assignment, Some(name))
+            if syntax_tree_node
+                .children
+                .iter()
+                .any(|child| matches!(child.type_, SyntaxTreeNodeType::Type)) =>
+        {
+            declared_names.push(name.clone())
+        }
+        _ => {}
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_declared_names(child, declared_names);
+    }
+}
+
+fn collect_non_linear_mutations(
+    syntax_tree_node: &SyntaxTreeNode,
+    is_expression: bool,
+    mutated_names: &mut Vec<(String, &'static str)>,
+) {
+    if matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Lambda) {
+        let parameter_names: Vec<&str> = syntax_tree_node
+            .children
+            .iter()
+            .filter(|child| matches!(child.type_, SyntaxTreeNodeType::MethodArgument))
+            .filter_map(|child| child.value.as_deref())
+            .collect::<Vec<&str>>();
+
+        for name in collect_mutated_names(&syntax_tree_node.children) {
+            if !parameter_names.contains(&name.as_str())
+                && !mutated_names
+                    .iter()
+                    .any(|(mutated_name, _)| *mutated_name == name)
+            {
+                mutated_names.push((name, "captured and mutated by a lambda"));
+            }
+        }
+
+        for child in &syntax_tree_node.children {
+            collect_non_linear_mutations(child, false, mutated_names);
+        }
+        return;
+    }
+
+    let expression_child_indices: Vec<usize> =
+        match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+            (SyntaxTreeNodeType::Assignment, Some(name)) => {
+                if is_expression
+                    && !mutated_names
+                        .iter()
+                        .any(|(mutated_name, _)| mutated_name == name)
+                {
+                    mutated_names.push((name.to_string(), "assigned inside an expression"));
+                }
+                (0..syntax_tree_node.children.len()).collect::<Vec<usize>>()
+            }
+            (SyntaxTreeNodeType::Assignment, None) => vec![],
+            (SyntaxTreeNodeType::Branch, Some("if")) | (SyntaxTreeNodeType::Switch, _) => {
+                Vec::from([0])
+            }
+            (SyntaxTreeNodeType::Loop, Some("for")) => Vec::from([1]),
+            (SyntaxTreeNodeType::Loop, Some("foreach")) => Vec::from([1]),
+            (SyntaxTreeNodeType::Loop, _) => Vec::from([0]),
+            (
+                SyntaxTreeNodeType::Method
+                | SyntaxTreeNodeType::Branch
+                | SyntaxTreeNodeType::SwitchSection
+                | SyntaxTreeNodeType::Block
+                | SyntaxTreeNodeType::Try
+                | SyntaxTreeNodeType::Catch
+                | SyntaxTreeNodeType::Using,
+                _,
+            ) if !is_expression => vec![],
+            _ => (0..syntax_tree_node.children.len()).collect::<Vec<usize>>(),
+        };
+
+    for (index, child) in syntax_tree_node.children.iter().enumerate() {
+        collect_non_linear_mutations(
+            child,
+            is_expression || expression_child_indices.contains(&index),
+            mutated_names,
+        );
+    }
+}
+
+fn rewrite_mutable_locals(
+    syntax_tree_node: &SyntaxTreeNode,
+    mutated_names: &[(String, &str)],
+    mutable_state_forms: &MutableStateForms,
+) -> SyntaxTreeNode {
+    let mutated_name: Option<&(String, &str)> = syntax_tree_node
+        .value
+        .as_ref()
+        .and_then(|value| mutated_names.iter().find(|(name, _)| name == value));
+
+    match (&syntax_tree_node.type_, mutated_name) {
+        (SyntaxTreeNodeType::Literal, Some((name, _))) => {
+            create_literal_node(&format!("@{}", name))
+        }
+        (SyntaxTreeNodeType::Assignment, Some((name, _))) => {
+            create_mutable_assignment(syntax_tree_node, name, mutated_names, mutable_state_forms)
+        }
+        _ => {
+            let accepts_statements: bool = matches!(
+                syntax_tree_node.type_,
+                SyntaxTreeNodeType::Method
+                    | SyntaxTreeNodeType::Branch
+                    | SyntaxTreeNodeType::Loop
+                    | SyntaxTreeNodeType::SwitchSection
+                    | SyntaxTreeNodeType::Block
+                    | SyntaxTreeNodeType::Catch
+                    | SyntaxTreeNodeType::Using
+                    | SyntaxTreeNodeType::Lambda
+            );
+
+            let mut children: Vec<SyntaxTreeNode> = vec![];
+            for child in &syntax_tree_node.children {
+                if accepts_statements {
+                    for mutated_name in mutated_names
+                        .iter()
+                        .filter(|(name, _)| declares_name(child, name))
+                    {
+                        children.push(create_warning_comment(mutated_name, mutable_state_forms));
+                    }
+                }
+
+                children.push(rewrite_mutable_locals(
+                    child,
+                    mutated_names,
+                    mutable_state_forms,
+                ));
+            }
+
+            SyntaxTreeNode {
+                value: syntax_tree_node.value.clone(),
+                type_: syntax_tree_node.type_.clone(),
+                children,
+            }
+        }
+    }
+}
+
+/// Holds `names` in atoms within `syntax_tree_node`: reads become derefs and assignments become
+/// `swap!`/`reset!` calls. Used for static fields, whose state outlives a single call.
+pub(crate) fn hold_names_in_atoms(
+    syntax_tree_node: &SyntaxTreeNode,
+    names: &[String],
+) -> SyntaxTreeNode {
+    let mutated_names: Vec<(String, &str)> = names
+        .iter()
+        .map(|name| (name.clone(), "it is a static field"))
+        .collect::<Vec<(String, &str)>>();
+
+    rewrite_mutable_locals(
+        syntax_tree_node,
+        &mutated_names,
+        &choose_mutable_state_forms(MutableStateFallback::Atom),
+    )
+}
+
+fn create_mutable_assignment(
+    assignment_node: &SyntaxTreeNode,
+    name: &str,
+    mutated_names: &[(String, &str)],
+    mutable_state_forms: &MutableStateForms,
+) -> SyntaxTreeNode {
+    let value_node: &SyntaxTreeNode = find_assigned_value(assignment_node);
+
+    if let Some(type_node) = assignment_node
+        .children
+        .iter()
+        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+    {
+        return SyntaxTreeNode {
+            value: Some(name.to_string()),
+            type_: SyntaxTreeNodeType::Assignment,
+            children: Vec::from([
+                type_node.clone(),
+                create_call_node(
+                    mutable_state_forms.constructor,
+                    Vec::from([rewrite_mutable_locals(
+                        value_node,
+                        mutated_names,
+                        mutable_state_forms,
+                    )]),
+                ),
+            ]),
+        };
+    }
+
+    let is_swap: bool = matches!(value_node.type_, SyntaxTreeNodeType::Expression)
+        && value_node
+            .value
+            .as_deref()
+            .is_some_and(|operator| SWAPPABLE_OPERATORS.contains(&operator))
+        && value_node.children.first().is_some_and(|operand| {
+            matches!(operand.type_, SyntaxTreeNodeType::Literal)
+                && operand.value.as_deref() == Some(name)
+        });
+
+    let mut arguments: Vec<SyntaxTreeNode> = Vec::from([create_literal_node(name)]);
+    match is_swap {
+        true => {
+            arguments.push(create_literal_node(value_node.value.as_deref().unwrap()));
+            arguments.extend(value_node.children[1..].iter().map(|operand| {
+                rewrite_mutable_locals(operand, mutated_names, mutable_state_forms)
+            }));
+            create_call_node(mutable_state_forms.swap, arguments)
+        }
+        false => {
+            arguments.push(rewrite_mutable_locals(
+                value_node,
+                mutated_names,
+                mutable_state_forms,
+            ));
+            create_call_node(mutable_state_forms.reset, arguments)
+        }
+    }
+}
+
+fn declares_name(syntax_tree_node: &SyntaxTreeNode, name: &str) -> bool {
+    match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Assignment, Some(assigned_name)) => {
+            assigned_name == name
+                && syntax_tree_node
+                    .children
+                    .iter()
+                    .any(|child| matches!(child.type_, SyntaxTreeNodeType::Type))
+        }
+        (SyntaxTreeNodeType::Assignment, None) => syntax_tree_node
+            .children
+            .iter()
+            .any(|child| declares_name(child, name)),
+        _ => false,
+    }
+}
+
+fn create_warning_comment(
+    mutated_name: &(String, &str),
+    mutable_state_forms: &MutableStateForms,
+) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(format!(
+            " WARNING: `{}` is {}, so it is held in {} instead of a let binding; review by hand",
+            mutated_name.0, mutated_name.1, mutable_state_forms.constructor
+        )),
+        type_: SyntaxTreeNodeType::Comment,
+        children: vec![],
+    }
+}
+
+/// A postfix `++`/`--` evaluates to the value before the step, while `vswap!` and `swap!` return
+/// the value after it, so the old value is captured first.
+pub(crate) fn convert_postfix_step_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let step_node: &SyntaxTreeNode = &syntax_tree_node.children[0];
+
+    match (step_node.value.as_deref(), step_node.children.first()) {
+        (Some("vswap!" | "swap!"), Some(holder_node))
+            if matches!(step_node.type_, SyntaxTreeNodeType::Expression)
+                && matches!(holder_node.type_, SyntaxTreeNodeType::Literal) =>
+        {
+            let holder_name: &str = holder_node.value.as_deref().unwrap();
+            let previous_name: String = format!("{}-before", holder_name);
+
+            format!(
+                "(let [{} @{}]\n{}\n{})",
+                previous_name,
+                holder_name,
+                convert_abstract_syntax_tree_to_code(step_node),
+                previous_name
+            )
+        }
+        _ => convert_abstract_syntax_tree_to_code(step_node),
+    }
+}
+
+pub(crate) fn create_call_node(function_name: &str, arguments: Vec<SyntaxTreeNode>) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(function_name.to_string()),
+        type_: SyntaxTreeNodeType::Expression,
+        children: arguments,
+    }
+}
+
+pub(crate) fn create_literal_node(value: &str) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(value.to_string()),
+        type_: SyntaxTreeNodeType::Literal,
+        children: vec![],
+    }
+}
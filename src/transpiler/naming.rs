@@ -0,0 +1,175 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use std::collections::HashMap;
+
+pub fn apply_naming_conventions(
+    abstract_syntax_tree: SyntaxTreeNode,
+    naming_overrides: &HashMap<String, String>,
+) -> SyntaxTreeNode {
+    let mut method_names: HashMap<String, String> = HashMap::new();
+    collect_method_names(&abstract_syntax_tree, &mut method_names);
+
+    rename_syntax_tree_node(abstract_syntax_tree, &method_names, naming_overrides)
+}
+
+fn collect_method_names(
+    syntax_tree_node: &SyntaxTreeNode,
+    method_names: &mut HashMap<String, String>,
+) {
+    if matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Method) {
+        let method_name: String = syntax_tree_node.value.clone().unwrap();
+        let returns_boolean: bool = syntax_tree_node.children.iter().any(|child| {
+            matches!(child.type_, SyntaxTreeNodeType::Type)
+                && child.value.as_deref() == Some("bool")
+        });
+
+        let converted_name: String = match returns_boolean {
+            true => to_predicate_name(&method_name),
+            false => to_kebab_case(&method_name),
+        };
+
+        method_names.insert(method_name, converted_name);
+    }
+
+    for child in &syntax_tree_node.children {
+        collect_method_names(child, method_names);
+    }
+}
+
+fn rename_syntax_tree_node(
+    syntax_tree_node: SyntaxTreeNode,
+    method_names: &HashMap<String, String>,
+    naming_overrides: &HashMap<String, String>,
+) -> SyntaxTreeNode {
+    let value: Option<String> = match (&syntax_tree_node.type_, syntax_tree_node.value) {
+        (_, None) => None,
+        (
+            SyntaxTreeNodeType::Comment
+            | SyntaxTreeNodeType::Type
+            | SyntaxTreeNodeType::Class
+            | SyntaxTreeNodeType::Interface,
+            value,
+        ) => value,
+        (SyntaxTreeNodeType::Namespace, Some(name)) => {
+            Some(convert_namespace_name(&name, naming_overrides))
+        }
+        (_, Some(name)) if is_identifier(&name) => {
+            Some(convert_name(&name, method_names, naming_overrides))
+        }
+        (_, Some(name)) if name.strip_prefix('@').is_some_and(is_identifier) => Some(format!(
+            "@{}",
+            convert_name(&name[1..], method_names, naming_overrides)
+        )),
+        (_, value) => value,
+    };
+
+    SyntaxTreeNode {
+        value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| rename_syntax_tree_node(child, method_names, naming_overrides))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
+
+fn convert_name(
+    name: &str,
+    method_names: &HashMap<String, String>,
+    naming_overrides: &HashMap<String, String>,
+) -> String {
+    match (naming_overrides.get(name), method_names.get(name)) {
+        (Some(overridden_name), _) => choose_overridden_name(name, overridden_name),
+        (None, Some(method_name)) => method_name.clone(),
+        (None, None) => to_kebab_case(name),
+    }
+}
+
+fn choose_overridden_name(original_name: &str, overridden_name: &str) -> String {
+    match overridden_name.is_empty() {
+        true => original_name.to_string(),
+        false => overridden_name.to_string(),
+    }
+}
+
+fn is_identifier(text_value: &str) -> bool {
+    text_value
+        .chars()
+        .next()
+        .is_some_and(|c: char| char::is_alphabetic(c) || c == '_')
+        && text_value
+            .chars()
+            .all(|c: char| char::is_alphanumeric(c) || c == '_')
+}
+
+pub(crate) fn to_kebab_case(name: &str) -> String {
+    if name.chars().all(|c: char| c == '_') {
+        return String::from("_");
+    }
+
+    let name: &str = name.trim_start_matches('_');
+
+    if name
+        .chars()
+        .all(|c: char| !char::is_lowercase(c) || c == '_')
+    {
+        return name.to_lowercase().replace('_', "-");
+    }
+
+    let characters: Vec<char> = name.chars().collect::<Vec<char>>();
+    let mut output: String = String::new();
+
+    for (index, character) in characters.iter().enumerate() {
+        if *character == '_' {
+            if !output.is_empty() && !output.ends_with('-') {
+                output.push('-');
+            }
+            continue;
+        }
+
+        if char::is_uppercase(*character) && index > 0 && !output.ends_with('-') {
+            let previous_character: char = characters[index - 1];
+            let next_is_lowercase: bool = characters
+                .get(index + 1)
+                .is_some_and(|c: &char| char::is_lowercase(*c));
+
+            if char::is_lowercase(previous_character)
+                || char::is_numeric(previous_character)
+                || (char::is_uppercase(previous_character) && next_is_lowercase)
+            {
+                output.push('-');
+            }
+        }
+
+        output.extend(character.to_lowercase());
+    }
+
+    output
+}
+
+fn to_predicate_name(name: &str) -> String {
+    let kebab_case_name: String = to_kebab_case(name);
+
+    match kebab_case_name.strip_prefix("is-") {
+        Some(stripped_name) => format!("{}?", stripped_name),
+        None if kebab_case_name.starts_with("has-") => format!("{}?", kebab_case_name),
+        None => kebab_case_name,
+    }
+}
+
+pub(crate) fn convert_namespace_name(
+    name: &str,
+    naming_overrides: &HashMap<String, String>,
+) -> String {
+    match naming_overrides.get(name) {
+        Some(overridden_name) => choose_overridden_name(name, overridden_name),
+        None => to_namespace_name(name),
+    }
+}
+
+fn to_namespace_name(name: &str) -> String {
+    name.split('.')
+        .map(to_kebab_case)
+        .collect::<Vec<String>>()
+        .join(".")
+}
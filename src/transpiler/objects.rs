@@ -0,0 +1,219 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::exceptions;
+use crate::transpiler::members::{is_dictionary_type, is_list_type, is_set_type};
+use crate::transpiler::transpiler::convert_abstract_syntax_tree_to_code;
+
+pub(crate) fn convert_object_creation_syntax_tree_node_to_code(
+    syntax_tree_node: &SyntaxTreeNode,
+) -> String {
+    let (type_node, creation_nodes): (Option<&SyntaxTreeNode>, &[SyntaxTreeNode]) =
+        match syntax_tree_node.children.split_first() {
+            Some((first_node, remaining_nodes))
+                if matches!(first_node.type_, SyntaxTreeNodeType::Type) =>
+            {
+                (Some(first_node), remaining_nodes)
+            }
+            _ => (None, syntax_tree_node.children.as_slice()),
+        };
+
+    let type_name: Option<&str> = type_node.and_then(|type_node| type_node.value.as_deref());
+
+    let argument_codes: Vec<String> = creation_nodes
+        .iter()
+        .filter(|node| !is_initializer_node(node))
+        .map(convert_abstract_syntax_tree_to_code)
+        .collect::<Vec<String>>();
+    let member_nodes: Vec<&SyntaxTreeNode> = creation_nodes
+        .iter()
+        .filter(|node| matches!(node.type_, SyntaxTreeNodeType::MemberInitializer))
+        .collect::<Vec<&SyntaxTreeNode>>();
+    let element_nodes: Option<&[SyntaxTreeNode]> = creation_nodes
+        .iter()
+        .find(|node| matches!(node.type_, SyntaxTreeNodeType::Collection))
+        .filter(|node| is_initializer_node(node))
+        .map(|node| node.children.as_slice());
+
+    match type_name {
+        Some(type_name) if type_name.starts_with(':') => {
+            exceptions::convert_exception_info_creation_to_code(type_name, &argument_codes)
+        }
+        Some(type_name) if type_name.ends_with("[]") || is_list_type(type_name) => {
+            convert_list_creation_to_code(type_name, &argument_codes, element_nodes)
+        }
+        Some(type_name) if is_set_type(type_name) => {
+            convert_set_creation_to_code(type_name, &argument_codes, element_nodes)
+        }
+        Some(type_name) if is_dictionary_type(type_name) => convert_dictionary_creation_to_code(
+            type_name,
+            &argument_codes,
+            &member_nodes,
+            element_nodes,
+        ),
+        _ if !member_nodes.is_empty() => {
+            let map_code: String = convert_member_initializers_to_code(&member_nodes);
+
+            match (type_name, argument_codes.is_empty()) {
+                (Some(type_name), false) => format!(
+                    "(merge {} {})",
+                    convert_constructor_call_to_code(type_name, &argument_codes),
+                    map_code
+                ),
+                _ => map_code,
+            }
+        }
+        Some(type_name) => {
+            let constructor_code: String =
+                convert_constructor_call_to_code(type_name, &argument_codes);
+
+            match element_nodes {
+                Some(element_nodes) => format!(
+                    "(into {} [{}])",
+                    constructor_code,
+                    convert_elements_to_code(element_nodes)
+                ),
+                None => constructor_code,
+            }
+        }
+        None => match element_nodes {
+            Some(element_nodes) => format!("[{}]", convert_elements_to_code(element_nodes)),
+            None => String::from("{}"),
+        },
+    }
+}
+
+fn is_initializer_node(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::MemberInitializer => true,
+        SyntaxTreeNodeType::Collection => syntax_tree_node.value.as_deref() == Some("initializer"),
+        _ => false,
+    }
+}
+
+fn convert_list_creation_to_code(
+    type_name: &str,
+    argument_codes: &[String],
+    element_nodes: Option<&[SyntaxTreeNode]>,
+) -> String {
+    match (element_nodes, argument_codes) {
+        (Some(element_nodes), _) => format!("[{}]", convert_elements_to_code(element_nodes)),
+        (None, [size_code]) if type_name.ends_with("[]") => format!(
+            "(vec (repeat {} {}))",
+            size_code,
+            find_default_value(type_name.trim_end_matches("[]"))
+        ),
+        (None, [source_code]) if !is_numeric_literal(source_code) => {
+            format!("(vec {})", source_code)
+        }
+        _ => String::from("[]"),
+    }
+}
+
+fn convert_set_creation_to_code(
+    type_name: &str,
+    argument_codes: &[String],
+    element_nodes: Option<&[SyntaxTreeNode]>,
+) -> String {
+    let is_sorted: bool = type_name.starts_with("Sorted");
+
+    match (element_nodes, argument_codes, is_sorted) {
+        (Some(element_nodes), _, true) => {
+            format!("(sorted-set {})", convert_elements_to_code(element_nodes))
+        }
+        (Some(element_nodes), _, false) => {
+            format!("#{{{}}}", convert_elements_to_code(element_nodes))
+        }
+        (None, [source_code], true) => format!("(into (sorted-set) {})", source_code),
+        (None, [source_code], false) => format!("(set {})", source_code),
+        (None, _, true) => String::from("(sorted-set)"),
+        (None, _, false) => String::from("#{}"),
+    }
+}
+
+fn convert_dictionary_creation_to_code(
+    type_name: &str,
+    argument_codes: &[String],
+    member_nodes: &[&SyntaxTreeNode],
+    element_nodes: Option<&[SyntaxTreeNode]>,
+) -> String {
+    let mut entry_codes: Vec<String> = member_nodes
+        .iter()
+        .map(|member_node| convert_member_initializer_to_code(member_node))
+        .collect::<Vec<String>>();
+    entry_codes.extend(
+        element_nodes
+            .unwrap_or_default()
+            .iter()
+            .map(|entry_node| convert_elements_to_code(&entry_node.children)),
+    );
+
+    let is_sorted: bool = type_name.starts_with("Sorted");
+
+    match (entry_codes.is_empty(), argument_codes, is_sorted) {
+        (true, [source_code], true) if !is_numeric_literal(source_code) => {
+            format!("(into (sorted-map) {})", source_code)
+        }
+        (true, [source_code], false) if !is_numeric_literal(source_code) => {
+            format!("(into {{}} {})", source_code)
+        }
+        (_, _, true) if entry_codes.is_empty() => String::from("(sorted-map)"),
+        (_, _, true) => format!("(sorted-map {})", entry_codes.join(" ")),
+        (_, _, false) => format!("{{{}}}", entry_codes.join(" ")),
+    }
+}
+
+fn convert_member_initializers_to_code(member_nodes: &[&SyntaxTreeNode]) -> String {
+    format!(
+        "{{{}}}",
+        member_nodes
+            .iter()
+            .map(|member_node| convert_member_initializer_to_code(member_node))
+            .collect::<Vec<String>>()
+            .join(" ")
+    )
+}
+
+fn convert_member_initializer_to_code(member_node: &SyntaxTreeNode) -> String {
+    match member_node.value.as_deref() {
+        Some(member_name) => format!(
+            ":{} {}",
+            member_name,
+            convert_abstract_syntax_tree_to_code(&member_node.children[0])
+        ),
+        None => convert_elements_to_code(&member_node.children),
+    }
+}
+
+fn convert_constructor_call_to_code(type_name: &str, argument_codes: &[String]) -> String {
+    let class_name: &str = match type_name.split_once('<') {
+        Some((class_name, _)) => class_name,
+        None => type_name,
+    };
+
+    match argument_codes.is_empty() {
+        true => format!("({}.)", class_name),
+        false => format!("({}. {})", class_name, argument_codes.join(" ")),
+    }
+}
+
+fn convert_elements_to_code(element_nodes: &[SyntaxTreeNode]) -> String {
+    element_nodes
+        .iter()
+        .map(convert_abstract_syntax_tree_to_code)
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub(crate) fn find_default_value(element_type_name: &str) -> &str {
+    match element_type_name {
+        "int" | "long" | "short" | "byte" => "0",
+        "double" | "float" | "decimal" => "0.0",
+        "bool" => "false",
+        _ => "nil",
+    }
+}
+
+fn is_numeric_literal(code: &str) -> bool {
+    code.chars()
+        .next()
+        .is_some_and(|c: char| char::is_numeric(c))
+}
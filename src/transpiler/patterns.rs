@@ -0,0 +1,412 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::transpiler::{convert_abstract_syntax_tree_to_code, find_assigned_value};
+
+const PATTERN_SUBJECT_NAME: &str = "pattern-value";
+
+#[derive(Default)]
+pub(crate) struct PatternCode {
+    pub hoisted_bindings: Vec<(String, String)>,
+    pub condition_code: String,
+    pub bindings: Vec<(String, String)>,
+}
+
+pub(crate) fn convert_pattern_to_code(
+    subject_code: &str,
+    pattern_node: &SyntaxTreeNode,
+) -> PatternCode {
+    let mut condition_codes: Vec<String> = vec![];
+    let mut bindings: Vec<(String, String)> = vec![];
+    collect_pattern_checks(
+        subject_code,
+        pattern_node,
+        &mut condition_codes,
+        &mut bindings,
+    );
+
+    PatternCode {
+        hoisted_bindings: vec![],
+        condition_code: join_condition_codes(condition_codes),
+        bindings,
+    }
+}
+
+pub(crate) fn convert_condition_to_code(condition_node: &SyntaxTreeNode) -> PatternCode {
+    convert_condition_with_subject_count(condition_node, &mut 0)
+}
+
+fn convert_condition_with_subject_count(
+    condition_node: &SyntaxTreeNode,
+    subject_count: &mut usize,
+) -> PatternCode {
+    match (&condition_node.type_, condition_node.value.as_deref()) {
+        (SyntaxTreeNodeType::PatternMatch, _) => {
+            let subject_node: &SyntaxTreeNode = &condition_node.children[0];
+
+            match subject_node.type_ {
+                SyntaxTreeNodeType::Literal => convert_pattern_to_code(
+                    &convert_abstract_syntax_tree_to_code(subject_node),
+                    &condition_node.children[1],
+                ),
+                _ => {
+                    *subject_count += 1;
+                    let subject_name: String = match subject_count {
+                        1 => String::from(PATTERN_SUBJECT_NAME),
+                        _ => format!("{}-{}", PATTERN_SUBJECT_NAME, subject_count),
+                    };
+
+                    let mut pattern_code: PatternCode =
+                        convert_pattern_to_code(&subject_name, &condition_node.children[1]);
+                    pattern_code.hoisted_bindings.push((
+                        subject_name,
+                        convert_abstract_syntax_tree_to_code(subject_node),
+                    ));
+                    pattern_code
+                }
+            }
+        }
+        (SyntaxTreeNodeType::Expression, Some("and")) if contains_pattern_match(condition_node) => {
+            let left_code: PatternCode =
+                convert_condition_with_subject_count(&condition_node.children[0], subject_count);
+            let right_code: PatternCode =
+                convert_condition_with_subject_count(&condition_node.children[1], subject_count);
+
+            let mut hoisted_bindings: Vec<(String, String)> = left_code.hoisted_bindings;
+            hoisted_bindings.extend(right_code.hoisted_bindings);
+
+            let mut bindings: Vec<(String, String)> = left_code.bindings.clone();
+            bindings.extend(right_code.bindings);
+
+            PatternCode {
+                hoisted_bindings,
+                condition_code: format!(
+                    "(and {} {})",
+                    left_code.condition_code,
+                    wrap_in_bindings(&left_code.bindings, right_code.condition_code)
+                ),
+                bindings,
+            }
+        }
+        (SyntaxTreeNodeType::Assignment, Some(bound_name)) => PatternCode {
+            hoisted_bindings: Vec::from([(
+                bound_name.to_string(),
+                convert_abstract_syntax_tree_to_code(find_assigned_value(condition_node)),
+            )]),
+            condition_code: bound_name.to_string(),
+            bindings: vec![],
+        },
+        _ => PatternCode {
+            hoisted_bindings: vec![],
+            condition_code: convert_abstract_syntax_tree_to_code(condition_node),
+            bindings: vec![],
+        },
+    }
+}
+
+pub(crate) fn convert_pattern_match_syntax_tree_node_to_code(
+    syntax_tree_node: &SyntaxTreeNode,
+) -> String {
+    let pattern_code: PatternCode = convert_condition_to_code(syntax_tree_node);
+
+    match pattern_code.hoisted_bindings.is_empty() {
+        true => pattern_code.condition_code,
+        false => format!(
+            "(let [{}]\n{})",
+            create_bindings_code(&pattern_code.hoisted_bindings),
+            pattern_code.condition_code
+        ),
+    }
+}
+
+pub(crate) fn wrap_in_bindings(bindings: &[(String, String)], body_code: String) -> String {
+    match bindings.is_empty() {
+        true => body_code,
+        false => format!("(let [{}]\n{})", create_bindings_code(bindings), body_code),
+    }
+}
+
+pub(crate) fn create_bindings_code(bindings: &[(String, String)]) -> String {
+    bindings
+        .iter()
+        .map(|(name, value_code)| format!("{} {}", name, value_code))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+pub(crate) fn convert_constant_to_code(constant_node: &SyntaxTreeNode) -> String {
+    match (&constant_node.type_, constant_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Literal, Some(value)) if is_enum_member(value) => {
+            let (type_name, member_name): (&str, &str) = value.rsplit_once('.').unwrap();
+            format!("{}/{}", type_name, member_name)
+        }
+        (SyntaxTreeNodeType::Expression, Some("-")) if constant_node.children.len() == 1 => {
+            format!("-{}", convert_constant_to_code(&constant_node.children[0]))
+        }
+        _ => convert_abstract_syntax_tree_to_code(constant_node),
+    }
+}
+
+/// Whether a constant names a var rather than a literal value, so it cannot be a `case` label.
+pub(crate) fn is_symbolic_constant(constant_node: &SyntaxTreeNode) -> bool {
+    match (&constant_node.type_, constant_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Literal, Some(value)) => {
+            is_enum_member(value)
+                || (value.starts_with(|c: char| c.is_alphabetic() || c == '_')
+                    && !matches!(value, "true" | "false" | "null"))
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn is_constant_literal(constant_node: &SyntaxTreeNode) -> bool {
+    match (&constant_node.type_, constant_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Literal, Some(value)) => {
+            value.starts_with(['"', '\\'])
+                || value
+                    .trim_start_matches('-')
+                    .starts_with(|c: char| c.is_ascii_digit())
+                || matches!(value, "true" | "false" | "null")
+                || is_enum_member(value)
+        }
+        (SyntaxTreeNodeType::Expression, Some("-")) => matches!(
+            constant_node.children.as_slice(),
+            [negated_node] if is_constant_literal(negated_node)
+        ),
+        _ => false,
+    }
+}
+
+fn collect_pattern_checks(
+    subject_code: &str,
+    pattern_node: &SyntaxTreeNode,
+    condition_codes: &mut Vec<String>,
+    bindings: &mut Vec<(String, String)>,
+) {
+    let capture_node: Option<&SyntaxTreeNode> = pattern_node
+        .children
+        .iter()
+        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Literal));
+    let type_node: Option<&SyntaxTreeNode> = pattern_node
+        .children
+        .iter()
+        .find(|child| matches!(child.type_, SyntaxTreeNodeType::Type));
+
+    match pattern_node.value.as_deref() {
+        Some("discard") => {}
+        Some("var") => {}
+        Some("type") => {
+            condition_codes.push(convert_type_check_to_code(type_node.unwrap(), subject_code))
+        }
+        Some("constant") => condition_codes.push(match pattern_node.children[0].value.as_deref() {
+            Some("null") => format!("(nil? {})", subject_code),
+            _ => format!(
+                "(= {} {})",
+                subject_code,
+                convert_constant_to_code(&pattern_node.children[0])
+            ),
+        }),
+        Some(operator @ ("<" | "<=" | ">" | ">=")) => condition_codes.push(format!(
+            "({} {} {})",
+            operator,
+            subject_code,
+            convert_abstract_syntax_tree_to_code(&pattern_node.children[0])
+        )),
+        Some("not") => {
+            let negated_node: &SyntaxTreeNode = &pattern_node.children[0];
+            condition_codes.push(
+                match (
+                    negated_node.value.as_deref(),
+                    negated_node
+                        .children
+                        .first()
+                        .and_then(|c| c.value.as_deref()),
+                ) {
+                    (Some("constant"), Some("null")) => format!("(some? {})", subject_code),
+                    _ => format!(
+                        "(not {})",
+                        convert_pattern_to_code(subject_code, negated_node).condition_code
+                    ),
+                },
+            );
+        }
+        Some("and") => {
+            for child in &pattern_node.children {
+                collect_pattern_checks(subject_code, child, condition_codes, bindings);
+            }
+        }
+        Some("or") => condition_codes.push(format!(
+            "(or {})",
+            pattern_node
+                .children
+                .iter()
+                .map(|child| convert_pattern_to_code(subject_code, child).condition_code)
+                .collect::<Vec<String>>()
+                .join(" ")
+        )),
+        Some("property") => {
+            condition_codes.push(match type_node {
+                Some(type_node) => convert_type_check_to_code(type_node, subject_code),
+                None => format!("(some? {})", subject_code),
+            });
+
+            for property_node in pattern_node
+                .children
+                .iter()
+                .filter(|child| matches!(child.type_, SyntaxTreeNodeType::PatternProperty))
+            {
+                collect_pattern_checks(
+                    &format!(
+                        "(:{} {})",
+                        property_node.value.clone().unwrap(),
+                        subject_code
+                    ),
+                    &property_node.children[0],
+                    condition_codes,
+                    bindings,
+                );
+            }
+        }
+        Some("positional") => {
+            if let Some(type_node) = type_node {
+                condition_codes.push(convert_type_check_to_code(type_node, subject_code));
+            }
+
+            for (index, element_node) in pattern_node
+                .children
+                .iter()
+                .filter(|child| matches!(child.type_, SyntaxTreeNodeType::Pattern))
+                .enumerate()
+            {
+                collect_pattern_checks(
+                    &format!("(nth {} {})", subject_code, index),
+                    element_node,
+                    condition_codes,
+                    bindings,
+                );
+            }
+        }
+        Some("list") => collect_list_pattern_checks(
+            subject_code,
+            &pattern_node.children,
+            condition_codes,
+            bindings,
+        ),
+        _ => panic!(
+            "Unable to convert pattern {} to code",
+            pattern_node.value.clone().unwrap_or_default()
+        ),
+    }
+
+    let capture_node: Option<&SyntaxTreeNode> = match pattern_node.value.as_deref() {
+        Some("var" | "type" | "property" | "positional") => capture_node,
+        _ => None,
+    };
+
+    if let Some(capture_node) = capture_node {
+        bindings.push((
+            capture_node.value.clone().unwrap(),
+            subject_code.to_string(),
+        ));
+    }
+}
+
+fn collect_list_pattern_checks(
+    subject_code: &str,
+    element_nodes: &[SyntaxTreeNode],
+    condition_codes: &mut Vec<String>,
+    bindings: &mut Vec<(String, String)>,
+) {
+    let slice_index: Option<usize> = element_nodes
+        .iter()
+        .position(|node| node.value.as_deref() == Some("slice"));
+    let fixed_element_count: usize = element_nodes.len() - slice_index.map_or(0, |_| 1);
+
+    condition_codes.push(format!("(sequential? {})", subject_code));
+    condition_codes.push(match slice_index {
+        Some(_) => format!("(>= (count {}) {})", subject_code, fixed_element_count),
+        None => format!("(= (count {}) {})", subject_code, fixed_element_count),
+    });
+
+    for (index, element_node) in element_nodes.iter().enumerate() {
+        match slice_index {
+            Some(slice_index) if index == slice_index => {
+                let trailing_count: usize = element_nodes.len() - slice_index - 1;
+                let slice_code: String = match (slice_index, trailing_count) {
+                    (0, 0) => subject_code.to_string(),
+                    (leading_count, 0) => format!("(drop {} {})", leading_count, subject_code),
+                    (0, trailing_count) => {
+                        format!("(drop-last {} {})", trailing_count, subject_code)
+                    }
+                    (leading_count, trailing_count) => format!(
+                        "(drop-last {} (drop {} {}))",
+                        trailing_count, leading_count, subject_code
+                    ),
+                };
+
+                if let Some(slice_pattern_node) = element_node.children.first() {
+                    collect_pattern_checks(
+                        &slice_code,
+                        slice_pattern_node,
+                        condition_codes,
+                        bindings,
+                    );
+                }
+            }
+            Some(slice_index) if index > slice_index => collect_pattern_checks(
+                &format!(
+                    "(nth {} (- (count {}) {}))",
+                    subject_code,
+                    subject_code,
+                    element_nodes.len() - index
+                ),
+                element_node,
+                condition_codes,
+                bindings,
+            ),
+            _ => collect_pattern_checks(
+                &format!("(nth {} {})", subject_code, index),
+                element_node,
+                condition_codes,
+                bindings,
+            ),
+        }
+    }
+}
+
+fn convert_type_check_to_code(type_node: &SyntaxTreeNode, subject_code: &str) -> String {
+    let type_name: &str = type_node.value.as_deref().unwrap();
+    let type_name: &str = match type_name.find('<') {
+        Some(generic_index) => &type_name[..generic_index],
+        None => type_name,
+    };
+
+    match type_name {
+        "int" | "long" | "short" | "byte" => format!("(int? {})", subject_code),
+        "double" | "float" => format!("(double? {})", subject_code),
+        "decimal" => format!("(decimal? {})", subject_code),
+        "string" => format!("(string? {})", subject_code),
+        "bool" => format!("(boolean? {})", subject_code),
+        "char" => format!("(char? {})", subject_code),
+        "object" => format!("(some? {})", subject_code),
+        _ => format!("(instance? {} {})", type_name, subject_code),
+    }
+}
+
+fn join_condition_codes(condition_codes: Vec<String>) -> String {
+    match condition_codes.len() {
+        0 => String::from("true"),
+        1 => condition_codes.into_iter().next().unwrap(),
+        _ => format!("(and {})", condition_codes.join(" ")),
+    }
+}
+
+pub(crate) fn contains_pattern_match(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    matches!(syntax_tree_node.type_, SyntaxTreeNodeType::PatternMatch)
+        || syntax_tree_node.children.iter().any(contains_pattern_match)
+}
+
+fn is_enum_member(value: &str) -> bool {
+    value.contains('.')
+        && value
+            .split('.')
+            .all(|segment| segment.starts_with(|c: char| c.is_alphabetic() || c == '_'))
+}
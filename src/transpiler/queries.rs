@@ -0,0 +1,221 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::transpiler::convert_abstract_syntax_tree_to_code;
+
+struct QueryState {
+    index_bindings: Vec<String>,
+    bindings: Vec<String>,
+    range_names: Vec<String>,
+    group_names: Vec<String>,
+    result_code: Option<String>,
+    ends_with_group: bool,
+}
+
+pub(crate) fn convert_query_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let mut query_state: QueryState = QueryState {
+        index_bindings: vec![],
+        bindings: vec![],
+        range_names: vec![],
+        group_names: vec![],
+        result_code: None,
+        ends_with_group: false,
+    };
+
+    for clause_node in &syntax_tree_node.children {
+        let clause_node: SyntaxTreeNode =
+            replace_group_keys(clause_node.clone(), &query_state.group_names);
+        let clause_codes: Vec<String> = clause_node
+            .children
+            .iter()
+            .map(|part_node| match part_node.type_ {
+                SyntaxTreeNodeType::QueryClause => {
+                    convert_abstract_syntax_tree_to_code(&part_node.children[0])
+                }
+                _ => convert_abstract_syntax_tree_to_code(part_node),
+            })
+            .collect::<Vec<String>>();
+
+        match (
+            clause_node.value.as_deref().unwrap(),
+            clause_codes.as_slice(),
+        ) {
+            ("from", [range_name, source_code]) => {
+                query_state
+                    .bindings
+                    .push(format!("{} {}", range_name, source_code));
+                query_state.range_names.push(range_name.clone());
+            }
+            ("let", [range_name, value_code]) => {
+                query_state
+                    .bindings
+                    .push(format!(":let [{} {}]", range_name, value_code));
+                query_state.range_names.push(range_name.clone());
+            }
+            ("where", [condition_code]) => {
+                query_state
+                    .bindings
+                    .push(format!(":when {}", condition_code));
+            }
+            (
+                "join",
+                [range_name, source_code, outer_key_code, inner_key_code, group_name @ ..],
+            ) => {
+                let index_name: String = format!("{}-index", range_name);
+                query_state.index_bindings.push(format!(
+                    "{} (group-by (fn [{}] {}) {})",
+                    index_name, range_name, inner_key_code, source_code
+                ));
+
+                match group_name.first() {
+                    Some(group_name) => {
+                        query_state.bindings.push(format!(
+                            ":let [{} (get {} {} [])]",
+                            group_name, index_name, outer_key_code
+                        ));
+                        query_state.range_names.push(group_name.clone());
+                    }
+                    None => {
+                        query_state.bindings.push(format!(
+                            "{} (get {} {})",
+                            range_name, index_name, outer_key_code
+                        ));
+                        query_state.range_names.push(range_name.clone());
+                    }
+                }
+            }
+            ("orderby", _) => {
+                let tuple_pattern: String = create_tuple_pattern(&query_state.range_names);
+                let mut sorted_code: String =
+                    create_comprehension_code(&query_state.bindings, &tuple_pattern);
+
+                for (ordering_node, key_code) in
+                    clause_node.children.iter().zip(&clause_codes).rev()
+                {
+                    sorted_code = match ordering_node.value.as_deref() {
+                        Some("descending") => format!(
+                            "(sort-by (fn [{}] {}) (fn [a b] (compare b a)) {})",
+                            tuple_pattern, key_code, sorted_code
+                        ),
+                        _ => format!(
+                            "(sort-by (fn [{}] {}) {})",
+                            tuple_pattern, key_code, sorted_code
+                        ),
+                    };
+                }
+
+                query_state.bindings = Vec::from([format!("{} {}", tuple_pattern, sorted_code)]);
+            }
+            ("select", [projection_code]) => {
+                query_state.result_code = Some(create_comprehension_code(
+                    &query_state.bindings,
+                    projection_code,
+                ));
+            }
+            ("group", [element_code, key_code]) => {
+                let tuple_pattern: String = create_tuple_pattern(&query_state.range_names);
+
+                query_state.result_code = Some(match *element_code == tuple_pattern {
+                    true => format!(
+                        "(group-by (fn [{}] {}) {})",
+                        tuple_pattern,
+                        key_code,
+                        create_comprehension_code(&query_state.bindings, &tuple_pattern)
+                    ),
+                    false => format!(
+                        "(reduce (fn [groups [key element]] (update groups key (fnil conj []) element)) {{}} {})",
+                        create_comprehension_code(
+                            &query_state.bindings,
+                            &format!("[{} {}]", key_code, element_code)
+                        )
+                    ),
+                });
+            }
+            ("into", [continuation_name]) => {
+                let previous_result_code: String =
+                    query_state.result_code.take().unwrap_or_else(|| {
+                        panic!(
+                            "`into {}` must follow a select or group clause",
+                            continuation_name
+                        )
+                    });
+                query_state.bindings = Vec::from([match query_state.ends_with_group {
+                    true => {
+                        query_state.group_names.push(continuation_name.clone());
+                        format!(
+                            "[{}-key {}] {}",
+                            continuation_name, continuation_name, previous_result_code
+                        )
+                    }
+                    false => format!("{} {}", continuation_name, previous_result_code),
+                }]);
+                query_state.range_names = Vec::from([continuation_name.clone()]);
+            }
+            (clause_keyword, _) => panic!("Unable to convert `{}` query clause", clause_keyword),
+        }
+
+        query_state.ends_with_group = clause_node.value.as_deref() == Some("group");
+    }
+
+    let result_code: String = match query_state.result_code {
+        Some(result_code) => result_code,
+        None => panic!("query expression must end with a select or group clause"),
+    };
+
+    match query_state.index_bindings.is_empty() {
+        true => result_code,
+        false => format!(
+            "(let [{}]\n{})",
+            query_state.index_bindings.join("\n"),
+            result_code
+        ),
+    }
+}
+
+fn create_comprehension_code(bindings: &[String], result_code: &str) -> String {
+    if let [binding] = bindings {
+        if let Some(source_code) = binding.strip_prefix(&format!("{} ", result_code)) {
+            return source_code.to_string();
+        }
+    }
+
+    format!("(for [{}]\n{})", bindings.join("\n"), result_code)
+}
+
+fn create_tuple_pattern(range_names: &[String]) -> String {
+    match range_names {
+        [range_name] => range_name.clone(),
+        _ => format!("[{}]", range_names.join(" ")),
+    }
+}
+
+pub(crate) fn replace_group_keys(
+    syntax_tree_node: SyntaxTreeNode,
+    group_names: &[String],
+) -> SyntaxTreeNode {
+    if let (SyntaxTreeNodeType::MemberAccess, Some("key" | "Key"), [target_node]) = (
+        &syntax_tree_node.type_,
+        syntax_tree_node.value.as_deref(),
+        syntax_tree_node.children.as_slice(),
+    ) {
+        if let (SyntaxTreeNodeType::Literal, Some(group_name)) =
+            (&target_node.type_, &target_node.value)
+        {
+            if group_names.contains(group_name) {
+                return SyntaxTreeNode {
+                    value: Some(format!("{}-key", group_name)),
+                    type_: SyntaxTreeNodeType::Literal,
+                    children: vec![],
+                };
+            }
+        }
+    }
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children: syntax_tree_node
+            .children
+            .into_iter()
+            .map(|child| replace_group_keys(child, group_names))
+            .collect::<Vec<SyntaxTreeNode>>(),
+    }
+}
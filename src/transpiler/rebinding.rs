@@ -0,0 +1,268 @@
+use crate::syntax_tree::syntax_tree_builder::{contains_loop_control, split_branch_bodies};
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::loops::collect_mutated_names;
+
+pub fn rebind_branch_reassignments(syntax_tree_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let children: Vec<SyntaxTreeNode> =
+        match (&syntax_tree_node.type_, syntax_tree_node.value.as_deref()) {
+            (SyntaxTreeNodeType::Branch, Some("if")) => {
+                let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+                    split_branch_bodies(&syntax_tree_node);
+
+                let mut children: Vec<SyntaxTreeNode> =
+                    Vec::from([syntax_tree_node.children[0].clone()]);
+                children.extend(rebind_statements(then_nodes.to_vec(), false));
+                if let Some(else_node) = syntax_tree_node.children.get(1 + then_nodes.len()) {
+                    children.push(SyntaxTreeNode {
+                        value: else_node.value.clone(),
+                        type_: SyntaxTreeNodeType::Branch,
+                        children: rebind_statements(else_nodes.to_vec(), false),
+                    });
+                }
+                children
+            }
+            (SyntaxTreeNodeType::Loop, _) => rebind_statements(syntax_tree_node.children, true),
+            _ => rebind_statements(syntax_tree_node.children, false),
+        };
+
+    SyntaxTreeNode {
+        value: syntax_tree_node.value,
+        type_: syntax_tree_node.type_,
+        children,
+    }
+}
+
+fn rebind_statements(
+    statement_nodes: Vec<SyntaxTreeNode>,
+    continues_after_last_statement: bool,
+) -> Vec<SyntaxTreeNode> {
+    let last_statement_index: Option<usize> = statement_nodes
+        .iter()
+        .rposition(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment));
+
+    statement_nodes
+        .into_iter()
+        .enumerate()
+        .map(|(index, statement_node)| {
+            let is_result: bool =
+                !continues_after_last_statement && Some(index) == last_statement_index;
+
+            match is_result || !is_rebindable(&statement_node) {
+                true => statement_node,
+                false => create_rebinding(statement_node),
+            }
+        })
+        .map(rebind_branch_reassignments)
+        .collect::<Vec<SyntaxTreeNode>>()
+}
+
+fn is_rebindable(statement_node: &SyntaxTreeNode) -> bool {
+    match (&statement_node.type_, statement_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Branch, Some("if"))
+        | (SyntaxTreeNodeType::Switch, _)
+        | (SyntaxTreeNodeType::Try, _)
+        | (SyntaxTreeNodeType::Using, _) => {
+            !contains_loop_control(statement_node)
+                && !collect_mutated_names(std::slice::from_ref(statement_node)).is_empty()
+        }
+        _ => false,
+    }
+}
+
+fn create_rebinding(statement_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    let mutated_names: Vec<String> = collect_mutated_names(std::slice::from_ref(&statement_node));
+
+    let result_node: SyntaxTreeNode = match mutated_names.as_slice() {
+        [mutated_name] => create_name_node(mutated_name),
+        _ => SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Collection,
+            children: mutated_names
+                .iter()
+                .map(|mutated_name| create_name_node(mutated_name))
+                .collect::<Vec<SyntaxTreeNode>>(),
+        },
+    };
+
+    let value_node: SyntaxTreeNode = match statement_node.type_ {
+        SyntaxTreeNodeType::Switch => append_result_to_switch(&statement_node, &result_node),
+        SyntaxTreeNodeType::Try => append_result_to_try(&statement_node, &result_node),
+        SyntaxTreeNodeType::Using => {
+            let mut children: Vec<SyntaxTreeNode> =
+                Vec::from([statement_node.children[0].clone()]);
+            children.extend(append_result_to_body(
+                &statement_node.children[1..],
+                &result_node,
+            ));
+
+            SyntaxTreeNode {
+                value: statement_node.value.clone(),
+                type_: SyntaxTreeNodeType::Using,
+                children,
+            }
+        }
+        _ => append_result_to_branch(&statement_node, &result_node),
+    };
+
+    match mutated_names.as_slice() {
+        [mutated_name] => SyntaxTreeNode {
+            value: Some(mutated_name.clone()),
+            type_: SyntaxTreeNodeType::Assignment,
+            children: Vec::from([value_node]),
+        },
+        _ => SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::Assignment,
+            children: Vec::from([result_node, value_node]),
+        },
+    }
+}
+
+fn append_result_to_branch(
+    branch_node: &SyntaxTreeNode,
+    result_node: &SyntaxTreeNode,
+) -> SyntaxTreeNode {
+    let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+        split_branch_bodies(branch_node);
+
+    let else_nodes: Vec<SyntaxTreeNode> = match else_nodes {
+        [else_if_node]
+            if matches!(else_if_node.type_, SyntaxTreeNodeType::Branch)
+                && else_if_node.value.as_deref() == Some("if") =>
+        {
+            Vec::from([append_result_to_branch(else_if_node, result_node)])
+        }
+        _ => append_result_to_body(else_nodes, result_node),
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([branch_node.children[0].clone()]);
+    children.extend(append_result_to_body(then_nodes, result_node));
+    children.push(SyntaxTreeNode {
+        value: Some(String::from("else")),
+        type_: SyntaxTreeNodeType::Branch,
+        children: else_nodes,
+    });
+
+    SyntaxTreeNode {
+        value: branch_node.value.clone(),
+        type_: SyntaxTreeNodeType::Branch,
+        children,
+    }
+}
+
+fn append_result_to_switch(
+    switch_node: &SyntaxTreeNode,
+    result_node: &SyntaxTreeNode,
+) -> SyntaxTreeNode {
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([switch_node.children[0].clone()]);
+    let mut has_default_section: bool = false;
+
+    for section_node in &switch_node.children[1..] {
+        let (mut section_children, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+            section_node
+                .children
+                .iter()
+                .cloned()
+                .partition(|child| matches!(child.type_, SyntaxTreeNodeType::SwitchLabel));
+
+        has_default_section |= section_children
+            .iter()
+            .any(|label_node| label_node.value.as_deref() == Some("default"));
+        section_children.extend(append_result_to_body(&body_nodes, result_node));
+
+        children.push(SyntaxTreeNode {
+            value: section_node.value.clone(),
+            type_: SyntaxTreeNodeType::SwitchSection,
+            children: section_children,
+        });
+    }
+
+    if !has_default_section {
+        children.push(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::SwitchSection,
+            children: Vec::from([
+                SyntaxTreeNode {
+                    value: Some(String::from("default")),
+                    type_: SyntaxTreeNodeType::SwitchLabel,
+                    children: vec![],
+                },
+                result_node.clone(),
+            ]),
+        });
+    }
+
+    SyntaxTreeNode {
+        value: switch_node.value.clone(),
+        type_: SyntaxTreeNodeType::Switch,
+        children,
+    }
+}
+
+fn append_result_to_try(try_node: &SyntaxTreeNode, result_node: &SyntaxTreeNode) -> SyntaxTreeNode {
+    let children: Vec<SyntaxTreeNode> = try_node
+        .children
+        .iter()
+        .map(|child| match (&child.type_, child.value.as_deref()) {
+            (SyntaxTreeNodeType::Block, Some("try")) => SyntaxTreeNode {
+                value: child.value.clone(),
+                type_: SyntaxTreeNodeType::Block,
+                children: append_result_to_body(&child.children, result_node),
+            },
+            (SyntaxTreeNodeType::Catch, _) => {
+                let (mut catch_children, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+                    child.children.iter().cloned().partition(|catch_child| {
+                        matches!(catch_child.type_, SyntaxTreeNodeType::Type)
+                            || (matches!(catch_child.type_, SyntaxTreeNodeType::Block)
+                                && catch_child.value.as_deref() == Some("when"))
+                    });
+                catch_children.extend(append_result_to_body(&body_nodes, result_node));
+
+                SyntaxTreeNode {
+                    value: child.value.clone(),
+                    type_: SyntaxTreeNodeType::Catch,
+                    children: catch_children,
+                }
+            }
+            _ => child.clone(),
+        })
+        .collect::<Vec<SyntaxTreeNode>>();
+
+    SyntaxTreeNode {
+        value: try_node.value.clone(),
+        type_: SyntaxTreeNodeType::Try,
+        children,
+    }
+}
+
+fn append_result_to_body(
+    body_nodes: &[SyntaxTreeNode],
+    result_node: &SyntaxTreeNode,
+) -> Vec<SyntaxTreeNode> {
+    let statement_nodes: Vec<&SyntaxTreeNode> = body_nodes
+        .iter()
+        .filter(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment))
+        .collect::<Vec<&SyntaxTreeNode>>();
+
+    if let [assignment_node] = statement_nodes.as_slice() {
+        if matches!(assignment_node.type_, SyntaxTreeNodeType::Assignment)
+            && matches!(result_node.type_, SyntaxTreeNodeType::Literal)
+            && assignment_node.value == result_node.value
+            && assignment_node.children.len() == 1
+        {
+            return Vec::from([assignment_node.children[0].clone()]);
+        }
+    }
+
+    let mut body_nodes: Vec<SyntaxTreeNode> = body_nodes.to_vec();
+    body_nodes.push(result_node.clone());
+    body_nodes
+}
+
+fn create_name_node(name: &str) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(name.to_string()),
+        type_: SyntaxTreeNodeType::Literal,
+        children: vec![],
+    }
+}
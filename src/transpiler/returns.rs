@@ -0,0 +1,546 @@
+// Restructuring errors are propagated with explicit matches, as in the syntax tree builder.
+#![allow(clippy::question_mark)]
+
+use crate::syntax_tree::syntax_tree_builder::split_branch_bodies;
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::mutation::create_literal_node;
+
+const RETURNED_FLAG_NAME: &str = "has-returned";
+const RETURNED_VALUE_NAME: &str = "returned-value";
+
+pub fn eliminate_early_returns(
+    abstract_syntax_tree: SyntaxTreeNode,
+) -> Result<(SyntaxTreeNode, Vec<String>), String> {
+    let mut warnings: Vec<String> = vec![];
+    let abstract_syntax_tree: SyntaxTreeNode =
+        match restructure_methods(abstract_syntax_tree, &mut warnings) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        };
+
+    Ok((abstract_syntax_tree, warnings))
+}
+
+fn restructure_methods(
+    syntax_tree_node: SyntaxTreeNode,
+    warnings: &mut Vec<String>,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+
+    for child in syntax_tree_node.children {
+        children.push(match restructure_methods(child, warnings) {
+            Ok(valid_node) => valid_node,
+            Err(error) => return Err(error),
+        });
+    }
+
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Method | SyntaxTreeNodeType::Lambda => {
+            let method_name: String = syntax_tree_node
+                .value
+                .clone()
+                .filter(|_| matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Method))
+                .unwrap_or_else(|| String::from("(anonymous function)"));
+            let (mut children, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+                children.into_iter().partition(|child| {
+                    matches!(
+                        child.type_,
+                        SyntaxTreeNodeType::MethodArgument | SyntaxTreeNodeType::Type
+                    )
+                });
+
+            children.extend(
+                match restructure_body(&body_nodes, &[], &method_name, warnings) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                },
+            );
+
+            Ok(SyntaxTreeNode {
+                value: syntax_tree_node.value,
+                type_: syntax_tree_node.type_,
+                children,
+            })
+        }
+        _ => Ok(SyntaxTreeNode {
+            value: syntax_tree_node.value,
+            type_: syntax_tree_node.type_,
+            children,
+        }),
+    }
+}
+
+fn restructure_body(
+    body_nodes: &[SyntaxTreeNode],
+    continuation_nodes: &[SyntaxTreeNode],
+    method_name: &str,
+    warnings: &mut Vec<String>,
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut statement_nodes: Vec<SyntaxTreeNode> = body_nodes.to_vec();
+    statement_nodes.extend_from_slice(continuation_nodes);
+
+    let mut restructured_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    for (index, statement_node) in statement_nodes.iter().enumerate() {
+        let remaining_nodes: &[SyntaxTreeNode] = &statement_nodes[index + 1..];
+
+        let restructured_node: Result<SyntaxTreeNode, String> = match (
+            &statement_node.type_,
+            statement_node.value.as_deref(),
+        ) {
+            (SyntaxTreeNodeType::Return, _) => {
+                restructured_nodes.extend(statement_node.children.iter().cloned());
+                return Ok(restructured_nodes);
+            }
+            (SyntaxTreeNodeType::Branch, Some("if")) if contains_return(statement_node) => {
+                restructure_branch(statement_node, remaining_nodes, method_name, warnings)
+            }
+            (SyntaxTreeNodeType::Switch, _) if contains_return(statement_node) => {
+                restructure_switch(statement_node, remaining_nodes, method_name, warnings)
+            }
+            (SyntaxTreeNodeType::Try, _) if contains_return(statement_node) => {
+                if has_statements(remaining_nodes) {
+                    warnings.push(format!(
+                        "statements after a `try` that returns in method {} were moved inside its body and catch clauses, so exceptions they throw are now caught",
+                        method_name
+                    ));
+                }
+                restructure_try(statement_node, remaining_nodes, method_name, warnings)
+            }
+            (SyntaxTreeNodeType::Using, _) if contains_return(statement_node) => {
+                let mut children: Vec<SyntaxTreeNode> =
+                    Vec::from([statement_node.children[0].clone()]);
+                children.extend(
+                    match restructure_body(
+                        &statement_node.children[1..],
+                        remaining_nodes,
+                        method_name,
+                        warnings,
+                    ) {
+                        Ok(valid_nodes) => valid_nodes,
+                        Err(error) => return Err(error),
+                    },
+                );
+
+                Ok(SyntaxTreeNode {
+                    value: statement_node.value.clone(),
+                    type_: SyntaxTreeNodeType::Using,
+                    children,
+                })
+            }
+            (SyntaxTreeNodeType::Loop, Some(loop_keyword)) if contains_return(statement_node) => {
+                let loop_node: SyntaxTreeNode = match replace_returns_with_exits(statement_node) {
+                    Ok(valid_node) => valid_node,
+                    Err(error) => {
+                        return Err(format!(
+                            "`return` inside `{}` loop in method {} cannot be restructured: {}",
+                            loop_keyword, method_name, error
+                        ))
+                    }
+                };
+
+                let returns_value: bool = contains_valued_return(statement_node);
+
+                let mut declaration_nodes: Vec<SyntaxTreeNode> =
+                    Vec::from([create_declaration_node(RETURNED_FLAG_NAME, "false")]);
+                if returns_value {
+                    declaration_nodes.push(create_declaration_node(RETURNED_VALUE_NAME, "null"));
+                }
+
+                append_declarations(&mut restructured_nodes, declaration_nodes);
+                restructured_nodes.push(loop_node);
+
+                restructure_branch(
+                    &SyntaxTreeNode {
+                        value: Some(String::from("if")),
+                        type_: SyntaxTreeNodeType::Branch,
+                        children: Vec::from([
+                            create_literal_node(RETURNED_FLAG_NAME),
+                            SyntaxTreeNode {
+                                value: None,
+                                type_: SyntaxTreeNodeType::Return,
+                                children: match returns_value {
+                                    true => Vec::from([create_literal_node(RETURNED_VALUE_NAME)]),
+                                    false => vec![],
+                                },
+                            },
+                        ]),
+                    },
+                    remaining_nodes,
+                    method_name,
+                    warnings,
+                )
+            }
+            _ => {
+                restructured_nodes.push(statement_node.clone());
+                continue;
+            }
+        };
+
+        match restructured_node {
+            Ok(valid_node) => restructured_nodes.push(valid_node),
+            Err(error) => return Err(error),
+        }
+
+        return Ok(restructured_nodes);
+    }
+
+    Ok(restructured_nodes)
+}
+
+fn restructure_branch(
+    branch_node: &SyntaxTreeNode,
+    continuation_nodes: &[SyntaxTreeNode],
+    method_name: &str,
+    warnings: &mut Vec<String>,
+) -> Result<SyntaxTreeNode, String> {
+    let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+        split_branch_bodies(branch_node);
+
+    let mut condition_node: SyntaxTreeNode = branch_node.children[0].clone();
+    let mut then_nodes: Vec<SyntaxTreeNode> =
+        match restructure_body(then_nodes, continuation_nodes, method_name, warnings) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        };
+    let mut else_nodes: Vec<SyntaxTreeNode> =
+        match restructure_body(else_nodes, continuation_nodes, method_name, warnings) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        };
+
+    if !has_statements(&then_nodes) && has_statements(&else_nodes) {
+        condition_node = negate_condition(condition_node);
+        then_nodes = else_nodes;
+        else_nodes = vec![];
+    }
+
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([condition_node]);
+    children.extend(then_nodes);
+    if has_statements(&else_nodes) {
+        children.push(SyntaxTreeNode {
+            value: Some(String::from("else")),
+            type_: SyntaxTreeNodeType::Branch,
+            children: else_nodes,
+        });
+    }
+
+    Ok(SyntaxTreeNode {
+        value: branch_node.value.clone(),
+        type_: SyntaxTreeNodeType::Branch,
+        children,
+    })
+}
+
+fn restructure_switch(
+    switch_node: &SyntaxTreeNode,
+    continuation_nodes: &[SyntaxTreeNode],
+    method_name: &str,
+    warnings: &mut Vec<String>,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = Vec::from([switch_node.children[0].clone()]);
+    let mut has_default_section: bool = false;
+
+    for section_node in &switch_node.children[1..] {
+        let (mut section_children, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+            section_node
+                .children
+                .iter()
+                .cloned()
+                .partition(|child| matches!(child.type_, SyntaxTreeNodeType::SwitchLabel));
+
+        has_default_section |= section_children
+            .iter()
+            .any(|label_node| label_node.value.as_deref() == Some("default"));
+
+        section_children.extend(
+            match restructure_body(&body_nodes, continuation_nodes, method_name, warnings) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
+
+        children.push(SyntaxTreeNode {
+            value: section_node.value.clone(),
+            type_: SyntaxTreeNodeType::SwitchSection,
+            children: section_children,
+        });
+    }
+
+    if !has_default_section && has_statements(continuation_nodes) {
+        let mut section_children: Vec<SyntaxTreeNode> = Vec::from([SyntaxTreeNode {
+            value: Some(String::from("default")),
+            type_: SyntaxTreeNodeType::SwitchLabel,
+            children: vec![],
+        }]);
+        section_children.extend(
+            match restructure_body(continuation_nodes, &[], method_name, warnings) {
+                Ok(valid_nodes) => valid_nodes,
+                Err(error) => return Err(error),
+            },
+        );
+
+        children.push(SyntaxTreeNode {
+            value: None,
+            type_: SyntaxTreeNodeType::SwitchSection,
+            children: section_children,
+        });
+    }
+
+    Ok(SyntaxTreeNode {
+        value: switch_node.value.clone(),
+        type_: SyntaxTreeNodeType::Switch,
+        children,
+    })
+}
+
+fn restructure_try(
+    try_node: &SyntaxTreeNode,
+    continuation_nodes: &[SyntaxTreeNode],
+    method_name: &str,
+    warnings: &mut Vec<String>,
+) -> Result<SyntaxTreeNode, String> {
+    let mut children: Vec<SyntaxTreeNode> = vec![];
+
+    for child in &try_node.children {
+        let restructured_child: SyntaxTreeNode = match (&child.type_, child.value.as_deref()) {
+            (SyntaxTreeNodeType::Block, Some("try")) => SyntaxTreeNode {
+                value: child.value.clone(),
+                type_: SyntaxTreeNodeType::Block,
+                children: match restructure_body(
+                    &child.children,
+                    continuation_nodes,
+                    method_name,
+                    warnings,
+                ) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                },
+            },
+            (SyntaxTreeNodeType::Catch, _) => {
+                let (mut catch_children, body_nodes): (Vec<SyntaxTreeNode>, Vec<SyntaxTreeNode>) =
+                    child.children.iter().cloned().partition(|catch_child| {
+                        matches!(catch_child.type_, SyntaxTreeNodeType::Type)
+                            || (matches!(catch_child.type_, SyntaxTreeNodeType::Block)
+                                && catch_child.value.as_deref() == Some("when"))
+                    });
+                catch_children.extend(
+                    match restructure_body(&body_nodes, continuation_nodes, method_name, warnings) {
+                        Ok(valid_nodes) => valid_nodes,
+                        Err(error) => return Err(error),
+                    },
+                );
+
+                SyntaxTreeNode {
+                    value: child.value.clone(),
+                    type_: SyntaxTreeNodeType::Catch,
+                    children: catch_children,
+                }
+            }
+            _ => child.clone(),
+        };
+
+        children.push(restructured_child);
+    }
+
+    Ok(SyntaxTreeNode {
+        value: try_node.value.clone(),
+        type_: SyntaxTreeNodeType::Try,
+        children,
+    })
+}
+
+fn negate_condition(condition_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    match (&condition_node.type_, condition_node.value.as_deref()) {
+        (SyntaxTreeNodeType::Expression, Some("not")) if condition_node.children.len() == 1 => {
+            condition_node.children[0].clone()
+        }
+        _ => SyntaxTreeNode {
+            value: Some(String::from("not")),
+            type_: SyntaxTreeNodeType::Expression,
+            children: Vec::from([condition_node]),
+        },
+    }
+}
+
+fn replace_returns_with_exits(loop_node: &SyntaxTreeNode) -> Result<SyntaxTreeNode, String> {
+    let first_body_index: usize = match loop_node.value.as_deref() {
+        Some("for") => 3,
+        Some("foreach") => 2,
+        _ => 1,
+    };
+
+    let mut children: Vec<SyntaxTreeNode> = loop_node.children[..first_body_index].to_vec();
+    children.extend(
+        match replace_body_returns_with_exits(&loop_node.children[first_body_index..]) {
+            Ok(valid_nodes) => valid_nodes,
+            Err(error) => return Err(error),
+        },
+    );
+
+    Ok(SyntaxTreeNode {
+        value: loop_node.value.clone(),
+        type_: SyntaxTreeNodeType::Loop,
+        children,
+    })
+}
+
+fn replace_body_returns_with_exits(
+    body_nodes: &[SyntaxTreeNode],
+) -> Result<Vec<SyntaxTreeNode>, String> {
+    let mut replaced_nodes: Vec<SyntaxTreeNode> = vec![];
+
+    for body_node in body_nodes {
+        match (&body_node.type_, body_node.value.as_deref()) {
+            (SyntaxTreeNodeType::Return, _) => {
+                let flag_assignment_node: SyntaxTreeNode =
+                    create_assignment_node(RETURNED_FLAG_NAME, create_literal_node("true"));
+
+                replaced_nodes.push(match body_node.children.first() {
+                    Some(value_node) => SyntaxTreeNode {
+                        value: None,
+                        type_: SyntaxTreeNodeType::Assignment,
+                        children: Vec::from([
+                            create_assignment_node(RETURNED_VALUE_NAME, value_node.clone()),
+                            flag_assignment_node,
+                        ]),
+                    },
+                    None => flag_assignment_node,
+                });
+                replaced_nodes.push(SyntaxTreeNode {
+                    value: Some(String::from("break")),
+                    type_: SyntaxTreeNodeType::LoopControl,
+                    children: vec![],
+                });
+
+                return Ok(replaced_nodes);
+            }
+            (SyntaxTreeNodeType::Branch, Some("if")) if contains_return(body_node) => {
+                let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+                    split_branch_bodies(body_node);
+
+                let mut children: Vec<SyntaxTreeNode> = Vec::from([body_node.children[0].clone()]);
+                children.extend(match replace_body_returns_with_exits(then_nodes) {
+                    Ok(valid_nodes) => valid_nodes,
+                    Err(error) => return Err(error),
+                });
+                if !else_nodes.is_empty() {
+                    children.push(SyntaxTreeNode {
+                        value: Some(String::from("else")),
+                        type_: SyntaxTreeNodeType::Branch,
+                        children: match replace_body_returns_with_exits(else_nodes) {
+                            Ok(valid_nodes) => valid_nodes,
+                            Err(error) => return Err(error),
+                        },
+                    });
+                }
+
+                replaced_nodes.push(SyntaxTreeNode {
+                    value: body_node.value.clone(),
+                    type_: SyntaxTreeNodeType::Branch,
+                    children,
+                });
+            }
+            (SyntaxTreeNodeType::Loop, _) if contains_return(body_node) => {
+                return Err(String::from("it is nested inside another loop"));
+            }
+            _ if contains_return(body_node) => {
+                return Err(String::from(
+                    "only returns directly in the loop body or inside `if` branches are supported",
+                ));
+            }
+            _ => replaced_nodes.push(body_node.clone()),
+        }
+    }
+
+    Ok(replaced_nodes)
+}
+
+fn append_declarations(
+    restructured_nodes: &mut Vec<SyntaxTreeNode>,
+    declaration_nodes: Vec<SyntaxTreeNode>,
+) {
+    match restructured_nodes.pop() {
+        Some(group_node) if is_assignment_group(&group_node) => {
+            let mut children: Vec<SyntaxTreeNode> = group_node.children;
+            children.extend(declaration_nodes);
+            restructured_nodes.push(SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::Assignment,
+                children,
+            });
+        }
+        Some(assignment_node)
+            if matches!(assignment_node.type_, SyntaxTreeNodeType::Assignment)
+                && assignment_node.value.is_some() =>
+        {
+            let mut children: Vec<SyntaxTreeNode> = Vec::from([assignment_node]);
+            children.extend(declaration_nodes);
+            restructured_nodes.push(SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::Assignment,
+                children,
+            });
+        }
+        last_node => {
+            restructured_nodes.extend(last_node);
+            restructured_nodes.push(SyntaxTreeNode {
+                value: None,
+                type_: SyntaxTreeNodeType::Assignment,
+                children: declaration_nodes,
+            });
+        }
+    }
+}
+
+fn is_assignment_group(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    matches!(syntax_tree_node.type_, SyntaxTreeNodeType::Assignment)
+        && syntax_tree_node.value.is_none()
+        && syntax_tree_node
+            .children
+            .iter()
+            .all(|child| matches!(child.type_, SyntaxTreeNodeType::Assignment))
+}
+
+fn create_declaration_node(name: &str, value: &str) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(name.to_string()),
+        type_: SyntaxTreeNodeType::Assignment,
+        children: Vec::from([
+            SyntaxTreeNode {
+                value: Some(String::from("var")),
+                type_: SyntaxTreeNodeType::Type,
+                children: vec![],
+            },
+            create_literal_node(value),
+        ]),
+    }
+}
+
+fn create_assignment_node(name: &str, value_node: SyntaxTreeNode) -> SyntaxTreeNode {
+    SyntaxTreeNode {
+        value: Some(name.to_string()),
+        type_: SyntaxTreeNodeType::Assignment,
+        children: Vec::from([value_node]),
+    }
+}
+
+fn contains_return(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Return => true,
+        _ => syntax_tree_node.children.iter().any(contains_return),
+    }
+}
+
+fn contains_valued_return(syntax_tree_node: &SyntaxTreeNode) -> bool {
+    match syntax_tree_node.type_ {
+        SyntaxTreeNodeType::Return => !syntax_tree_node.children.is_empty(),
+        _ => syntax_tree_node.children.iter().any(contains_valued_return),
+    }
+}
+
+fn has_statements(body_nodes: &[SyntaxTreeNode]) -> bool {
+    body_nodes
+        .iter()
+        .any(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment))
+}
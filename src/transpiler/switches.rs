@@ -0,0 +1,248 @@
+use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::patterns::{
+    convert_constant_to_code, convert_pattern_to_code, is_constant_literal, is_symbolic_constant,
+    wrap_in_bindings, PatternCode,
+};
+use crate::transpiler::transpiler::{convert_abstract_syntax_tree_to_code, convert_body_to_code};
+
+const SWITCH_SUBJECT_NAME: &str = "switch-value";
+
+pub(crate) fn convert_switch_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let subject_node: &SyntaxTreeNode = &syntax_tree_node.children[0];
+    let section_nodes: &[SyntaxTreeNode] = &syntax_tree_node.children[1..];
+    let is_expression: bool = syntax_tree_node.value.as_deref() == Some("switch expression");
+
+    match section_nodes.iter().all(is_constant_section) {
+        true => convert_case_switch_to_code(subject_node, section_nodes, is_expression),
+        false => convert_cond_switch_to_code(subject_node, section_nodes, is_expression),
+    }
+}
+
+fn convert_case_switch_to_code(
+    subject_node: &SyntaxTreeNode,
+    section_nodes: &[SyntaxTreeNode],
+    is_expression: bool,
+) -> String {
+    let default_section_node: Option<&SyntaxTreeNode> =
+        section_nodes.iter().find(|node| is_default_section(node));
+    let binds_subject: bool = is_expression
+        && default_section_node.is_none()
+        && !matches!(subject_node.type_, SyntaxTreeNodeType::Literal);
+    let subject_code: String = match binds_subject {
+        true => String::from(SWITCH_SUBJECT_NAME),
+        false => convert_abstract_syntax_tree_to_code(subject_node),
+    };
+
+    let section_label_nodes: Vec<Vec<&SyntaxTreeNode>> = section_nodes
+        .iter()
+        .filter(|node| !is_default_section(node))
+        .map(|section_node| {
+            find_section_labels(section_node)
+                .iter()
+                .flat_map(|label_node| collect_constant_labels(&label_node.children[0]).unwrap())
+                .collect::<Vec<&SyntaxTreeNode>>()
+        })
+        .collect::<Vec<Vec<&SyntaxTreeNode>>>();
+
+    // `case` does not evaluate its labels, so host constants such as enum members are compared
+    // with `condp` instead.
+    let is_symbolic: bool = section_label_nodes
+        .iter()
+        .flatten()
+        .any(|label_node| is_symbolic_constant(label_node));
+    let has_grouped_labels: bool = section_label_nodes
+        .iter()
+        .any(|label_nodes| label_nodes.len() > 1);
+    let dispatch_code: &str = match (is_symbolic, has_grouped_labels) {
+        (false, _) => "case",
+        (true, false) => "condp =",
+        (true, true) => "condp contains?",
+    };
+
+    let mut clauses_code: Vec<String> = vec![];
+
+    for (section_node, label_nodes) in section_nodes
+        .iter()
+        .filter(|node| !is_default_section(node))
+        .zip(section_label_nodes)
+    {
+        let label_codes: Vec<String> = label_nodes
+            .into_iter()
+            .map(convert_constant_to_code)
+            .collect::<Vec<String>>();
+
+        let label_code: String = match (label_codes.as_slice(), dispatch_code) {
+            (_, "condp contains?") => format!("#{{{}}}", label_codes.join(" ")),
+            ([single_label_code], _) => single_label_code.clone(),
+            _ => format!("({})", label_codes.join(" ")),
+        };
+
+        clauses_code.push(format!(
+            "{} {}",
+            label_code,
+            convert_section_body_to_code(section_node)
+        ));
+    }
+
+    clauses_code.push(match (default_section_node, is_expression) {
+        (Some(default_section_node), _) => convert_section_body_to_code(default_section_node),
+        (None, true) => create_no_match_code(&subject_code),
+        (None, false) => String::from("nil"),
+    });
+
+    let case_code: String = format!(
+        "({} {}\n{})",
+        dispatch_code,
+        subject_code,
+        clauses_code.join("\n")
+    );
+
+    match binds_subject {
+        true => format!(
+            "(let [{} {}]\n{})",
+            subject_code,
+            convert_abstract_syntax_tree_to_code(subject_node),
+            case_code
+        ),
+        false => case_code,
+    }
+}
+
+fn convert_cond_switch_to_code(
+    subject_node: &SyntaxTreeNode,
+    section_nodes: &[SyntaxTreeNode],
+    is_expression: bool,
+) -> String {
+    let subject_code: String = match subject_node.type_ {
+        SyntaxTreeNodeType::Literal => convert_abstract_syntax_tree_to_code(subject_node),
+        _ => String::from(SWITCH_SUBJECT_NAME),
+    };
+
+    let mut clauses_code: Vec<String> = vec![];
+
+    for section_node in section_nodes
+        .iter()
+        .filter(|node| !is_default_section(node))
+    {
+        let label_nodes: Vec<&SyntaxTreeNode> = find_section_labels(section_node);
+        let pattern_codes: Vec<PatternCode> = label_nodes
+            .iter()
+            .map(|label_node| convert_pattern_to_code(&subject_code, &label_node.children[0]))
+            .collect::<Vec<PatternCode>>();
+
+        let condition_codes: Vec<String> = label_nodes
+            .iter()
+            .zip(pattern_codes.iter())
+            .map(
+                |(label_node, pattern_code)| match label_node.children.get(1) {
+                    Some(guard_node) => format!(
+                        "(and {} {})",
+                        pattern_code.condition_code,
+                        wrap_in_bindings(
+                            &pattern_code.bindings,
+                            convert_abstract_syntax_tree_to_code(guard_node)
+                        )
+                    ),
+                    None => pattern_code.condition_code.clone(),
+                },
+            )
+            .collect::<Vec<String>>();
+
+        let condition_code: String = match condition_codes.as_slice() {
+            [single_condition_code] if single_condition_code == "true" => String::from(":else"),
+            [single_condition_code] => single_condition_code.clone(),
+            _ => format!("(or {})", condition_codes.join(" ")),
+        };
+
+        let body_code: String = match pattern_codes.as_slice() {
+            [single_pattern_code] => wrap_in_bindings(
+                &single_pattern_code.bindings,
+                convert_section_body_to_code(section_node),
+            ),
+            _ => convert_section_body_to_code(section_node),
+        };
+
+        clauses_code.push(format!("{} {}", condition_code, body_code));
+    }
+
+    if let Some(default_section_node) = section_nodes.iter().find(|node| is_default_section(node)) {
+        clauses_code.push(format!(
+            ":else {}",
+            convert_section_body_to_code(default_section_node)
+        ));
+    }
+
+    if is_expression
+        && !clauses_code
+            .last()
+            .is_some_and(|clause_code| clause_code.starts_with(":else"))
+    {
+        clauses_code.push(format!(":else {}", create_no_match_code(&subject_code)));
+    }
+
+    let cond_code: String = format!("(cond\n{})", clauses_code.join("\n"));
+
+    match subject_node.type_ {
+        SyntaxTreeNodeType::Literal => cond_code,
+        _ => format!(
+            "(let [{} {}]\n{})",
+            subject_code,
+            convert_abstract_syntax_tree_to_code(subject_node),
+            cond_code
+        ),
+    }
+}
+
+fn create_no_match_code(subject_code: &str) -> String {
+    format!(
+        "(throw (ex-info \"No matching pattern\" {{:value {}}}))",
+        subject_code
+    )
+}
+
+fn convert_section_body_to_code(section_node: &SyntaxTreeNode) -> String {
+    convert_body_to_code(&section_node.children[find_section_labels(section_node).len()..])
+}
+
+fn find_section_labels(section_node: &SyntaxTreeNode) -> Vec<&SyntaxTreeNode> {
+    section_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::SwitchLabel))
+        .collect::<Vec<&SyntaxTreeNode>>()
+}
+
+fn is_default_section(section_node: &SyntaxTreeNode) -> bool {
+    find_section_labels(section_node)
+        .iter()
+        .any(|label_node| label_node.value.as_deref() == Some("default"))
+}
+
+fn is_constant_section(section_node: &SyntaxTreeNode) -> bool {
+    find_section_labels(section_node).iter().all(|label_node| {
+        match label_node.children.as_slice() {
+            [] => true,
+            [pattern_node] => collect_constant_labels(pattern_node).is_some(),
+            _ => false,
+        }
+    })
+}
+
+fn collect_constant_labels(pattern_node: &SyntaxTreeNode) -> Option<Vec<&SyntaxTreeNode>> {
+    match pattern_node.value.as_deref() {
+        Some("constant")
+            if is_constant_literal(&pattern_node.children[0])
+                || is_symbolic_constant(&pattern_node.children[0]) =>
+        {
+            Some(Vec::from([&pattern_node.children[0]]))
+        }
+        Some("or") => {
+            let mut constant_nodes: Vec<&SyntaxTreeNode> = vec![];
+            for child in &pattern_node.children {
+                constant_nodes.extend(collect_constant_labels(child)?);
+            }
+            Some(constant_nodes)
+        }
+        _ => None,
+    }
+}
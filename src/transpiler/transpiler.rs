@@ -1,22 +1,37 @@
+use crate::syntax_tree::syntax_tree_builder::{split_branch_bodies, POSTFIX_STEP_NAME};
 use crate::syntax_tree::syntax_tree_node::{SyntaxTreeNode, SyntaxTreeNodeType};
+use crate::transpiler::classes;
+use crate::transpiler::exceptions;
+use crate::transpiler::idioms;
+use crate::transpiler::lambdas;
+use crate::transpiler::linq;
+use crate::transpiler::loops;
+use crate::transpiler::mutation;
+use crate::transpiler::objects;
+use crate::transpiler::patterns;
+use crate::transpiler::patterns::PatternCode;
+use crate::transpiler::queries;
+use crate::transpiler::switches;
 
 pub fn transpile(abstract_syntax_tree: SyntaxTreeNode) -> String {
-    let mut output: String = String::new();
-
-    output.push_str(&convert_abstract_syntax_tree_to_code(&abstract_syntax_tree));
-
-    for child in abstract_syntax_tree.children {
-        output.push_str(&convert_abstract_syntax_tree_to_code(&child));
-    }
-
-    output
+    abstract_syntax_tree
+        .children
+        .iter()
+        .map(convert_abstract_syntax_tree_to_code)
+        .collect::<Vec<String>>()
+        .join("\n\n")
 }
 
-fn convert_abstract_syntax_tree_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+pub(crate) fn convert_abstract_syntax_tree_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
     match syntax_tree_node.type_ {
         SyntaxTreeNodeType::Namespace => {
             convert_namespace_syntax_tree_node_to_code(syntax_tree_node)
         }
+        SyntaxTreeNodeType::Class => classes::convert_class_syntax_tree_node_to_code(syntax_tree_node),
+        SyntaxTreeNodeType::Interface => {
+            classes::convert_interface_syntax_tree_node_to_code(syntax_tree_node)
+        }
+        SyntaxTreeNodeType::Field => classes::convert_field_syntax_tree_node_to_code(syntax_tree_node),
         SyntaxTreeNodeType::Method => convert_method_syntax_tree_node_to_code(syntax_tree_node),
         SyntaxTreeNodeType::Literal => convert_literal_syntax_tree_node_to_code(syntax_tree_node),
         SyntaxTreeNodeType::Expression => {
@@ -26,13 +41,36 @@ fn convert_abstract_syntax_tree_to_code(syntax_tree_node: &SyntaxTreeNode) -> St
             convert_assignment_syntax_tree_node_to_code(syntax_tree_node)
         }
         SyntaxTreeNodeType::EqualityCheck => {
-            convert_expression_syntax_tree_node_to_code(syntax_tree_node)
+            convert_equality_check_syntax_tree_node_to_code(syntax_tree_node)
         }
         SyntaxTreeNodeType::Branch => convert_branch_syntax_tree_node_to_code(syntax_tree_node),
+        SyntaxTreeNodeType::Loop => loops::convert_loop_syntax_tree_node_to_code(syntax_tree_node),
+        SyntaxTreeNodeType::MemberAccess => {
+            convert_member_access_syntax_tree_node_to_code(syntax_tree_node)
+        }
+        SyntaxTreeNodeType::PatternMatch => {
+            patterns::convert_pattern_match_syntax_tree_node_to_code(syntax_tree_node)
+        }
+        SyntaxTreeNodeType::Switch => {
+            switches::convert_switch_syntax_tree_node_to_code(syntax_tree_node)
+        }
         SyntaxTreeNodeType::Comment => convert_comment_syntax_tree_node_to_code(syntax_tree_node),
         SyntaxTreeNodeType::Collection => {
             convert_collection_syntax_tree_node_to_code(syntax_tree_node)
         }
+        SyntaxTreeNodeType::Try => exceptions::convert_try_syntax_tree_node_to_code(syntax_tree_node),
+        SyntaxTreeNodeType::Throw => {
+            exceptions::convert_throw_syntax_tree_node_to_code(syntax_tree_node)
+        }
+        SyntaxTreeNodeType::ObjectCreation => {
+            objects::convert_object_creation_syntax_tree_node_to_code(syntax_tree_node)
+        }
+        SyntaxTreeNodeType::Using => convert_using_syntax_tree_node_to_code(syntax_tree_node),
+        SyntaxTreeNodeType::Lambda => {
+            lambdas::convert_lambda_syntax_tree_node_to_code(syntax_tree_node)
+        }
+        SyntaxTreeNodeType::Query => queries::convert_query_syntax_tree_node_to_code(syntax_tree_node),
+        SyntaxTreeNodeType::ElementAccess => convert_expression_syntax_tree_node_to_code(syntax_tree_node),
         _ => panic!(
             "Unable to convert abstract syntax tree node {} to code",
             &syntax_tree_node.type_
@@ -41,7 +79,37 @@ fn convert_abstract_syntax_tree_to_code(syntax_tree_node: &SyntaxTreeNode) -> St
 }
 
 fn convert_namespace_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
-    format!("(ns {})\n\n", syntax_tree_node.value.clone().unwrap())
+    let mut output: String = format!("(ns {}", syntax_tree_node.value.clone().unwrap());
+
+    for directive in syntax_tree_node
+        .children
+        .iter()
+        .filter(|child| matches!(child.type_, SyntaxTreeNodeType::ReferClojureExclude))
+    {
+        output.push_str("\n(:refer-clojure :exclude [");
+        output.push_str(
+            directive
+                .children
+                .iter()
+                .map(convert_abstract_syntax_tree_to_code)
+                .collect::<Vec<String>>()
+                .join(" ")
+                .as_str(),
+        );
+        output.push_str("])");
+    }
+
+    output.push_str(")\n\n");
+
+    for child in syntax_tree_node
+        .children
+        .iter()
+        .filter(|child| !matches!(child.type_, SyntaxTreeNodeType::ReferClojureExclude))
+    {
+        output.push_str(&convert_abstract_syntax_tree_to_code(child));
+    }
+
+    output.trim_end().to_string()
 }
 
 fn convert_method_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
@@ -65,19 +133,19 @@ fn convert_method_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) ->
         syntax_tree_node
             .children
             .iter()
-            .filter(|child| !matches!(child.type_, SyntaxTreeNodeType::MethodArgument))
+            .filter(|child| {
+                !matches!(
+                    child.type_,
+                    SyntaxTreeNodeType::MethodArgument | SyntaxTreeNodeType::Type
+                )
+            })
             .map(convert_abstract_syntax_tree_to_code)
             .collect::<Vec<String>>()
             .join("\n")
             .as_str(),
     );
 
-    let number_of_open_parens = output.chars().filter(|c| *c == '(').count();
-    let number_of_close_parens = output.chars().filter(|c| *c == ')').count();
-    output.push_str(
-        ")".repeat(number_of_open_parens - number_of_close_parens)
-            .as_str(),
-    );
+    let mut output: String = close_open_parentheses(output);
 
     output.push_str("\n\n");
 
@@ -85,11 +153,29 @@ fn convert_method_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) ->
 }
 
 fn convert_expression_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    if syntax_tree_node.value.as_deref() == Some("and")
+        && patterns::contains_pattern_match(syntax_tree_node)
+    {
+        return patterns::convert_pattern_match_syntax_tree_node_to_code(syntax_tree_node);
+    }
+
+    if syntax_tree_node.value.as_deref() == Some("some->") {
+        return idioms::convert_some_thread_to_code(syntax_tree_node);
+    }
+
+    if syntax_tree_node.value.as_deref() == Some("->>") {
+        return linq::convert_thread_last_to_code(syntax_tree_node);
+    }
+
+    if syntax_tree_node.value.as_deref() == Some(POSTFIX_STEP_NAME) {
+        return mutation::convert_postfix_step_to_code(syntax_tree_node);
+    }
+
     let mut output: String = String::new();
 
-    output.push_str("(");
+    output.push('(');
     output.push_str(syntax_tree_node.value.clone().unwrap().as_str());
-    output.push_str(" ");
+    output.push(' ');
     output.push_str(
         syntax_tree_node
             .children
@@ -99,11 +185,18 @@ fn convert_expression_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode
             .join(" ")
             .as_str(),
     );
-    output.push_str(")");
+    output.push(')');
 
     output
 }
 
+fn convert_equality_check_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    match idioms::convert_null_check_to_code(syntax_tree_node) {
+        Some(null_check_code) => null_check_code,
+        None => convert_expression_syntax_tree_node_to_code(syntax_tree_node),
+    }
+}
+
 fn convert_assignment_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
     let mut output: String = String::from("(let [");
 
@@ -116,29 +209,87 @@ fn convert_assignment_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode
             syntax_tree_node
                 .children
                 .iter()
-                .map(|child| {
-                    String::from(
-                        child.value.clone().unwrap()
+                .map(|child| match &child.value {
+                    Some(name) => {
+                        name.clone()
                             + " "
-                            + convert_abstract_syntax_tree_to_code(&child.children[0]).as_str(),
-                    )
+                            + convert_abstract_syntax_tree_to_code(find_assigned_value(child)).as_str()
+                    }
+                    None => convert_destructuring_binding_to_code(child),
                 })
                 .collect::<Vec<String>>()
                 .join("\n  ")
                 .as_str(),
         );
+    } else if syntax_tree_node.value.is_none() {
+        output.push_str(convert_destructuring_binding_to_code(syntax_tree_node).as_str());
     } else {
         output.push_str(syntax_tree_node.value.clone().unwrap().as_str());
-        output.push_str(" ");
+        output.push(' ');
         output
-            .push_str(convert_abstract_syntax_tree_to_code(&syntax_tree_node.children[0]).as_str());
+            .push_str(convert_abstract_syntax_tree_to_code(find_assigned_value(syntax_tree_node)).as_str());
     }
 
-    output.push_str("]");
+    output.push(']');
 
     output
 }
 
+fn convert_destructuring_binding_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let (target_node, value_node): (&SyntaxTreeNode, &SyntaxTreeNode) = match syntax_tree_node
+        .children
+        .iter()
+        .filter(|child| !matches!(child.type_, SyntaxTreeNodeType::Type))
+        .collect::<Vec<&SyntaxTreeNode>>()
+        .as_slice()
+    {
+        [target_node, value_node] => (target_node, value_node),
+        _ => panic!("destructuring assignment needs a target and a value"),
+    };
+
+    format!(
+        "{} {}",
+        convert_abstract_syntax_tree_to_code(target_node),
+        convert_abstract_syntax_tree_to_code(value_node)
+    )
+}
+
+pub(crate) fn find_assigned_value(assignment_node: &SyntaxTreeNode) -> &SyntaxTreeNode {
+    assignment_node
+        .children
+        .iter()
+        .find(|child| !matches!(child.type_, SyntaxTreeNodeType::Type))
+        .expect("assignment has no value")
+}
+
+pub(crate) fn close_open_parentheses(mut code: String) -> String {
+    let mut number_of_open_parens: i32 = 0;
+    let mut in_string: bool = false;
+    let mut in_comment: bool = false;
+    let mut previous_character: char = ' ';
+
+    for character in code.chars() {
+        match character {
+            '\n' => in_comment = false,
+            '"' if !in_comment && previous_character != '\\' => in_string = !in_string,
+            ';' if !in_string => in_comment = true,
+            '(' if !in_string && !in_comment => number_of_open_parens += 1,
+            ')' if !in_string && !in_comment => number_of_open_parens -= 1,
+            _ => {}
+        }
+        previous_character = character;
+    }
+
+    if number_of_open_parens > 0 {
+        if in_comment {
+            code.push('\n');
+        }
+        code.push_str(")".repeat(number_of_open_parens as usize).as_str());
+    }
+
+    code
+}
+
 fn convert_literal_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
     match &syntax_tree_node.value {
         Some(s) if s == "null" => String::from("nil"),
@@ -148,51 +299,137 @@ fn convert_literal_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -
 }
 
 fn convert_branch_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
-    let mut output: String = String::new();
-    let mut index_offset: usize = 0;
+    if let Some(idiomatic_code) = idioms::convert_idiomatic_branch_to_code(syntax_tree_node) {
+        return idiomatic_code;
+    }
+
+    let mut clauses_code: Vec<(String, String)> = vec![];
+    let mut hoisted_bindings: Vec<(String, String)> = vec![];
+    let mut branch_node: &SyntaxTreeNode = syntax_tree_node;
 
-    if syntax_tree_node.value.clone().unwrap() == "if" {
-        index_offset = 1;
-        output.push_str("(if ");
-        output.push_str(&convert_abstract_syntax_tree_to_code(
-            &syntax_tree_node.children[0],
+    loop {
+        let condition_code: PatternCode =
+            patterns::convert_condition_to_code(&branch_node.children[0]);
+
+        if !clauses_code.is_empty() && !condition_code.hoisted_bindings.is_empty() {
+            clauses_code.push((
+                String::from(":else"),
+                close_open_parentheses(convert_abstract_syntax_tree_to_code(branch_node)),
+            ));
+            break;
+        }
+        hoisted_bindings.extend(condition_code.hoisted_bindings);
+
+        let (then_nodes, else_nodes): (&[SyntaxTreeNode], &[SyntaxTreeNode]) =
+            split_branch_bodies(branch_node);
+
+        clauses_code.push((
+            condition_code.condition_code,
+            patterns::wrap_in_bindings(&condition_code.bindings, convert_body_to_code(then_nodes)),
         ));
-        output.push('\n');
+
+        match else_nodes {
+            [] => break,
+            [else_if_node]
+                if matches!(else_if_node.type_, SyntaxTreeNodeType::Branch)
+                    && else_if_node.value.as_deref() == Some("if") =>
+            {
+                branch_node = else_if_node;
+            }
+            _ => {
+                clauses_code.push((String::from(":else"), convert_body_to_code(else_nodes)));
+                break;
+            }
+        }
     }
 
-    if syntax_tree_node.children.len() == index_offset + 1 {
-        output.push_str(
-            convert_abstract_syntax_tree_to_code(&syntax_tree_node.children[index_offset]).as_str(),
-        );
-    } else {
-        output.push_str("(do \n");
-        output.push_str(
-            syntax_tree_node
-                .children
+    let output: String = match clauses_code.as_slice() {
+        [(condition_code, then_code), (else_keyword, else_code)] if else_keyword == ":else" => {
+            format!("(if {}\n{}\n{})", condition_code, then_code, else_code)
+        }
+        _ => format!(
+            "(cond\n{})",
+            clauses_code
                 .iter()
-                .skip(index_offset)
-                .map(convert_abstract_syntax_tree_to_code)
+                .map(|(condition_code, body_code)| format!("{} {}", condition_code, body_code))
                 .collect::<Vec<String>>()
                 .join("\n")
-                .as_str(),
-        );
-        output.push(')');
+        ),
+    };
+
+    match hoisted_bindings.is_empty() {
+        true => output,
+        false => format!(
+            "(let [{}]\n{}",
+            patterns::create_bindings_code(&hoisted_bindings),
+            output
+        ),
     }
+}
 
-    let number_of_open_parens: i32 = output.chars().filter(|c| *c == '(').count() as i32;
-    let number_of_close_parens: i32 = output.chars().filter(|c| *c == ')').count() as i32;
-    let number_of_missing_parens: i32 = number_of_open_parens - number_of_close_parens;
+pub(crate) fn convert_body_to_code(body_nodes: &[SyntaxTreeNode]) -> String {
+    let body_nodes: &[SyntaxTreeNode] = match body_nodes
+        .iter()
+        .rposition(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment))
+    {
+        Some(last_statement_index) => &body_nodes[..=last_statement_index],
+        None => return String::from("nil"),
+    };
 
-    if number_of_missing_parens - 1 >= 0 {
-        output.push_str(")".repeat((number_of_missing_parens - 1) as usize).as_str());
+    let statement_nodes: Vec<&SyntaxTreeNode> = body_nodes
+        .iter()
+        .filter(|node| !matches!(node.type_, SyntaxTreeNodeType::Comment))
+        .collect::<Vec<&SyntaxTreeNode>>();
+
+    let body_code: String = body_nodes
+        .iter()
+        .map(convert_abstract_syntax_tree_to_code)
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    match statement_nodes.as_slice() {
+        [_] => close_open_parentheses(body_code),
+        [first_node, ..] if matches!(first_node.type_, SyntaxTreeNodeType::Assignment) => {
+            close_open_parentheses(body_code)
+        }
+        _ => close_open_parentheses(format!("(do\n{}", body_code)),
     }
+}
 
-    output
+pub(crate) fn convert_body_to_forms_code(body_nodes: &[SyntaxTreeNode]) -> String {
+    close_open_parentheses(
+        body_nodes
+            .iter()
+            .map(convert_abstract_syntax_tree_to_code)
+            .collect::<Vec<String>>()
+            .join("\n"),
+    )
+}
+
+fn convert_using_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    let resource_bindings: Vec<String> = syntax_tree_node.children[0]
+        .children
+        .iter()
+        .map(|resource_node| match (&resource_node.type_, &resource_node.value) {
+            (SyntaxTreeNodeType::Assignment, Some(name)) => format!(
+                "{} {}",
+                name,
+                convert_abstract_syntax_tree_to_code(find_assigned_value(resource_node))
+            ),
+            _ => format!("_ {}", convert_abstract_syntax_tree_to_code(resource_node)),
+        })
+        .collect::<Vec<String>>();
+
+    format!(
+        "(with-open [{}]\n{})",
+        resource_bindings.join(" "),
+        convert_body_to_forms_code(&syntax_tree_node.children[1..])
+    )
 }
 
 fn convert_comment_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
     let mut output: String = String::from(";;");
-    output.push_str(&syntax_tree_node.value.clone().unwrap().as_str());
+    output.push_str(syntax_tree_node.value.clone().unwrap().as_str());
     output
 }
 
@@ -210,3 +447,14 @@ fn convert_collection_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode
 
     output
 }
+
+fn convert_member_access_syntax_tree_node_to_code(syntax_tree_node: &SyntaxTreeNode) -> String {
+    match syntax_tree_node.children.first() {
+        Some(target_node) => format!(
+            "(:{} {})",
+            syntax_tree_node.value.clone().unwrap(),
+            convert_abstract_syntax_tree_to_code(target_node)
+        ),
+        None => format!(":{}", syntax_tree_node.value.clone().unwrap()),
+    }
+}